@@ -0,0 +1,118 @@
+#![feature(test)]
+
+extern crate test;
+
+use server_shared::{
+    capnp::{self, message::Builder},
+    encoding::{CapnpAlloc, MessageSegments, PackStrategy},
+    schema::shared::{player_display_data, user_role},
+};
+use test::{Bencher, black_box};
+
+const RANDOM_LEN: usize = 2048;
+
+fn random_bytes(len: usize) -> Vec<u8> {
+    let mut state = 0x2545f4914f6cdd1du64;
+
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect()
+}
+
+/// A message made of small integers and short strings, which packs well
+fn with_typical_message(f: impl FnOnce(&Builder<CapnpAlloc<1024>>)) {
+    let mut builder = Builder::new(CapnpAlloc::<1024>::new());
+
+    let mut data = builder.init_root::<player_display_data::Builder>();
+    data.set_account_id(7123456);
+    data.set_user_id(24567123);
+    data.set_username("SomePlayerName");
+
+    let mut icons = data.init_icons();
+    icons.set_cube(132);
+    icons.set_ship(45);
+    icons.set_ball(12);
+    icons.set_color1(41);
+    icons.set_color2(12);
+    icons.set_glow_color(3);
+    icons.set_death_effect(1);
+    icons.set_trail(255);
+    icons.set_ship_trail(255);
+
+    f(&builder);
+}
+
+/// A message that is mostly incompressible data, such as a voice frame
+fn with_random_message(f: impl FnOnce(&Builder<CapnpAlloc<4096>>)) {
+    let mut builder = Builder::new(CapnpAlloc::<4096>::new());
+    let bytes = random_bytes(RANDOM_LEN);
+
+    let mut role = builder.init_root::<user_role::Builder>();
+    role.set_string_id("voice");
+    role.set_name_color(&bytes);
+
+    f(&builder);
+}
+
+fn bench_strategy<A: capnp::message::Allocator>(
+    b: &mut Bencher,
+    builder: &Builder<A>,
+    strategy: PackStrategy,
+) {
+    let segments = MessageSegments::new(builder);
+    let mut out = vec![0u8; segments.output_size(strategy)];
+
+    b.bytes = segments.unpacked_size() as u64;
+    b.iter(|| {
+        let segments = MessageSegments::new(black_box(builder));
+        let size = segments.output_size(strategy);
+        segments.write(strategy, &mut out[..size]);
+        black_box(&out);
+    });
+}
+
+fn bench_reference<A: capnp::message::Allocator>(b: &mut Bencher, builder: &Builder<A>) {
+    let mut out = Vec::with_capacity(8192);
+
+    b.bytes = (capnp::serialize::compute_serialized_size_in_words(builder) * 8) as u64;
+    b.iter(|| {
+        out.clear();
+        capnp::serialize_packed::write_message(&mut out, black_box(builder)).unwrap();
+        black_box(&out);
+    });
+}
+
+#[bench]
+fn typical_packed(b: &mut Bencher) {
+    with_typical_message(|builder| bench_strategy(b, builder, PackStrategy::Packed));
+}
+
+#[bench]
+fn typical_literal(b: &mut Bencher) {
+    with_typical_message(|builder| bench_strategy(b, builder, PackStrategy::Literal));
+}
+
+#[bench]
+fn typical_capnp_write_message(b: &mut Bencher) {
+    with_typical_message(|builder| bench_reference(b, builder));
+}
+
+#[bench]
+fn random_packed(b: &mut Bencher) {
+    with_random_message(|builder| bench_strategy(b, builder, PackStrategy::Packed));
+}
+
+#[bench]
+fn random_literal(b: &mut Bencher) {
+    with_random_message(|builder| bench_strategy(b, builder, PackStrategy::Literal));
+}
+
+#[bench]
+fn random_capnp_write_message(b: &mut Bencher) {
+    with_random_message(|builder| bench_reference(b, builder));
+}
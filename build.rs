@@ -1,10 +1,33 @@
-fn generate_for(name: &str) {
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+use capnp_build::{
+    message::ReaderOptions,
+    schema_capnp::{code_generator_request, field, node, type_, value},
+    serialize::OwnedSegments,
+};
+
+#[path = "build/schema_compat.rs"]
+mod schema_compat;
+
+use schema_compat::compatibility_errors;
+
+fn generate_for(name: &str) -> PathBuf {
+    let request_path =
+        PathBuf::from(std::env::var("OUT_DIR").unwrap()).join(format!("{name}.request.bin"));
+
     capnpc::CompilerCommand::new()
         .src_prefix("schema")
         .file(format!("./schema/{name}.capnp"))
         .output_path("schema/generated")
+        .raw_code_generator_request_path(&request_path)
         .run()
         .unwrap_or_else(|e| panic!("capnpc failed for {name}: {e}"));
+
+    request_path
 }
 
 fn main() {
@@ -15,17 +38,787 @@ fn main() {
         return;
     }
 
-    generate_for("shared");
+    println!("cargo:rerun-if-env-changed=SERVER_SHARED_UPDATE_SCHEMA_BASELINE");
+
+    let shared_request = generate_for("shared");
+    generate_models_for("shared", &shared_request);
+    check_compatibility("shared", &shared_request);
+
+    for name in ["main", "game", "srvc"] {
+        if std::env::var(format!("CARGO_FEATURE_{}", name.to_uppercase())).is_ok() {
+            let request = generate_for(name);
+            generate_models_for(name, &request);
+            check_compatibility(name, &request);
+        }
+    }
+}
+
+// Model generation
+//
+// Structs annotated with `$rustModel` (declared in shared.capnp) get a plain Rust struct generated for them,
+// along with `Default`, `from_reader` and `encode` implementations, written to `schema/generated/{name}_models.rs`.
+// Models may refer to models of imported files, and to hand-written types named by a `$rustType` annotation.
+
+fn snake_case(name: &str) -> String {
+    let mut out = String::new();
+
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            if !out.is_empty() {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+
+    out
+}
+
+fn camel_case(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+struct ModelGen<'a> {
+    nodes: HashMap<u64, node::Reader<'a>>,
+    docs: HashMap<u64, node::source_info::Reader<'a>>,
+    models: HashMap<u64, String>,
+}
+
+enum FieldKind {
+    /// Primitive or enum, stored by value and `Copy`
+    Copy(String),
+    Text,
+    Data,
+    List(Box<FieldKind>),
+    Model(String),
+}
+
+impl FieldKind {
+    fn rust_type(&self) -> String {
+        match self {
+            Self::Copy(ty) => ty.clone(),
+            Self::Text => "String".into(),
+            Self::Data => "Vec<u8>".into(),
+            Self::List(inner) => format!("Vec<{}>", inner.rust_type()),
+            Self::Model(name) => name.clone(),
+        }
+    }
+}
+
+impl<'a> ModelGen<'a> {
+    fn short_name(&self, node: node::Reader<'a>) -> String {
+        let display = node.get_display_name().unwrap().to_str().unwrap();
+        display[node.get_display_name_prefix_length() as usize..].to_owned()
+    }
+
+    /// Path to a node within the capnp generated module, e.g. `player_icon_data` for a struct or `IconType` for an enum
+    fn capnp_path(&self, id: u64, is_enum: bool) -> String {
+        let node = self.nodes[&id];
+        let display = node.get_display_name().unwrap().to_str().unwrap();
+        let (file, path) = display.split_once(':').unwrap();
+        let module = format!("{}_capnp", file.trim_end_matches(".capnp"));
+
+        let mut parts: Vec<&str> = path.split('.').collect();
+        let last = parts.pop().unwrap();
+
+        let mut out = format!("crate::{module}");
+        for p in parts {
+            write!(out, "::{}", snake_case(p)).unwrap();
+        }
+
+        if is_enum {
+            write!(out, "::{last}").unwrap();
+        } else {
+            write!(out, "::{}", snake_case(last)).unwrap();
+        }
+
+        out
+    }
+
+    fn field_kind(&self, ty: type_::Reader<'a>, owner: &str, field: &str) -> FieldKind {
+        use type_::Which;
+
+        match ty.which().unwrap() {
+            Which::Bool(()) => FieldKind::Copy("bool".into()),
+            Which::Int8(()) => FieldKind::Copy("i8".into()),
+            Which::Int16(()) => FieldKind::Copy("i16".into()),
+            Which::Int32(()) => FieldKind::Copy("i32".into()),
+            Which::Int64(()) => FieldKind::Copy("i64".into()),
+            Which::Uint8(()) => FieldKind::Copy("u8".into()),
+            Which::Uint16(()) => FieldKind::Copy("u16".into()),
+            Which::Uint32(()) => FieldKind::Copy("u32".into()),
+            Which::Uint64(()) => FieldKind::Copy("u64".into()),
+            Which::Float32(()) => FieldKind::Copy("f32".into()),
+            Which::Float64(()) => FieldKind::Copy("f64".into()),
+            Which::Text(()) => FieldKind::Text,
+            Which::Data(()) => FieldKind::Data,
+            Which::List(list) => {
+                let inner = self.field_kind(list.get_element_type().unwrap(), owner, field);
+                if matches!(inner, FieldKind::List(_)) {
+                    panic!("{owner}.{field}: nested lists are not supported in models");
+                }
+                FieldKind::List(Box::new(inner))
+            }
+            Which::Enum(e) => FieldKind::Copy(self.capnp_path(e.get_type_id(), true)),
+            Which::Struct(s) => match self.models.get(&s.get_type_id()) {
+                Some(name) => FieldKind::Model(name.clone()),
+                None => panic!(
+                    "{owner}.{field}: referenced struct must also be annotated with $rustModel or $rustType"
+                ),
+            },
+            _ => panic!("{owner}.{field}: unsupported field type in a model"),
+        }
+    }
+
+    fn default_value(
+        &self,
+        kind: &FieldKind,
+        ty: type_::Reader<'a>,
+        val: value::Reader<'a>,
+    ) -> String {
+        use value::Which;
+
+        match (kind, val.which().unwrap()) {
+            (_, Which::Bool(v)) => v.to_string(),
+            (_, Which::Int8(v)) => v.to_string(),
+            (_, Which::Int16(v)) => v.to_string(),
+            (_, Which::Int32(v)) => v.to_string(),
+            (_, Which::Int64(v)) => v.to_string(),
+            (_, Which::Uint8(v)) => v.to_string(),
+            (_, Which::Uint16(v)) => v.to_string(),
+            (_, Which::Uint32(v)) => v.to_string(),
+            (_, Which::Uint64(v)) => v.to_string(),
+            (_, Which::Float32(v)) => format!("{v:?}"),
+            (_, Which::Float64(v)) => format!("{v:?}"),
+            (FieldKind::Copy(path), Which::Enum(v)) => {
+                let type_::Which::Enum(e) = ty.which().unwrap() else {
+                    unreachable!()
+                };
+
+                let node::Which::Enum(enum_node) = self.nodes[&e.get_type_id()].which().unwrap()
+                else {
+                    unreachable!()
+                };
+
+                let enumerant = enum_node.get_enumerants().unwrap().get(v as u32);
+                format!(
+                    "{path}::{}",
+                    camel_case(enumerant.get_name().unwrap().to_str().unwrap())
+                )
+            }
+            _ => "Default::default()".into(),
+        }
+    }
+
+    fn read_expr(&self, kind: &FieldKind, getter: &str) -> String {
+        match kind {
+            FieldKind::Copy(_) => getter.to_owned(),
+            FieldKind::Text => format!("{getter}?.to_str()?.to_owned()"),
+            FieldKind::Data => format!("{getter}?.to_vec()"),
+            FieldKind::Model(name) => format!("{name}::from_reader({getter}?)?"),
+            FieldKind::List(inner) => match &**inner {
+                FieldKind::Copy(ty) if is_primitive(ty) => format!("{getter}?.iter().collect()"),
+                FieldKind::Copy(_) => format!("{getter}?.iter().collect::<Result<_, _>>()?"),
+                FieldKind::Text => format!(
+                    "{getter}?.iter().map(|s| Ok(s?.to_str()?.to_owned())).collect::<Result<_, DataDecodeError>>()?"
+                ),
+                FieldKind::Data => {
+                    format!(
+                        "{getter}?.iter().map(|d| d.map(<[u8]>::to_vec)).collect::<Result<_, _>>()?"
+                    )
+                }
+                FieldKind::Model(name) => {
+                    format!("{getter}?.iter().map({name}::from_reader).collect::<Result<_, _>>()?")
+                }
+                FieldKind::List(_) => unreachable!(),
+            },
+        }
+    }
+
+    fn generate_model(&self, out: &mut String, id: u64) {
+        let node = self.nodes[&id];
+        let name = &self.models[&id];
+        let module = self.capnp_path(id, false);
+
+        let node::Which::Struct(st) = node.which().unwrap() else {
+            panic!("{name}: $rustModel can only be applied to structs");
+        };
+
+        // source info lists members in the same order as the node, so keep the index around for doc comments
+        let mut fields: Vec<(u32, field::Reader<'a>)> = st
+            .get_fields()
+            .unwrap()
+            .iter()
+            .enumerate()
+            .map(|(i, f)| (i as u32, f))
+            .collect();
+        fields.sort_by_key(|(_, f)| f.get_code_order());
+
+        let docs = self.docs.get(&id);
+        let mut generated = Vec::new();
+
+        for (idx, f) in fields {
+            let fname = f.get_name().unwrap().to_str().unwrap();
+
+            if f.get_discriminant_value() != field::NO_DISCRIMINANT {
+                panic!("{name}.{fname}: unions are not supported in models");
+            }
+
+            let field::Which::Slot(slot) = f.which().unwrap() else {
+                panic!("{name}.{fname}: groups are not supported in models");
+            };
+
+            let ty = slot.get_type().unwrap();
+            let kind = self.field_kind(ty, name, fname);
+            let default = self.default_value(&kind, ty, slot.get_default_value().unwrap());
+
+            let rust_name = match snake_case(fname).as_str() {
+                "type" => "r#type".to_owned(),
+                other => other.to_owned(),
+            };
+
+            let doc = docs
+                .and_then(|d| d.get_members().ok())
+                .and_then(|m| m.try_get(idx))
+                .and_then(|m| m.get_doc_comment().ok())
+                .and_then(|d| d.to_str().ok())
+                .unwrap_or_default();
+
+            generated.push((fname, rust_name, kind, default, doc));
+        }
+
+        let is_copy = generated
+            .iter()
+            .all(|(_, _, k, _, _)| matches!(k, FieldKind::Copy(_)));
+
+        // struct definition
+        if let Some(doc) = docs
+            .and_then(|d| d.get_doc_comment().ok())
+            .and_then(|d| d.to_str().ok())
+        {
+            write_doc(out, "", doc);
+        }
+
+        if is_copy {
+            writeln!(out, "#[derive(Clone, Copy, Debug, PartialEq)]").unwrap();
+        } else {
+            writeln!(out, "#[derive(Clone, Debug, PartialEq)]").unwrap();
+        }
+
+        writeln!(out, "pub struct {name} {{").unwrap();
+        for (_, rust_name, kind, _, doc) in &generated {
+            write_doc(out, "    ", doc);
+            writeln!(out, "    pub {rust_name}: {},", kind.rust_type()).unwrap();
+        }
+        writeln!(out, "}}\n").unwrap();
+
+        // default impl, following the defaults in the schema
+        writeln!(out, "impl Default for {name} {{").unwrap();
+        writeln!(out, "    fn default() -> Self {{").unwrap();
+        writeln!(out, "        Self {{").unwrap();
+        for (_, rust_name, _, default, _) in &generated {
+            writeln!(out, "            {rust_name}: {default},").unwrap();
+        }
+        writeln!(out, "        }}").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}\n").unwrap();
+
+        // conversions
+        writeln!(out, "impl {name} {{").unwrap();
+        writeln!(
+            out,
+            "    pub fn from_reader(reader: {module}::Reader<'_>) -> Result<Self, DataDecodeError> {{"
+        )
+        .unwrap();
+        writeln!(out, "        Ok(Self {{").unwrap();
+        for (fname, rust_name, kind, _, _) in &generated {
+            let getter = format!("reader.get_{}()", snake_case(fname));
+            let getter = match kind {
+                FieldKind::Copy(ty) if !is_primitive(ty) => format!("{getter}?"),
+                _ => getter,
+            };
+
+            writeln!(
+                out,
+                "            {rust_name}: {},",
+                self.read_expr(kind, &getter)
+            )
+            .unwrap();
+        }
+        writeln!(out, "        }})").unwrap();
+        writeln!(out, "    }}\n").unwrap();
+
+        writeln!(
+            out,
+            "    pub fn encode(&self, mut builder: {module}::Builder<'_>) {{"
+        )
+        .unwrap();
+        for (fname, rust_name, kind, _, _) in &generated {
+            let field = snake_case(fname);
+
+            match kind {
+                FieldKind::Copy(_) => {
+                    writeln!(out, "        builder.set_{field}(self.{rust_name});").unwrap()
+                }
+                FieldKind::Text => writeln!(
+                    out,
+                    "        builder.set_{field}(self.{rust_name}.as_str());"
+                )
+                .unwrap(),
+                FieldKind::Data => {
+                    writeln!(out, "        builder.set_{field}(&self.{rust_name});").unwrap()
+                }
+                FieldKind::Model(_) => writeln!(
+                    out,
+                    "        self.{rust_name}.encode(builder.reborrow().init_{field}());"
+                )
+                .unwrap(),
+                FieldKind::List(inner) => {
+                    let set = match &**inner {
+                        FieldKind::Copy(_) => "list.set(i as u32, *v)",
+                        FieldKind::Text => "list.set(i as u32, v.as_str())",
+                        FieldKind::Data => "list.set(i as u32, v)",
+                        FieldKind::Model(_) => "v.encode(list.reborrow().get(i as u32))",
+                        FieldKind::List(_) => unreachable!(),
+                    };
+
+                    writeln!(out, "        {{").unwrap();
+                    writeln!(
+                        out,
+                        "            let mut list = builder.reborrow().init_{field}(self.{rust_name}.len() as u32);"
+                    )
+                    .unwrap();
+                    writeln!(
+                        out,
+                        "            for (i, v) in self.{rust_name}.iter().enumerate() {{"
+                    )
+                    .unwrap();
+                    writeln!(out, "                {set};").unwrap();
+                    writeln!(out, "            }}").unwrap();
+                    writeln!(out, "        }}").unwrap();
+                }
+            }
+        }
+        writeln!(out, "    }}\n").unwrap();
+
+        // size estimation, see `encoding::size`
+        writeln!(out, "    pub fn encoded_size(&self) -> usize {{").unwrap();
+        write!(out, "        struct_size::<{module}::Builder<'static>>()").unwrap();
+        for (_, rust_name, kind, _, _) in &generated {
+            if let Some(size) = self.size_expr(kind, &format!("self.{rust_name}")) {
+                write!(out, "\n            + {size}").unwrap();
+            }
+        }
+        writeln!(out, "\n    }}").unwrap();
+        writeln!(out, "}}").unwrap();
+    }
+
+    fn size_expr(&self, kind: &FieldKind, value: &str) -> Option<String> {
+        Some(match kind {
+            FieldKind::Copy(_) => return None,
+            FieldKind::Text => format!("text_size({value}.len())"),
+            FieldKind::Data => format!("data_size({value}.len())"),
+            FieldKind::Model(_) => format!("{value}.encoded_size()"),
+            FieldKind::List(inner) => match &**inner {
+                FieldKind::Copy(ty) if ty == "bool" => format!("bool_list_size({value}.len())"),
+                FieldKind::Copy(ty) if is_primitive(ty) => {
+                    format!("list_size::<{ty}>({value}.len())")
+                }
+                FieldKind::Copy(_) => format!("list_size::<u16>({value}.len())"),
+                FieldKind::Text => format!(
+                    "pointer_list_size({value}.len()) + {value}.iter().map(|s| text_size(s.len())).sum::<usize>()"
+                ),
+                FieldKind::Data => format!(
+                    "pointer_list_size({value}.len()) + {value}.iter().map(|d| data_size(d.len())).sum::<usize>()"
+                ),
+                FieldKind::Model(name) => {
+                    format!("composite_list_size({value}.iter().map({name}::encoded_size).sum())")
+                }
+                FieldKind::List(_) => unreachable!(),
+            },
+        })
+    }
+}
+
+fn write_doc(out: &mut String, indent: &str, doc: &str) {
+    for line in doc.trim().lines() {
+        writeln!(out, "{indent}/// {}", line.trim()).unwrap();
+    }
+}
+
+fn is_primitive(ty: &str) -> bool {
+    matches!(
+        ty,
+        "bool" | "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "f32" | "f64"
+    )
+}
+
+fn read_request(request_path: &Path) -> capnp_build::message::Reader<OwnedSegments> {
+    let data = std::fs::read(request_path).expect("failed to read code generator request");
+    capnp_build::serialize::read_message(&mut &data[..], ReaderOptions::new())
+        .expect("failed to parse code generator request")
+}
+
+fn generate_models_for(name: &str, request_path: &Path) {
+    let message = read_request(request_path);
+    let request = message
+        .get_root::<code_generator_request::Reader<'_>>()
+        .expect("failed to parse code generator request");
+
+    let mut gen_ = ModelGen {
+        nodes: HashMap::new(),
+        docs: HashMap::new(),
+        models: HashMap::new(),
+    };
+
+    for node in request.get_nodes().unwrap() {
+        gen_.nodes.insert(node.get_id(), node);
+    }
+
+    for info in request.get_source_info().unwrap() {
+        gen_.docs.insert(info.get_id(), info);
+    }
+
+    let find_annotation = |name: &str| {
+        gen_.nodes
+            .values()
+            .find(|n| {
+                matches!(n.which(), Ok(node::Which::Annotation(_)))
+                    && n.get_display_name().unwrap().to_str().unwrap()
+                        == format!("shared.capnp:{name}")
+            })
+            .map(|n| n.get_id())
+            .unwrap_or_else(|| {
+                panic!("{name} annotation not found, it must be declared in shared.capnp")
+            })
+    };
+
+    let annotation_id = find_annotation("rustModel");
+    let rust_type_id = find_annotation("rustType");
+
+    let file = request.get_requested_files().unwrap().get(0).get_id();
+    let file_prefix = format!("{name}.capnp:");
+
+    // structs of imported files can be referenced too, either as their generated model or as a hand-written type
+    for node in gen_.nodes.values() {
+        let display = node.get_display_name().unwrap().to_str().unwrap();
+
+        for a in node.get_annotations().unwrap() {
+            if a.get_id() == rust_type_id {
+                let value::Which::Text(path) = a.get_value().unwrap().which().unwrap() else {
+                    panic!("{display}: $rustType must be a text value");
+                };
+                gen_.models
+                    .insert(node.get_id(), path.unwrap().to_str().unwrap().to_owned());
+            } else if a.get_id() == annotation_id && !display.starts_with(&file_prefix) {
+                let (file, _) = display.split_once(':').unwrap();
+                let module = file.trim_end_matches(".capnp");
+                let path = format!("crate::{module}_models::{}", gen_.short_name(*node));
+                gen_.models.insert(node.get_id(), path);
+            }
+        }
+    }
+
+    // collect all annotated structs in this file, in the order they are declared in the schema
+    let mut model_ids = Vec::new();
+    let mut stack = vec![file];
+
+    while let Some(id) = stack.pop() {
+        let node = gen_.nodes[&id];
+
+        let annotated = node
+            .get_annotations()
+            .unwrap()
+            .iter()
+            .any(|a| a.get_id() == annotation_id);
+
+        if annotated {
+            let name = gen_.short_name(node);
+            model_ids.push(id);
+            gen_.models.insert(id, name);
+        }
+
+        let nested: Vec<u64> = node
+            .get_nested_nodes()
+            .unwrap()
+            .iter()
+            .map(|n| n.get_id())
+            .collect();
+        stack.extend(nested.into_iter().rev());
+    }
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// @generated by build.rs from structs annotated with $rustModel in {name}.capnp."
+    )
+    .unwrap();
+    writeln!(out, "// DO NOT EDIT.\n").unwrap();
+    writeln!(out, "use crate::encoding::{{DataDecodeError, size::*}};").unwrap();
+
+    for id in &model_ids {
+        writeln!(out).unwrap();
+        gen_.generate_model(&mut out, *id);
+    }
+
+    let out_path = format!("schema/generated/{name}_models.rs");
+
+    // avoid touching the file if nothing changed, to not trigger needless rebuilds
+    if std::fs::read_to_string(&out_path).ok().as_deref() != Some(out.as_str()) {
+        std::fs::write(&out_path, out).expect("failed to write generated models");
+    }
+}
+
+// Schema compatibility
+//
+// A summary of the compiled schema nodes of every file is checked in at `schema/baseline/{name}.txt`,
+// and the build fails if the current schema is not wire-compatible with it.
+// Adding new fields, union members and enumerants is fine, while changing ordinals, types, defaults or union membership,
+// as well as removing anything, is not. Renaming is allowed, as names never go over the wire, except for nodes
+// that no field of the same file refers to, which are how the rest gets matched (see `build/schema_compat.rs`).
+// The build never touches the baseline, the current summary is only written to `OUT_DIR`.
+// After adding to the schema or making an intentional breaking change, build with SERVER_SHARED_UPDATE_SCHEMA_BASELINE=1
+// to rewrite the baseline, so that the additions are protected as well.
+
+struct SchemaSummary<'a> {
+    nodes: HashMap<u64, node::Reader<'a>>,
+    lines: Vec<String>,
+}
+
+impl<'a> SchemaSummary<'a> {
+    /// Path of a node within its file, e.g. `PlayerData` or `Outer.Inner`
+    fn node_path(&self, id: u64) -> String {
+        let display = self.nodes[&id]
+            .get_display_name()
+            .unwrap()
+            .to_str()
+            .unwrap();
+        display.split_once(':').unwrap().1.to_owned()
+    }
+
+    fn type_name(&self, ty: type_::Reader<'a>) -> String {
+        use type_::Which;
+
+        match ty.which().unwrap() {
+            Which::Void(()) => "Void".into(),
+            Which::Bool(()) => "Bool".into(),
+            Which::Int8(()) => "Int8".into(),
+            Which::Int16(()) => "Int16".into(),
+            Which::Int32(()) => "Int32".into(),
+            Which::Int64(()) => "Int64".into(),
+            Which::Uint8(()) => "UInt8".into(),
+            Which::Uint16(()) => "UInt16".into(),
+            Which::Uint32(()) => "UInt32".into(),
+            Which::Uint64(()) => "UInt64".into(),
+            Which::Float32(()) => "Float32".into(),
+            Which::Float64(()) => "Float64".into(),
+            Which::Text(()) => "Text".into(),
+            Which::Data(()) => "Data".into(),
+            Which::List(list) => {
+                format!("List({})", self.type_name(list.get_element_type().unwrap()))
+            }
+            Which::Enum(e) => self.node_path(e.get_type_id()),
+            Which::Struct(s) => self.node_path(s.get_type_id()),
+            Which::Interface(i) => self.node_path(i.get_type_id()),
+            Which::AnyPointer(_) => "AnyPointer".into(),
+        }
+    }
+
+    /// Only non-zero defaults are listed, as those are the ones that change how a value is encoded
+    fn default_suffix(val: value::Reader<'a>) -> String {
+        use value::Which;
 
-    if std::env::var("CARGO_FEATURE_MAIN").is_ok() {
-        generate_for("main");
+        let val = match val.which().unwrap() {
+            Which::Bool(v) => v.then(|| "true".to_owned()),
+            Which::Int8(v) => (v != 0).then(|| v.to_string()),
+            Which::Int16(v) => (v != 0).then(|| v.to_string()),
+            Which::Int32(v) => (v != 0).then(|| v.to_string()),
+            Which::Int64(v) => (v != 0).then(|| v.to_string()),
+            Which::Uint8(v) => (v != 0).then(|| v.to_string()),
+            Which::Uint16(v) => (v != 0).then(|| v.to_string()),
+            Which::Uint32(v) => (v != 0).then(|| v.to_string()),
+            Which::Uint64(v) => (v != 0).then(|| v.to_string()),
+            Which::Float32(v) => (v.to_bits() != 0).then(|| format!("{v:?}")),
+            Which::Float64(v) => (v.to_bits() != 0).then(|| format!("{v:?}")),
+            Which::Enum(v) => (v != 0).then(|| v.to_string()),
+            _ => None,
+        };
+
+        val.map(|v| format!(" = {v}")).unwrap_or_default()
     }
 
-    if std::env::var("CARGO_FEATURE_GAME").is_ok() {
-        generate_for("game");
+    /// Groups have no ordinal of their own, so they are identified by the lowest ordinal among their members,
+    /// which cannot change without renumbering a field
+    fn first_ordinal(&self, id: u64) -> u16 {
+        let node::Which::Struct(st) = self.nodes[&id].which().unwrap() else {
+            unreachable!()
+        };
+
+        st.get_fields()
+            .unwrap()
+            .iter()
+            .map(|field| match field.which().unwrap() {
+                field::Which::Slot(_) => match field.get_ordinal().which() {
+                    Ok(field::ordinal::Which::Explicit(ordinal)) => ordinal,
+                    _ => u16::MAX,
+                },
+                field::Which::Group(group) => self.first_ordinal(group.get_type_id()),
+            })
+            .min()
+            .unwrap_or(u16::MAX)
     }
 
-    if std::env::var("CARGO_FEATURE_SRVC").is_ok() {
-        generate_for("srvc");
+    fn summarize_struct(&mut self, owner: &str, prefix: &str, group_suffix: &str, id: u64) {
+        let node::Which::Struct(st) = self.nodes[&id].which().unwrap() else {
+            unreachable!()
+        };
+
+        for field in st.get_fields().unwrap() {
+            let name = format!("{prefix}{}", field.get_name().unwrap().to_str().unwrap());
+
+            let union = match field.get_discriminant_value() {
+                field::NO_DISCRIMINANT => String::new(),
+                d => format!(" union #{d}"),
+            };
+
+            match field.which().unwrap() {
+                field::Which::Slot(slot) => {
+                    let Ok(field::ordinal::Which::Explicit(ordinal)) = field.get_ordinal().which()
+                    else {
+                        panic!("{owner}.{name}: field without an explicit ordinal");
+                    };
+
+                    let ty = self.type_name(slot.get_type().unwrap());
+                    let default = Self::default_suffix(slot.get_default_value().unwrap());
+
+                    self.lines.push(format!(
+                        "{owner} @{ordinal} {name} :{ty}{union}{default}{group_suffix}"
+                    ));
+                }
+
+                field::Which::Group(group) => {
+                    let slot = format!("group@{}", self.first_ordinal(group.get_type_id()));
+
+                    self.lines
+                        .push(format!("{owner} {slot} {name}{union}{group_suffix}"));
+                    self.summarize_struct(
+                        owner,
+                        &format!("{name}."),
+                        &format!(" in {slot}"),
+                        group.get_type_id(),
+                    );
+                }
+            }
+        }
+    }
+
+    fn summarize_enum(&mut self, id: u64) {
+        let node::Which::Enum(e) = self.nodes[&id].which().unwrap() else {
+            unreachable!()
+        };
+
+        let owner = self.node_path(id);
+
+        // enumerants are stored in ordinal order
+        for (ordinal, enumerant) in e.get_enumerants().unwrap().iter().enumerate() {
+            let name = enumerant.get_name().unwrap().to_str().unwrap();
+            self.lines.push(format!("{owner} @{ordinal} {name}"));
+        }
+    }
+}
+
+fn check_compatibility(name: &str, request_path: &Path) {
+    let message = read_request(request_path);
+    let request = message
+        .get_root::<code_generator_request::Reader<'_>>()
+        .expect("failed to parse code generator request");
+
+    let mut summary = SchemaSummary {
+        nodes: HashMap::new(),
+        lines: Vec::new(),
+    };
+
+    for node in request.get_nodes().unwrap() {
+        summary.nodes.insert(node.get_id(), node);
+    }
+
+    let file = request.get_requested_files().unwrap().get(0).get_id();
+    let mut stack = vec![file];
+
+    while let Some(id) = stack.pop() {
+        let node = summary.nodes[&id];
+
+        match node.which().unwrap() {
+            node::Which::Struct(_) => {
+                let owner = summary.node_path(id);
+                summary.summarize_struct(&owner, "", "", id);
+            }
+            node::Which::Enum(_) => summary.summarize_enum(id),
+            _ => {}
+        }
+
+        let nested: Vec<u64> = node
+            .get_nested_nodes()
+            .unwrap()
+            .iter()
+            .map(|n| n.get_id())
+            .collect();
+        stack.extend(nested.into_iter().rev());
+    }
+
+    let mut current = String::new();
+    writeln!(
+        current,
+        "# Wire compatibility baseline for {name}.capnp, checked by build.rs."
+    )
+    .unwrap();
+    writeln!(
+        current,
+        "# Regenerate with SERVER_SHARED_UPDATE_SCHEMA_BASELINE=1 after adding to the schema or an intentional breaking change.\n"
+    )
+    .unwrap();
+
+    for line in &summary.lines {
+        writeln!(current, "{line}").unwrap();
+    }
+
+    let out_path =
+        PathBuf::from(std::env::var("OUT_DIR").unwrap()).join(format!("{name}.baseline.txt"));
+    std::fs::write(&out_path, &current).expect("failed to write schema summary");
+
+    let baseline_path = format!("schema/baseline/{name}.txt");
+    let baseline = std::fs::read_to_string(&baseline_path).ok();
+
+    if std::env::var("SERVER_SHARED_UPDATE_SCHEMA_BASELINE").is_ok() {
+        if baseline.as_deref() != Some(current.as_str()) {
+            std::fs::create_dir_all("schema/baseline").expect("failed to create schema/baseline");
+            std::fs::write(&baseline_path, current).expect("failed to write schema baseline");
+        }
+
+        return;
+    }
+
+    let Some(baseline) = baseline else {
+        println!(
+            "cargo:warning={baseline_path} is missing, {name}.capnp is not checked for compatibility. \
+             Build with SERVER_SHARED_UPDATE_SCHEMA_BASELINE=1 to create it"
+        );
+        return;
+    };
+
+    let errors = compatibility_errors(&baseline, &current);
+
+    if !errors.is_empty() {
+        panic!(
+            "{name}.capnp is not wire-compatible with {baseline_path}:\n  {}\n\
+             if this is intentional, rebuild with SERVER_SHARED_UPDATE_SCHEMA_BASELINE=1",
+            errors.join("\n  ")
+        );
     }
 }
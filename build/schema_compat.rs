@@ -0,0 +1,258 @@
+// Schema compatibility checks on the summaries written by build.rs, which includes this file.
+// It is also compiled into the library under `cfg(test)`, as tests of build scripts are never run.
+
+use std::collections::{HashMap, HashSet};
+
+/// A parsed summary line, `{owner} {slot} {name}{desc}`, where `slot` is either an ordinal (`@3`) or a group (`group@3`).
+/// Entries are identified by their owner and slot, the name is only there for humans.
+struct SummaryEntry<'a> {
+    owner: &'a str,
+    slot: &'a str,
+    name: &'a str,
+    desc: &'a str,
+}
+
+impl<'a> SummaryEntry<'a> {
+    fn parse_all(text: &'a str) -> Vec<Self> {
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let mut parts = line.splitn(3, ' ');
+                let owner = parts.next().unwrap();
+                let slot = parts.next().expect("malformed schema baseline line");
+                let rest = parts.next().expect("malformed schema baseline line");
+                let (name, desc) = rest.split_at(rest.find(' ').unwrap_or(rest.len()));
+
+                Self {
+                    owner,
+                    slot,
+                    name,
+                    desc: desc.trim_start(),
+                }
+            })
+            .collect()
+    }
+
+    /// Struct, enum or interface referenced by the type of this field, if any
+    fn referenced(&self) -> Option<&'a str> {
+        let ty = self.desc.strip_prefix(':')?;
+        let mut ty = &ty[..ty.find(' ').unwrap_or(ty.len())];
+
+        while let Some(inner) = ty.strip_prefix("List(") {
+            ty = inner.strip_suffix(')').unwrap_or(inner);
+        }
+
+        const BUILTINS: &[&str] = &[
+            "Void",
+            "Bool",
+            "Int8",
+            "Int16",
+            "Int32",
+            "Int64",
+            "UInt8",
+            "UInt16",
+            "UInt32",
+            "UInt64",
+            "Float32",
+            "Float64",
+            "Text",
+            "Data",
+            "AnyPointer",
+        ];
+
+        (!BUILTINS.contains(&ty)).then_some(ty)
+    }
+
+    /// Description with the referenced type translated through `matched`, for comparing against the other summary
+    fn desc_in(&self, matched: &HashMap<&'a str, &'a str>) -> String {
+        match self.referenced() {
+            Some(old) => {
+                let new = matched.get(old).copied().unwrap_or(old);
+                let end = self.desc.find(' ').unwrap_or(self.desc.len());
+                let (ty, rest) = self.desc.split_at(end);
+
+                // the referenced name is always the innermost part of the type
+                let at = ty.rfind(old).unwrap();
+                format!("{}{new}{}{rest}", &ty[..at], &ty[at + old.len()..])
+            }
+            None => self.desc.to_owned(),
+        }
+    }
+}
+
+/// Matches the structs and enums of the baseline to the current ones.
+/// Most schemas don't give their nodes explicit ids, so capnp derives them from the names, and neither ids nor names
+/// survive a rename. Instead, nodes are matched by how they are reached: nodes that no other node refers to are
+/// matched by name, and from there the types of fields with the same ordinal are matched to each other.
+/// Nodes that cannot be reached this way are left unmatched.
+fn match_nodes<'a>(
+    baseline: &[SummaryEntry<'a>],
+    current: &[SummaryEntry<'a>],
+) -> HashMap<&'a str, &'a str> {
+    let mut old_owners: Vec<&str> = baseline.iter().map(|e| e.owner).collect();
+    old_owners.dedup();
+
+    let new_owners: HashSet<&str> = current.iter().map(|e| e.owner).collect();
+    let by_key: HashMap<_, _> = current.iter().map(|e| ((e.owner, e.slot), e)).collect();
+
+    // a node referring to itself can still be a root
+    let referenced: HashSet<_> = baseline
+        .iter()
+        .filter_map(|e| e.referenced().filter(|r| *r != e.owner))
+        .collect();
+
+    let mut matched = HashMap::new();
+
+    for &root in old_owners.iter().filter(|o| !referenced.contains(*o)) {
+        if !new_owners.contains(root) || matched.values().any(|taken| *taken == root) {
+            continue;
+        }
+
+        matched.insert(root, root);
+        let mut queue = vec![root];
+
+        while let Some(old_owner) = queue.pop() {
+            let new_owner = matched[old_owner];
+
+            for old in baseline.iter().filter(|e| e.owner == old_owner) {
+                if let Some(old_ref) = old.referenced()
+                    && !matched.contains_key(old_ref)
+                    && let Some(new_ref) = by_key
+                        .get(&(new_owner, old.slot))
+                        .and_then(|new| new.referenced())
+                {
+                    matched.insert(old_ref, new_ref);
+                    queue.push(old_ref);
+                }
+            }
+        }
+    }
+
+    matched
+}
+
+pub fn compatibility_errors(baseline: &str, current: &str) -> Vec<String> {
+    let baseline = SummaryEntry::parse_all(baseline);
+    let current = SummaryEntry::parse_all(current);
+
+    let matched = match_nodes(&baseline, &current);
+    let by_key: HashMap<_, _> = current.iter().map(|e| ((e.owner, e.slot), e)).collect();
+
+    let mut errors = Vec::new();
+    let mut unmatched = HashSet::new();
+
+    for old in &baseline {
+        let Some(owner) = matched.get(old.owner) else {
+            if unmatched.insert(old.owner) {
+                errors.push(format!(
+                    "{} was removed, or renamed without being referred to by a field",
+                    old.owner
+                ));
+            }
+            continue;
+        };
+
+        let what = format!("{}.{} ({})", old.owner, old.name, old.slot);
+
+        let Some(new) = by_key.get(&(*owner, old.slot)) else {
+            errors.push(format!("{what} was removed"));
+            continue;
+        };
+
+        let expected = old.desc_in(&matched);
+
+        if new.desc != expected {
+            errors.push(format!(
+                "{what} changed from `{expected}` to `{}`",
+                new.desc
+            ));
+        }
+    }
+
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASELINE: &str = "\
+# comment
+Message @0 login :Login union #0
+Message @1 logout :Void union #1
+Login @0 accountId :Int32
+Login @1 icons :Icons
+Login @2 flags :List(Icons)
+Icons @0 cube :UInt16 = 1
+";
+
+    fn errors(current: &str) -> Vec<String> {
+        compatibility_errors(BASELINE, current)
+    }
+
+    #[test]
+    fn unchanged_is_compatible() {
+        assert!(errors(BASELINE).is_empty());
+    }
+
+    #[test]
+    fn additions_are_compatible() {
+        let current = format!("{BASELINE}Login @3 name :Text\nMessage @2 ping :Void union #2\n");
+        assert!(errors(&current).is_empty());
+    }
+
+    #[test]
+    fn renamed_field_is_compatible() {
+        let current = BASELINE
+            .replace("accountId", "userId")
+            .replace("logout", "disconnect");
+        assert_eq!(errors(&current), Vec::<String>::new());
+    }
+
+    #[test]
+    fn renamed_struct_is_compatible() {
+        let current = BASELINE.replace("Icons", "PlayerIcons");
+        assert_eq!(errors(&current), Vec::<String>::new());
+    }
+
+    #[test]
+    fn retyped_field_is_incompatible() {
+        let current = BASELINE.replace("accountId :Int32", "accountId :Int64");
+        assert_eq!(
+            errors(&current),
+            ["Login.accountId (@0) changed from `:Int32` to `:Int64`"]
+        );
+
+        let current = BASELINE.replace("cube :UInt16 = 1", "cube :UInt16");
+        assert_eq!(errors(&current).len(), 1);
+
+        let current = BASELINE.replace("logout :Void union #1", "logout :Void");
+        assert_eq!(errors(&current).len(), 1);
+    }
+
+    #[test]
+    fn removed_field_is_incompatible() {
+        let current = BASELINE.replace("Login @0 accountId :Int32\n", "");
+        assert_eq!(errors(&current), ["Login.accountId (@0) was removed"]);
+    }
+
+    #[test]
+    fn unreachable_node_is_incompatible() {
+        // without the fields referring to it, a renamed struct cannot be matched
+        let current = BASELINE
+            .replace("Login @1 icons :Icons\n", "")
+            .replace("Login @2 flags :List(Icons)\n", "")
+            .replace("Icons", "PlayerIcons");
+
+        let errs = errors(&current);
+        assert_eq!(errs.len(), 3, "{errs:?}");
+        assert!(errs[2].starts_with("Icons was removed"));
+    }
+
+    #[test]
+    fn renamed_root_is_incompatible() {
+        let current = BASELINE.replace("Message", "Packet");
+        assert!(errors(&current)[0].starts_with("Message was removed"));
+    }
+}
@@ -0,0 +1,922 @@
+// @generated by build.rs from structs annotated with $rustModel in game.capnp.
+// DO NOT EDIT.
+
+use crate::encoding::{DataDecodeError, size::*};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LoginFailedMessage {
+    pub reason: crate::game_capnp::LoginFailedReason,
+}
+
+impl Default for LoginFailedMessage {
+    fn default() -> Self {
+        Self {
+            reason: crate::game_capnp::LoginFailedReason::InvalidUserToken,
+        }
+    }
+}
+
+impl LoginFailedMessage {
+    pub fn from_reader(reader: crate::game_capnp::login_failed_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            reason: reader.get_reason()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::login_failed_message::Builder<'_>) {
+        builder.set_reason(self.reason);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::login_failed_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JoinSessionMessage {
+    pub session_id: u64,
+    pub passcode: u32,
+    pub platformer: bool,
+    pub editor_collab: bool,
+}
+
+impl Default for JoinSessionMessage {
+    fn default() -> Self {
+        Self {
+            session_id: 0,
+            passcode: 0,
+            platformer: false,
+            editor_collab: false,
+        }
+    }
+}
+
+impl JoinSessionMessage {
+    pub fn from_reader(reader: crate::game_capnp::join_session_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            session_id: reader.get_session_id(),
+            passcode: reader.get_passcode(),
+            platformer: reader.get_platformer(),
+            editor_collab: reader.get_editor_collab(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::join_session_message::Builder<'_>) {
+        builder.set_session_id(self.session_id);
+        builder.set_passcode(self.passcode);
+        builder.set_platformer(self.platformer);
+        builder.set_editor_collab(self.editor_collab);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::join_session_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JoinSessionOkMessage {
+    pub session_id: u64,
+}
+
+impl Default for JoinSessionOkMessage {
+    fn default() -> Self {
+        Self {
+            session_id: 0,
+        }
+    }
+}
+
+impl JoinSessionOkMessage {
+    pub fn from_reader(reader: crate::game_capnp::join_session_ok_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            session_id: reader.get_session_id(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::join_session_ok_message::Builder<'_>) {
+        builder.set_session_id(self.session_id);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::join_session_ok_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JoinSessionFailedMessage {
+    pub reason: crate::game_capnp::JoinSessionFailedReason,
+}
+
+impl Default for JoinSessionFailedMessage {
+    fn default() -> Self {
+        Self {
+            reason: crate::game_capnp::JoinSessionFailedReason::InvalidPasscode,
+        }
+    }
+}
+
+impl JoinSessionFailedMessage {
+    pub fn from_reader(reader: crate::game_capnp::join_session_failed_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            reason: reader.get_reason()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::join_session_failed_message::Builder<'_>) {
+        builder.set_reason(self.reason);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::join_session_failed_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExtendedPlayerData {
+    pub velocity_x: f32,
+    pub velocity_y: f32,
+    pub accelerating: bool,
+    pub acceleration: f32,
+    pub fall_start_y: f32,
+    pub is_on_ground2: bool,
+    pub gravity_mod: f32,
+    pub gravity: f32,
+    pub touched_pad: bool,
+    pub maybe_falling: bool,
+    pub fall_speed: f32,
+    pub is_on_ground4: bool,
+}
+
+impl Default for ExtendedPlayerData {
+    fn default() -> Self {
+        Self {
+            velocity_x: 0.0,
+            velocity_y: 0.0,
+            accelerating: false,
+            acceleration: 0.0,
+            fall_start_y: 0.0,
+            is_on_ground2: false,
+            gravity_mod: 0.0,
+            gravity: 0.0,
+            touched_pad: false,
+            maybe_falling: false,
+            fall_speed: 0.0,
+            is_on_ground4: false,
+        }
+    }
+}
+
+impl ExtendedPlayerData {
+    pub fn from_reader(reader: crate::game_capnp::extended_player_data::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            velocity_x: reader.get_velocity_x(),
+            velocity_y: reader.get_velocity_y(),
+            accelerating: reader.get_accelerating(),
+            acceleration: reader.get_acceleration(),
+            fall_start_y: reader.get_fall_start_y(),
+            is_on_ground2: reader.get_is_on_ground2(),
+            gravity_mod: reader.get_gravity_mod(),
+            gravity: reader.get_gravity(),
+            touched_pad: reader.get_touched_pad(),
+            maybe_falling: reader.get_maybe_falling(),
+            fall_speed: reader.get_fall_speed(),
+            is_on_ground4: reader.get_is_on_ground4(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::extended_player_data::Builder<'_>) {
+        builder.set_velocity_x(self.velocity_x);
+        builder.set_velocity_y(self.velocity_y);
+        builder.set_accelerating(self.accelerating);
+        builder.set_acceleration(self.acceleration);
+        builder.set_fall_start_y(self.fall_start_y);
+        builder.set_is_on_ground2(self.is_on_ground2);
+        builder.set_gravity_mod(self.gravity_mod);
+        builder.set_gravity(self.gravity);
+        builder.set_touched_pad(self.touched_pad);
+        builder.set_maybe_falling(self.maybe_falling);
+        builder.set_fall_speed(self.fall_speed);
+        builder.set_is_on_ground4(self.is_on_ground4);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::extended_player_data::Builder<'static>>()
+    }
+}
+
+/// aka SpecificIconData in globed v1
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlayerObjectData {
+    pub position_x: f32,
+    pub position_y: f32,
+    pub rotation: f32,
+    pub icon_type: crate::shared_capnp::IconType,
+    pub is_visible: bool,
+    pub is_looking_left: bool,
+    pub is_upside_down: bool,
+    pub is_dashing: bool,
+    pub is_mini: bool,
+    pub is_grounded: bool,
+    pub is_stationary: bool,
+    pub is_falling: bool,
+    pub is_rotating: bool,
+    pub is_sideways: bool,
+    pub did_just_jump: bool,
+    pub is_flipped: bool,
+    pub is_holding: bool,
+    pub ext_data: ExtendedPlayerData,
+}
+
+impl Default for PlayerObjectData {
+    fn default() -> Self {
+        Self {
+            position_x: 0.0,
+            position_y: 0.0,
+            rotation: 0.0,
+            icon_type: crate::shared_capnp::IconType::Unknown,
+            is_visible: false,
+            is_looking_left: false,
+            is_upside_down: false,
+            is_dashing: false,
+            is_mini: false,
+            is_grounded: false,
+            is_stationary: false,
+            is_falling: false,
+            is_rotating: false,
+            is_sideways: false,
+            did_just_jump: false,
+            is_flipped: false,
+            is_holding: false,
+            ext_data: Default::default(),
+        }
+    }
+}
+
+impl PlayerObjectData {
+    pub fn from_reader(reader: crate::game_capnp::player_object_data::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            position_x: reader.get_position_x(),
+            position_y: reader.get_position_y(),
+            rotation: reader.get_rotation(),
+            icon_type: reader.get_icon_type()?,
+            is_visible: reader.get_is_visible(),
+            is_looking_left: reader.get_is_looking_left(),
+            is_upside_down: reader.get_is_upside_down(),
+            is_dashing: reader.get_is_dashing(),
+            is_mini: reader.get_is_mini(),
+            is_grounded: reader.get_is_grounded(),
+            is_stationary: reader.get_is_stationary(),
+            is_falling: reader.get_is_falling(),
+            is_rotating: reader.get_is_rotating(),
+            is_sideways: reader.get_is_sideways(),
+            did_just_jump: reader.get_did_just_jump(),
+            is_flipped: reader.get_is_flipped(),
+            is_holding: reader.get_is_holding(),
+            ext_data: ExtendedPlayerData::from_reader(reader.get_ext_data()?)?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::player_object_data::Builder<'_>) {
+        builder.set_position_x(self.position_x);
+        builder.set_position_y(self.position_y);
+        builder.set_rotation(self.rotation);
+        builder.set_icon_type(self.icon_type);
+        builder.set_is_visible(self.is_visible);
+        builder.set_is_looking_left(self.is_looking_left);
+        builder.set_is_upside_down(self.is_upside_down);
+        builder.set_is_dashing(self.is_dashing);
+        builder.set_is_mini(self.is_mini);
+        builder.set_is_grounded(self.is_grounded);
+        builder.set_is_stationary(self.is_stationary);
+        builder.set_is_falling(self.is_falling);
+        builder.set_is_rotating(self.is_rotating);
+        builder.set_is_sideways(self.is_sideways);
+        builder.set_did_just_jump(self.did_just_jump);
+        builder.set_is_flipped(self.is_flipped);
+        builder.set_is_holding(self.is_holding);
+        self.ext_data.encode(builder.reborrow().init_ext_data());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::player_object_data::Builder<'static>>()
+            + self.ext_data.encoded_size()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlayerLevelMeta {
+    /// This is one of:
+    /// 1. in platformer: user's best time on the level in milliseconds
+    /// 2. in classic: user's best percentage, where 0 is 0% and 2^32-1 is 100%
+    pub progress: u32,
+}
+
+impl Default for PlayerLevelMeta {
+    fn default() -> Self {
+        Self {
+            progress: 0,
+        }
+    }
+}
+
+impl PlayerLevelMeta {
+    pub fn from_reader(reader: crate::game_capnp::player_level_meta::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            progress: reader.get_progress(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::player_level_meta::Builder<'_>) {
+        builder.set_progress(self.progress);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::player_level_meta::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlayerDataMessage {
+    pub data: crate::messages::game::PlayerData,
+    /// at most 64 requests
+    pub data_requests: Vec<i32>,
+    /// pre v2.2.0 legacy events
+    pub event_data: Vec<u8>,
+    pub camera_x: f32,
+    pub camera_y: f32,
+    pub camera_radius: f32,
+    /// wraps
+    pub message_id: u16,
+}
+
+impl Default for PlayerDataMessage {
+    fn default() -> Self {
+        Self {
+            data: Default::default(),
+            data_requests: Default::default(),
+            event_data: Default::default(),
+            camera_x: 0.0,
+            camera_y: 0.0,
+            camera_radius: 0.0,
+            message_id: 0,
+        }
+    }
+}
+
+impl PlayerDataMessage {
+    pub fn from_reader(reader: crate::game_capnp::player_data_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            data: crate::messages::game::PlayerData::from_reader(reader.get_data()?)?,
+            data_requests: reader.get_data_requests()?.iter().collect(),
+            event_data: reader.get_event_data()?.to_vec(),
+            camera_x: reader.get_camera_x(),
+            camera_y: reader.get_camera_y(),
+            camera_radius: reader.get_camera_radius(),
+            message_id: reader.get_message_id(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::player_data_message::Builder<'_>) {
+        self.data.encode(builder.reborrow().init_data());
+        {
+            let mut list = builder.reborrow().init_data_requests(self.data_requests.len() as u32);
+            for (i, v) in self.data_requests.iter().enumerate() {
+                list.set(i as u32, *v);
+            }
+        }
+        builder.set_event_data(&self.event_data);
+        builder.set_camera_x(self.camera_x);
+        builder.set_camera_y(self.camera_y);
+        builder.set_camera_radius(self.camera_radius);
+        builder.set_message_id(self.message_id);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::player_data_message::Builder<'static>>()
+            + self.data.encoded_size()
+            + list_size::<i32>(self.data_requests.len())
+            + data_size(self.event_data.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlayerUpdateMetaMessage {
+    pub meta: PlayerLevelMeta,
+    /// at most 256 requests
+    pub requests: Vec<i32>,
+}
+
+impl Default for PlayerUpdateMetaMessage {
+    fn default() -> Self {
+        Self {
+            meta: Default::default(),
+            requests: Default::default(),
+        }
+    }
+}
+
+impl PlayerUpdateMetaMessage {
+    pub fn from_reader(reader: crate::game_capnp::player_update_meta_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            meta: PlayerLevelMeta::from_reader(reader.get_meta()?)?,
+            requests: reader.get_requests()?.iter().collect(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::player_update_meta_message::Builder<'_>) {
+        self.meta.encode(builder.reborrow().init_meta());
+        {
+            let mut list = builder.reborrow().init_requests(self.requests.len() as u32);
+            for (i, v) in self.requests.iter().enumerate() {
+                list.set(i as u32, *v);
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::player_update_meta_message::Builder<'static>>()
+            + self.meta.encoded_size()
+            + list_size::<i32>(self.requests.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LevelDataMessage {
+    pub players: Vec<crate::messages::game::PlayerData>,
+    pub display_datas: Vec<crate::data::PlayerDisplayData>,
+    pub event_data: Vec<u8>,
+    /// same as client provided value
+    pub message_id: u16,
+}
+
+impl Default for LevelDataMessage {
+    fn default() -> Self {
+        Self {
+            players: Default::default(),
+            display_datas: Default::default(),
+            event_data: Default::default(),
+            message_id: 0,
+        }
+    }
+}
+
+impl LevelDataMessage {
+    pub fn from_reader(reader: crate::game_capnp::level_data_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            players: reader.get_players()?.iter().map(crate::messages::game::PlayerData::from_reader).collect::<Result<_, _>>()?,
+            display_datas: reader.get_display_datas()?.iter().map(crate::data::PlayerDisplayData::from_reader).collect::<Result<_, _>>()?,
+            event_data: reader.get_event_data()?.to_vec(),
+            message_id: reader.get_message_id(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::level_data_message::Builder<'_>) {
+        {
+            let mut list = builder.reborrow().init_players(self.players.len() as u32);
+            for (i, v) in self.players.iter().enumerate() {
+                v.encode(list.reborrow().get(i as u32));
+            }
+        }
+        {
+            let mut list = builder.reborrow().init_display_datas(self.display_datas.len() as u32);
+            for (i, v) in self.display_datas.iter().enumerate() {
+                v.encode(list.reborrow().get(i as u32));
+            }
+        }
+        builder.set_event_data(&self.event_data);
+        builder.set_message_id(self.message_id);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::level_data_message::Builder<'static>>()
+            + composite_list_size(self.players.iter().map(crate::messages::game::PlayerData::encoded_size).sum())
+            + composite_list_size(self.display_datas.iter().map(crate::data::PlayerDisplayData::encoded_size).sum())
+            + data_size(self.event_data.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LevelMetaMessage {
+    pub ids: Vec<i32>,
+    pub metas: Vec<PlayerLevelMeta>,
+}
+
+impl Default for LevelMetaMessage {
+    fn default() -> Self {
+        Self {
+            ids: Default::default(),
+            metas: Default::default(),
+        }
+    }
+}
+
+impl LevelMetaMessage {
+    pub fn from_reader(reader: crate::game_capnp::level_meta_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            ids: reader.get_ids()?.iter().collect(),
+            metas: reader.get_metas()?.iter().map(PlayerLevelMeta::from_reader).collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::level_meta_message::Builder<'_>) {
+        {
+            let mut list = builder.reborrow().init_ids(self.ids.len() as u32);
+            for (i, v) in self.ids.iter().enumerate() {
+                list.set(i as u32, *v);
+            }
+        }
+        {
+            let mut list = builder.reborrow().init_metas(self.metas.len() as u32);
+            for (i, v) in self.metas.iter().enumerate() {
+                v.encode(list.reborrow().get(i as u32));
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::level_meta_message::Builder<'static>>()
+            + list_size::<i32>(self.ids.len())
+            + composite_list_size(self.metas.iter().map(PlayerLevelMeta::encoded_size).sum())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdateIconsMessage {
+    pub icons: crate::shared_models::PlayerIconData,
+}
+
+impl Default for UpdateIconsMessage {
+    fn default() -> Self {
+        Self {
+            icons: Default::default(),
+        }
+    }
+}
+
+impl UpdateIconsMessage {
+    pub fn from_reader(reader: crate::game_capnp::update_icons_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            icons: crate::shared_models::PlayerIconData::from_reader(reader.get_icons()?)?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::update_icons_message::Builder<'_>) {
+        self.icons.encode(builder.reborrow().init_icons());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::update_icons_message::Builder<'static>>()
+            + self.icons.encoded_size()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LevelScript {
+    pub content: String,
+    pub filename: String,
+    pub main: bool,
+    pub signature: Vec<u8>,
+}
+
+impl Default for LevelScript {
+    fn default() -> Self {
+        Self {
+            content: Default::default(),
+            filename: Default::default(),
+            main: false,
+            signature: Default::default(),
+        }
+    }
+}
+
+impl LevelScript {
+    pub fn from_reader(reader: crate::game_capnp::level_script::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            content: reader.get_content()?.to_str()?.to_owned(),
+            filename: reader.get_filename()?.to_str()?.to_owned(),
+            main: reader.get_main(),
+            signature: reader.get_signature()?.to_vec(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::level_script::Builder<'_>) {
+        builder.set_content(self.content.as_str());
+        builder.set_filename(self.filename.as_str());
+        builder.set_main(self.main);
+        builder.set_signature(&self.signature);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::level_script::Builder<'static>>()
+            + text_size(self.content.len())
+            + text_size(self.filename.len())
+            + data_size(self.signature.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SendLevelScriptMessage {
+    pub scripts: Vec<LevelScript>,
+}
+
+impl Default for SendLevelScriptMessage {
+    fn default() -> Self {
+        Self {
+            scripts: Default::default(),
+        }
+    }
+}
+
+impl SendLevelScriptMessage {
+    pub fn from_reader(reader: crate::game_capnp::send_level_script_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            scripts: reader.get_scripts()?.iter().map(LevelScript::from_reader).collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::send_level_script_message::Builder<'_>) {
+        {
+            let mut list = builder.reborrow().init_scripts(self.scripts.len() as u32);
+            for (i, v) in self.scripts.iter().enumerate() {
+                v.encode(list.reborrow().get(i as u32));
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::send_level_script_message::Builder<'static>>()
+            + composite_list_size(self.scripts.iter().map(LevelScript::encoded_size).sum())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct VoiceDataMessage {
+    pub frames: Vec<Vec<u8>>,
+}
+
+impl Default for VoiceDataMessage {
+    fn default() -> Self {
+        Self {
+            frames: Default::default(),
+        }
+    }
+}
+
+impl VoiceDataMessage {
+    pub fn from_reader(reader: crate::game_capnp::voice_data_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            frames: reader.get_frames()?.iter().map(|d| d.map(<[u8]>::to_vec)).collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::voice_data_message::Builder<'_>) {
+        {
+            let mut list = builder.reborrow().init_frames(self.frames.len() as u32);
+            for (i, v) in self.frames.iter().enumerate() {
+                list.set(i as u32, v);
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::voice_data_message::Builder<'static>>()
+            + pointer_list_size(self.frames.len()) + self.frames.iter().map(|d| data_size(d.len())).sum::<usize>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuickChatMessage {
+    pub id: u32,
+}
+
+impl Default for QuickChatMessage {
+    fn default() -> Self {
+        Self {
+            id: 0,
+        }
+    }
+}
+
+impl QuickChatMessage {
+    pub fn from_reader(reader: crate::game_capnp::quick_chat_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            id: reader.get_id(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::quick_chat_message::Builder<'_>) {
+        builder.set_id(self.id);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::quick_chat_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdateUserSettingsMessage {
+    pub settings: crate::shared_models::UserSettings,
+}
+
+impl Default for UpdateUserSettingsMessage {
+    fn default() -> Self {
+        Self {
+            settings: Default::default(),
+        }
+    }
+}
+
+impl UpdateUserSettingsMessage {
+    pub fn from_reader(reader: crate::game_capnp::update_user_settings_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            settings: crate::shared_models::UserSettings::from_reader(reader.get_settings()?)?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::update_user_settings_message::Builder<'_>) {
+        self.settings.encode(builder.reborrow().init_settings());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::update_user_settings_message::Builder<'static>>()
+            + self.settings.encoded_size()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct KickedMessage {
+    pub reason: crate::game_capnp::KickReason,
+    pub message: String,
+}
+
+impl Default for KickedMessage {
+    fn default() -> Self {
+        Self {
+            reason: crate::game_capnp::KickReason::Custom,
+            message: Default::default(),
+        }
+    }
+}
+
+impl KickedMessage {
+    pub fn from_reader(reader: crate::game_capnp::kicked_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            reason: reader.get_reason()?,
+            message: reader.get_message()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::kicked_message::Builder<'_>) {
+        builder.set_reason(self.reason);
+        builder.set_message(self.message.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::kicked_message::Builder<'static>>()
+            + text_size(self.message.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScriptLogsMessage {
+    pub logs: Vec<String>,
+    pub ram_usage: f32,
+}
+
+impl Default for ScriptLogsMessage {
+    fn default() -> Self {
+        Self {
+            logs: Default::default(),
+            ram_usage: 0.0,
+        }
+    }
+}
+
+impl ScriptLogsMessage {
+    pub fn from_reader(reader: crate::game_capnp::script_logs_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            logs: reader.get_logs()?.iter().map(|s| Ok(s?.to_str()?.to_owned())).collect::<Result<_, DataDecodeError>>()?,
+            ram_usage: reader.get_ram_usage(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::script_logs_message::Builder<'_>) {
+        {
+            let mut list = builder.reborrow().init_logs(self.logs.len() as u32);
+            for (i, v) in self.logs.iter().enumerate() {
+                list.set(i as u32, v.as_str());
+            }
+        }
+        builder.set_ram_usage(self.ram_usage);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::script_logs_message::Builder<'static>>()
+            + pointer_list_size(self.logs.len()) + self.logs.iter().map(|s| text_size(s.len())).sum::<usize>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct VoiceBroadcastMessage {
+    pub account_id: i32,
+    pub frames: Vec<Vec<u8>>,
+}
+
+impl Default for VoiceBroadcastMessage {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+            frames: Default::default(),
+        }
+    }
+}
+
+impl VoiceBroadcastMessage {
+    pub fn from_reader(reader: crate::game_capnp::voice_broadcast_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            frames: reader.get_frames()?.iter().map(|d| d.map(<[u8]>::to_vec)).collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::voice_broadcast_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        {
+            let mut list = builder.reborrow().init_frames(self.frames.len() as u32);
+            for (i, v) in self.frames.iter().enumerate() {
+                list.set(i as u32, v);
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::voice_broadcast_message::Builder<'static>>()
+            + pointer_list_size(self.frames.len()) + self.frames.iter().map(|d| data_size(d.len())).sum::<usize>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuickChatBroadcastMessage {
+    pub account_id: i32,
+    pub id: u32,
+}
+
+impl Default for QuickChatBroadcastMessage {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+            id: 0,
+        }
+    }
+}
+
+impl QuickChatBroadcastMessage {
+    pub fn from_reader(reader: crate::game_capnp::quick_chat_broadcast_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            id: reader.get_id(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::quick_chat_broadcast_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        builder.set_id(self.id);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::quick_chat_broadcast_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChatNotPermittedMessage {
+    pub is_voice: bool,
+    pub reason: crate::game_capnp::ChatNotPermittedReason,
+}
+
+impl Default for ChatNotPermittedMessage {
+    fn default() -> Self {
+        Self {
+            is_voice: false,
+            reason: crate::game_capnp::ChatNotPermittedReason::NotLinked,
+        }
+    }
+}
+
+impl ChatNotPermittedMessage {
+    pub fn from_reader(reader: crate::game_capnp::chat_not_permitted_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            is_voice: reader.get_is_voice(),
+            reason: reader.get_reason()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::game_capnp::chat_not_permitted_message::Builder<'_>) {
+        builder.set_is_voice(self.is_voice);
+        builder.set_reason(self.reason);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::game_capnp::chat_not_permitted_message::Builder<'static>>()
+    }
+}
@@ -0,0 +1,3231 @@
+// @generated by build.rs from structs annotated with $rustModel in main.capnp.
+// DO NOT EDIT.
+
+use crate::encoding::{DataDecodeError, size::*};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlayerAccountData {
+    pub account_id: i32,
+    pub user_id: i32,
+    pub username: String,
+}
+
+impl Default for PlayerAccountData {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+            user_id: 0,
+            username: Default::default(),
+        }
+    }
+}
+
+impl PlayerAccountData {
+    pub fn from_reader(reader: crate::main_capnp::player_account_data::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            user_id: reader.get_user_id(),
+            username: reader.get_username()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::player_account_data::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        builder.set_user_id(self.user_id);
+        builder.set_username(self.username.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::player_account_data::Builder<'static>>()
+            + text_size(self.username.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExtendedUserData {
+    pub new_token: String,
+    pub roles: Vec<u8>,
+    pub name_color: Vec<u8>,
+    pub is_moderator: bool,
+    pub can_mute: bool,
+    pub can_ban: bool,
+    pub can_set_password: bool,
+    pub can_edit_roles: bool,
+    pub can_send_features: bool,
+    pub can_rate_features: bool,
+    pub can_name_rooms: bool,
+}
+
+impl Default for ExtendedUserData {
+    fn default() -> Self {
+        Self {
+            new_token: Default::default(),
+            roles: Default::default(),
+            name_color: Default::default(),
+            is_moderator: false,
+            can_mute: false,
+            can_ban: false,
+            can_set_password: false,
+            can_edit_roles: false,
+            can_send_features: false,
+            can_rate_features: false,
+            can_name_rooms: false,
+        }
+    }
+}
+
+impl ExtendedUserData {
+    pub fn from_reader(reader: crate::main_capnp::extended_user_data::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            new_token: reader.get_new_token()?.to_str()?.to_owned(),
+            roles: reader.get_roles()?.iter().collect(),
+            name_color: reader.get_name_color()?.to_vec(),
+            is_moderator: reader.get_is_moderator(),
+            can_mute: reader.get_can_mute(),
+            can_ban: reader.get_can_ban(),
+            can_set_password: reader.get_can_set_password(),
+            can_edit_roles: reader.get_can_edit_roles(),
+            can_send_features: reader.get_can_send_features(),
+            can_rate_features: reader.get_can_rate_features(),
+            can_name_rooms: reader.get_can_name_rooms(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::extended_user_data::Builder<'_>) {
+        builder.set_new_token(self.new_token.as_str());
+        {
+            let mut list = builder.reborrow().init_roles(self.roles.len() as u32);
+            for (i, v) in self.roles.iter().enumerate() {
+                list.set(i as u32, *v);
+            }
+        }
+        builder.set_name_color(&self.name_color);
+        builder.set_is_moderator(self.is_moderator);
+        builder.set_can_mute(self.can_mute);
+        builder.set_can_ban(self.can_ban);
+        builder.set_can_set_password(self.can_set_password);
+        builder.set_can_edit_roles(self.can_edit_roles);
+        builder.set_can_send_features(self.can_send_features);
+        builder.set_can_rate_features(self.can_rate_features);
+        builder.set_can_name_rooms(self.can_name_rooms);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::extended_user_data::Builder<'static>>()
+            + text_size(self.new_token.len())
+            + list_size::<u8>(self.roles.len())
+            + data_size(self.name_color.len())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LoginFailedMessage {
+    pub reason: crate::main_capnp::LoginFailedReason,
+}
+
+impl Default for LoginFailedMessage {
+    fn default() -> Self {
+        Self {
+            reason: crate::main_capnp::LoginFailedReason::InvalidUserToken,
+        }
+    }
+}
+
+impl LoginFailedMessage {
+    pub fn from_reader(reader: crate::main_capnp::login_failed_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            reason: reader.get_reason()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::login_failed_message::Builder<'_>) {
+        builder.set_reason(self.reason);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::login_failed_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoginRequiredMessage {
+    pub argon_url: String,
+}
+
+impl Default for LoginRequiredMessage {
+    fn default() -> Self {
+        Self {
+            argon_url: Default::default(),
+        }
+    }
+}
+
+impl LoginRequiredMessage {
+    pub fn from_reader(reader: crate::main_capnp::login_required_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            argon_url: reader.get_argon_url()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::login_required_message::Builder<'_>) {
+        builder.set_argon_url(self.argon_url.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::login_required_message::Builder<'static>>()
+            + text_size(self.argon_url.len())
+    }
+}
+
+/// Notifies the user of a ban
+#[derive(Clone, Debug, PartialEq)]
+pub struct BannedMessage {
+    pub reason: String,
+    pub expires_at: i64,
+}
+
+impl Default for BannedMessage {
+    fn default() -> Self {
+        Self {
+            reason: Default::default(),
+            expires_at: 0,
+        }
+    }
+}
+
+impl BannedMessage {
+    pub fn from_reader(reader: crate::main_capnp::banned_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            reason: reader.get_reason()?.to_str()?.to_owned(),
+            expires_at: reader.get_expires_at(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::banned_message::Builder<'_>) {
+        builder.set_reason(self.reason.as_str());
+        builder.set_expires_at(self.expires_at);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::banned_message::Builder<'static>>()
+            + text_size(self.reason.len())
+    }
+}
+
+/// Notifies the user of a mute
+#[derive(Clone, Debug, PartialEq)]
+pub struct MutedMessage {
+    pub reason: String,
+    pub expires_at: i64,
+}
+
+impl Default for MutedMessage {
+    fn default() -> Self {
+        Self {
+            reason: Default::default(),
+            expires_at: 0,
+        }
+    }
+}
+
+impl MutedMessage {
+    pub fn from_reader(reader: crate::main_capnp::muted_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            reason: reader.get_reason()?.to_str()?.to_owned(),
+            expires_at: reader.get_expires_at(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::muted_message::Builder<'_>) {
+        builder.set_reason(self.reason.as_str());
+        builder.set_expires_at(self.expires_at);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::muted_message::Builder<'static>>()
+            + text_size(self.reason.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserDataChangedMessage {
+    pub user_data: ExtendedUserData,
+}
+
+impl Default for UserDataChangedMessage {
+    fn default() -> Self {
+        Self {
+            user_data: Default::default(),
+        }
+    }
+}
+
+impl UserDataChangedMessage {
+    pub fn from_reader(reader: crate::main_capnp::user_data_changed_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            user_data: ExtendedUserData::from_reader(reader.get_user_data()?)?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::user_data_changed_message::Builder<'_>) {
+        self.user_data.encode(builder.reborrow().init_user_data());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::user_data_changed_message::Builder<'static>>()
+            + self.user_data.encoded_size()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequestPlayerCountsMessage {
+    pub levels: Vec<u64>,
+}
+
+impl Default for RequestPlayerCountsMessage {
+    fn default() -> Self {
+        Self {
+            levels: Default::default(),
+        }
+    }
+}
+
+impl RequestPlayerCountsMessage {
+    pub fn from_reader(reader: crate::main_capnp::request_player_counts_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            levels: reader.get_levels()?.iter().collect(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::request_player_counts_message::Builder<'_>) {
+        {
+            let mut list = builder.reborrow().init_levels(self.levels.len() as u32);
+            for (i, v) in self.levels.iter().enumerate() {
+                list.set(i as u32, *v);
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::request_player_counts_message::Builder<'static>>()
+            + list_size::<u64>(self.levels.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequestGlobalPlayerListMessage {
+    pub name_filter: String,
+}
+
+impl Default for RequestGlobalPlayerListMessage {
+    fn default() -> Self {
+        Self {
+            name_filter: Default::default(),
+        }
+    }
+}
+
+impl RequestGlobalPlayerListMessage {
+    pub fn from_reader(reader: crate::main_capnp::request_global_player_list_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            name_filter: reader.get_name_filter()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::request_global_player_list_message::Builder<'_>) {
+        builder.set_name_filter(self.name_filter.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::request_global_player_list_message::Builder<'static>>()
+            + text_size(self.name_filter.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdateUserSettingsMessage {
+    pub settings: crate::shared_models::UserSettings,
+}
+
+impl Default for UpdateUserSettingsMessage {
+    fn default() -> Self {
+        Self {
+            settings: Default::default(),
+        }
+    }
+}
+
+impl UpdateUserSettingsMessage {
+    pub fn from_reader(reader: crate::main_capnp::update_user_settings_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            settings: crate::shared_models::UserSettings::from_reader(reader.get_settings()?)?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::update_user_settings_message::Builder<'_>) {
+        self.settings.encode(builder.reborrow().init_settings());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::update_user_settings_message::Builder<'static>>()
+            + self.settings.encoded_size()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateRoomMessage {
+    pub name: String,
+    pub passcode: u32,
+    pub settings: crate::messages::main::RoomSettings,
+}
+
+impl Default for CreateRoomMessage {
+    fn default() -> Self {
+        Self {
+            name: Default::default(),
+            passcode: 0,
+            settings: Default::default(),
+        }
+    }
+}
+
+impl CreateRoomMessage {
+    pub fn from_reader(reader: crate::main_capnp::create_room_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            name: reader.get_name()?.to_str()?.to_owned(),
+            passcode: reader.get_passcode(),
+            settings: crate::messages::main::RoomSettings::from_reader(reader.get_settings()?)?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::create_room_message::Builder<'_>) {
+        builder.set_name(self.name.as_str());
+        builder.set_passcode(self.passcode);
+        self.settings.encode(builder.reborrow().init_settings());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::create_room_message::Builder<'static>>()
+            + text_size(self.name.len())
+            + self.settings.encoded_size()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JoinRoomMessage {
+    pub room_id: u32,
+    pub passcode: u32,
+}
+
+impl Default for JoinRoomMessage {
+    fn default() -> Self {
+        Self {
+            room_id: 0,
+            passcode: 0,
+        }
+    }
+}
+
+impl JoinRoomMessage {
+    pub fn from_reader(reader: crate::main_capnp::join_room_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            room_id: reader.get_room_id(),
+            passcode: reader.get_passcode(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::join_room_message::Builder<'_>) {
+        builder.set_room_id(self.room_id);
+        builder.set_passcode(self.passcode);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::join_room_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JoinRoomByTokenMessage {
+    pub token: u64,
+}
+
+impl Default for JoinRoomByTokenMessage {
+    fn default() -> Self {
+        Self {
+            token: 0,
+        }
+    }
+}
+
+impl JoinRoomByTokenMessage {
+    pub fn from_reader(reader: crate::main_capnp::join_room_by_token_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            token: reader.get_token(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::join_room_by_token_message::Builder<'_>) {
+        builder.set_token(self.token);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::join_room_by_token_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequestRoomPlayersMessage {
+    pub name_filter: String,
+}
+
+impl Default for RequestRoomPlayersMessage {
+    fn default() -> Self {
+        Self {
+            name_filter: Default::default(),
+        }
+    }
+}
+
+impl RequestRoomPlayersMessage {
+    pub fn from_reader(reader: crate::main_capnp::request_room_players_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            name_filter: reader.get_name_filter()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::request_room_players_message::Builder<'_>) {
+        builder.set_name_filter(self.name_filter.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::request_room_players_message::Builder<'static>>()
+            + text_size(self.name_filter.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequestRoomListMessage {
+    pub name_filter: String,
+    pub page: u32,
+}
+
+impl Default for RequestRoomListMessage {
+    fn default() -> Self {
+        Self {
+            name_filter: Default::default(),
+            page: 0,
+        }
+    }
+}
+
+impl RequestRoomListMessage {
+    pub fn from_reader(reader: crate::main_capnp::request_room_list_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            name_filter: reader.get_name_filter()?.to_str()?.to_owned(),
+            page: reader.get_page(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::request_room_list_message::Builder<'_>) {
+        builder.set_name_filter(self.name_filter.as_str());
+        builder.set_page(self.page);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::request_room_list_message::Builder<'static>>()
+            + text_size(self.name_filter.len())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AssignTeamMessage {
+    pub account_id: i32,
+    pub team_id: u16,
+}
+
+impl Default for AssignTeamMessage {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+            team_id: 0,
+        }
+    }
+}
+
+impl AssignTeamMessage {
+    pub fn from_reader(reader: crate::main_capnp::assign_team_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            team_id: reader.get_team_id(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::assign_team_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        builder.set_team_id(self.team_id);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::assign_team_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CreateTeamMessage {
+    pub color: u32,
+}
+
+impl Default for CreateTeamMessage {
+    fn default() -> Self {
+        Self {
+            color: 0,
+        }
+    }
+}
+
+impl CreateTeamMessage {
+    pub fn from_reader(reader: crate::main_capnp::create_team_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            color: reader.get_color(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::create_team_message::Builder<'_>) {
+        builder.set_color(self.color);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::create_team_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DeleteTeamMessage {
+    pub team_id: u16,
+}
+
+impl Default for DeleteTeamMessage {
+    fn default() -> Self {
+        Self {
+            team_id: 0,
+        }
+    }
+}
+
+impl DeleteTeamMessage {
+    pub fn from_reader(reader: crate::main_capnp::delete_team_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            team_id: reader.get_team_id(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::delete_team_message::Builder<'_>) {
+        builder.set_team_id(self.team_id);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::delete_team_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UpdateTeamMessage {
+    pub team_id: u16,
+    pub color: u32,
+}
+
+impl Default for UpdateTeamMessage {
+    fn default() -> Self {
+        Self {
+            team_id: 0,
+            color: 0,
+        }
+    }
+}
+
+impl UpdateTeamMessage {
+    pub fn from_reader(reader: crate::main_capnp::update_team_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            team_id: reader.get_team_id(),
+            color: reader.get_color(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::update_team_message::Builder<'_>) {
+        builder.set_team_id(self.team_id);
+        builder.set_color(self.color);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::update_team_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdateRoomSettingsMessage {
+    pub settings: crate::messages::main::RoomSettings,
+}
+
+impl Default for UpdateRoomSettingsMessage {
+    fn default() -> Self {
+        Self {
+            settings: Default::default(),
+        }
+    }
+}
+
+impl UpdateRoomSettingsMessage {
+    pub fn from_reader(reader: crate::main_capnp::update_room_settings_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            settings: crate::messages::main::RoomSettings::from_reader(reader.get_settings()?)?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::update_room_settings_message::Builder<'_>) {
+        self.settings.encode(builder.reborrow().init_settings());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::update_room_settings_message::Builder<'static>>()
+            + self.settings.encoded_size()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InvitePlayerMessage {
+    pub player: i32,
+}
+
+impl Default for InvitePlayerMessage {
+    fn default() -> Self {
+        Self {
+            player: 0,
+        }
+    }
+}
+
+impl InvitePlayerMessage {
+    pub fn from_reader(reader: crate::main_capnp::invite_player_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            player: reader.get_player(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::invite_player_message::Builder<'_>) {
+        builder.set_player(self.player);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::invite_player_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UpdatePinnedLevelMessage {
+    pub id: u64,
+}
+
+impl Default for UpdatePinnedLevelMessage {
+    fn default() -> Self {
+        Self {
+            id: 0,
+        }
+    }
+}
+
+impl UpdatePinnedLevelMessage {
+    pub fn from_reader(reader: crate::main_capnp::update_pinned_level_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            id: reader.get_id(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::update_pinned_level_message::Builder<'_>) {
+        builder.set_id(self.id);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::update_pinned_level_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TeamCreationResultMessage {
+    pub success: bool,
+    pub team_count: u16,
+}
+
+impl Default for TeamCreationResultMessage {
+    fn default() -> Self {
+        Self {
+            success: false,
+            team_count: 0,
+        }
+    }
+}
+
+impl TeamCreationResultMessage {
+    pub fn from_reader(reader: crate::main_capnp::team_creation_result_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            success: reader.get_success(),
+            team_count: reader.get_team_count(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::team_creation_result_message::Builder<'_>) {
+        builder.set_success(self.success);
+        builder.set_team_count(self.team_count);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::team_creation_result_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TeamChangedMessage {
+    pub team_id: u16,
+}
+
+impl Default for TeamChangedMessage {
+    fn default() -> Self {
+        Self {
+            team_id: 0,
+        }
+    }
+}
+
+impl TeamChangedMessage {
+    pub fn from_reader(reader: crate::main_capnp::team_changed_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            team_id: reader.get_team_id(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::team_changed_message::Builder<'_>) {
+        builder.set_team_id(self.team_id);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::team_changed_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoomPlayer {
+    pub account_data: PlayerAccountData,
+    pub cube: i16,
+    pub color1: u16,
+    pub color2: u16,
+    pub glow_color: u16,
+    pub session: u64,
+    pub team_id: u16,
+    pub special_data: crate::data::SpecialUserData,
+}
+
+impl Default for RoomPlayer {
+    fn default() -> Self {
+        Self {
+            account_data: Default::default(),
+            cube: 0,
+            color1: 0,
+            color2: 0,
+            glow_color: 0,
+            session: 0,
+            team_id: 0,
+            special_data: Default::default(),
+        }
+    }
+}
+
+impl RoomPlayer {
+    pub fn from_reader(reader: crate::main_capnp::room_player::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_data: PlayerAccountData::from_reader(reader.get_account_data()?)?,
+            cube: reader.get_cube(),
+            color1: reader.get_color1(),
+            color2: reader.get_color2(),
+            glow_color: reader.get_glow_color(),
+            session: reader.get_session(),
+            team_id: reader.get_team_id(),
+            special_data: crate::data::SpecialUserData::from_reader(reader.get_special_data()?)?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::room_player::Builder<'_>) {
+        self.account_data.encode(builder.reborrow().init_account_data());
+        builder.set_cube(self.cube);
+        builder.set_color1(self.color1);
+        builder.set_color2(self.color2);
+        builder.set_glow_color(self.glow_color);
+        builder.set_session(self.session);
+        builder.set_team_id(self.team_id);
+        self.special_data.encode(builder.reborrow().init_special_data());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::room_player::Builder<'static>>()
+            + self.account_data.encoded_size()
+            + self.special_data.encoded_size()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MinimalRoomPlayer {
+    pub account_data: PlayerAccountData,
+    pub cube: i16,
+    pub color1: u16,
+    pub color2: u16,
+    pub glow_color: u16,
+}
+
+impl Default for MinimalRoomPlayer {
+    fn default() -> Self {
+        Self {
+            account_data: Default::default(),
+            cube: 0,
+            color1: 0,
+            color2: 0,
+            glow_color: 0,
+        }
+    }
+}
+
+impl MinimalRoomPlayer {
+    pub fn from_reader(reader: crate::main_capnp::minimal_room_player::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_data: PlayerAccountData::from_reader(reader.get_account_data()?)?,
+            cube: reader.get_cube(),
+            color1: reader.get_color1(),
+            color2: reader.get_color2(),
+            glow_color: reader.get_glow_color(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::minimal_room_player::Builder<'_>) {
+        self.account_data.encode(builder.reborrow().init_account_data());
+        builder.set_cube(self.cube);
+        builder.set_color1(self.color1);
+        builder.set_color2(self.color2);
+        builder.set_glow_color(self.glow_color);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::minimal_room_player::Builder<'static>>()
+            + self.account_data.encoded_size()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TeamMembersMessage {
+    pub members: Vec<i32>,
+    pub team_ids: Vec<u8>,
+}
+
+impl Default for TeamMembersMessage {
+    fn default() -> Self {
+        Self {
+            members: Default::default(),
+            team_ids: Default::default(),
+        }
+    }
+}
+
+impl TeamMembersMessage {
+    pub fn from_reader(reader: crate::main_capnp::team_members_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            members: reader.get_members()?.iter().collect(),
+            team_ids: reader.get_team_ids()?.iter().collect(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::team_members_message::Builder<'_>) {
+        {
+            let mut list = builder.reborrow().init_members(self.members.len() as u32);
+            for (i, v) in self.members.iter().enumerate() {
+                list.set(i as u32, *v);
+            }
+        }
+        {
+            let mut list = builder.reborrow().init_team_ids(self.team_ids.len() as u32);
+            for (i, v) in self.team_ids.iter().enumerate() {
+                list.set(i as u32, *v);
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::team_members_message::Builder<'static>>()
+            + list_size::<i32>(self.members.len())
+            + list_size::<u8>(self.team_ids.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoomStateMessage {
+    pub room_id: u32,
+    pub room_owner: i32,
+    pub room_name: String,
+    pub players: Vec<RoomPlayer>,
+    pub settings: crate::messages::main::RoomSettings,
+    pub teams: Vec<u32>,
+    pub passcode: u32,
+    pub player_count: u32,
+    pub pinned_level: u64,
+}
+
+impl Default for RoomStateMessage {
+    fn default() -> Self {
+        Self {
+            room_id: 0,
+            room_owner: 0,
+            room_name: Default::default(),
+            players: Default::default(),
+            settings: Default::default(),
+            teams: Default::default(),
+            passcode: 0,
+            player_count: 0,
+            pinned_level: 0,
+        }
+    }
+}
+
+impl RoomStateMessage {
+    pub fn from_reader(reader: crate::main_capnp::room_state_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            room_id: reader.get_room_id(),
+            room_owner: reader.get_room_owner(),
+            room_name: reader.get_room_name()?.to_str()?.to_owned(),
+            players: reader.get_players()?.iter().map(RoomPlayer::from_reader).collect::<Result<_, _>>()?,
+            settings: crate::messages::main::RoomSettings::from_reader(reader.get_settings()?)?,
+            teams: reader.get_teams()?.iter().collect(),
+            passcode: reader.get_passcode(),
+            player_count: reader.get_player_count(),
+            pinned_level: reader.get_pinned_level(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::room_state_message::Builder<'_>) {
+        builder.set_room_id(self.room_id);
+        builder.set_room_owner(self.room_owner);
+        builder.set_room_name(self.room_name.as_str());
+        {
+            let mut list = builder.reborrow().init_players(self.players.len() as u32);
+            for (i, v) in self.players.iter().enumerate() {
+                v.encode(list.reborrow().get(i as u32));
+            }
+        }
+        self.settings.encode(builder.reborrow().init_settings());
+        {
+            let mut list = builder.reborrow().init_teams(self.teams.len() as u32);
+            for (i, v) in self.teams.iter().enumerate() {
+                list.set(i as u32, *v);
+            }
+        }
+        builder.set_passcode(self.passcode);
+        builder.set_player_count(self.player_count);
+        builder.set_pinned_level(self.pinned_level);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::room_state_message::Builder<'static>>()
+            + text_size(self.room_name.len())
+            + composite_list_size(self.players.iter().map(RoomPlayer::encoded_size).sum())
+            + self.settings.encoded_size()
+            + list_size::<u32>(self.teams.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoomPlayersMessage {
+    pub players: Vec<RoomPlayer>,
+}
+
+impl Default for RoomPlayersMessage {
+    fn default() -> Self {
+        Self {
+            players: Default::default(),
+        }
+    }
+}
+
+impl RoomPlayersMessage {
+    pub fn from_reader(reader: crate::main_capnp::room_players_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            players: reader.get_players()?.iter().map(RoomPlayer::from_reader).collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::room_players_message::Builder<'_>) {
+        {
+            let mut list = builder.reborrow().init_players(self.players.len() as u32);
+            for (i, v) in self.players.iter().enumerate() {
+                v.encode(list.reborrow().get(i as u32));
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::room_players_message::Builder<'static>>()
+            + composite_list_size(self.players.iter().map(RoomPlayer::encoded_size).sum())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TeamsUpdatedMessage {
+    pub teams: Vec<u32>,
+}
+
+impl Default for TeamsUpdatedMessage {
+    fn default() -> Self {
+        Self {
+            teams: Default::default(),
+        }
+    }
+}
+
+impl TeamsUpdatedMessage {
+    pub fn from_reader(reader: crate::main_capnp::teams_updated_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            teams: reader.get_teams()?.iter().collect(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::teams_updated_message::Builder<'_>) {
+        {
+            let mut list = builder.reborrow().init_teams(self.teams.len() as u32);
+            for (i, v) in self.teams.iter().enumerate() {
+                list.set(i as u32, *v);
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::teams_updated_message::Builder<'static>>()
+            + list_size::<u32>(self.teams.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoomSettingsUpdatedMessage {
+    pub settings: crate::messages::main::RoomSettings,
+}
+
+impl Default for RoomSettingsUpdatedMessage {
+    fn default() -> Self {
+        Self {
+            settings: Default::default(),
+        }
+    }
+}
+
+impl RoomSettingsUpdatedMessage {
+    pub fn from_reader(reader: crate::main_capnp::room_settings_updated_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            settings: crate::messages::main::RoomSettings::from_reader(reader.get_settings()?)?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::room_settings_updated_message::Builder<'_>) {
+        self.settings.encode(builder.reborrow().init_settings());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::room_settings_updated_message::Builder<'static>>()
+            + self.settings.encoded_size()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PinnedLevelUpdatedMessage {
+    pub id: u64,
+}
+
+impl Default for PinnedLevelUpdatedMessage {
+    fn default() -> Self {
+        Self {
+            id: 0,
+        }
+    }
+}
+
+impl PinnedLevelUpdatedMessage {
+    pub fn from_reader(reader: crate::main_capnp::pinned_level_updated_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            id: reader.get_id(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::pinned_level_updated_message::Builder<'_>) {
+        builder.set_id(self.id);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::pinned_level_updated_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RoomJoinFailedMessage {
+    pub reason: crate::main_capnp::RoomJoinFailedReason,
+}
+
+impl Default for RoomJoinFailedMessage {
+    fn default() -> Self {
+        Self {
+            reason: crate::main_capnp::RoomJoinFailedReason::NotFound,
+        }
+    }
+}
+
+impl RoomJoinFailedMessage {
+    pub fn from_reader(reader: crate::main_capnp::room_join_failed_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            reason: reader.get_reason()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::room_join_failed_message::Builder<'_>) {
+        builder.set_reason(self.reason);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::room_join_failed_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RoomCreateFailedMessage {
+    pub reason: crate::main_capnp::RoomCreateFailedReason,
+}
+
+impl Default for RoomCreateFailedMessage {
+    fn default() -> Self {
+        Self {
+            reason: crate::main_capnp::RoomCreateFailedReason::InvalidName,
+        }
+    }
+}
+
+impl RoomCreateFailedMessage {
+    pub fn from_reader(reader: crate::main_capnp::room_create_failed_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            reason: reader.get_reason()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::room_create_failed_message::Builder<'_>) {
+        builder.set_reason(self.reason);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::room_create_failed_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoomListingInfo {
+    pub room_id: u32,
+    pub room_name: String,
+    pub room_owner: RoomPlayer,
+    pub original_owner_id: i32,
+    pub player_count: u32,
+    pub has_password: bool,
+    pub settings: crate::messages::main::RoomSettings,
+}
+
+impl Default for RoomListingInfo {
+    fn default() -> Self {
+        Self {
+            room_id: 0,
+            room_name: Default::default(),
+            room_owner: Default::default(),
+            original_owner_id: 0,
+            player_count: 0,
+            has_password: false,
+            settings: Default::default(),
+        }
+    }
+}
+
+impl RoomListingInfo {
+    pub fn from_reader(reader: crate::main_capnp::room_listing_info::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            room_id: reader.get_room_id(),
+            room_name: reader.get_room_name()?.to_str()?.to_owned(),
+            room_owner: RoomPlayer::from_reader(reader.get_room_owner()?)?,
+            original_owner_id: reader.get_original_owner_id(),
+            player_count: reader.get_player_count(),
+            has_password: reader.get_has_password(),
+            settings: crate::messages::main::RoomSettings::from_reader(reader.get_settings()?)?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::room_listing_info::Builder<'_>) {
+        builder.set_room_id(self.room_id);
+        builder.set_room_name(self.room_name.as_str());
+        self.room_owner.encode(builder.reborrow().init_room_owner());
+        builder.set_original_owner_id(self.original_owner_id);
+        builder.set_player_count(self.player_count);
+        builder.set_has_password(self.has_password);
+        self.settings.encode(builder.reborrow().init_settings());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::room_listing_info::Builder<'static>>()
+            + text_size(self.room_name.len())
+            + self.room_owner.encoded_size()
+            + self.settings.encoded_size()
+    }
+}
+
+/// Notifies the user of a room ban
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoomBannedMessage {
+    pub reason: String,
+    pub expires_at: i64,
+}
+
+impl Default for RoomBannedMessage {
+    fn default() -> Self {
+        Self {
+            reason: Default::default(),
+            expires_at: 0,
+        }
+    }
+}
+
+impl RoomBannedMessage {
+    pub fn from_reader(reader: crate::main_capnp::room_banned_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            reason: reader.get_reason()?.to_str()?.to_owned(),
+            expires_at: reader.get_expires_at(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::room_banned_message::Builder<'_>) {
+        builder.set_reason(self.reason.as_str());
+        builder.set_expires_at(self.expires_at);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::room_banned_message::Builder<'static>>()
+            + text_size(self.reason.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoomListMessage {
+    pub rooms: Vec<RoomListingInfo>,
+    pub total: u32,
+    pub page: u16,
+}
+
+impl Default for RoomListMessage {
+    fn default() -> Self {
+        Self {
+            rooms: Default::default(),
+            total: 0,
+            page: 0,
+        }
+    }
+}
+
+impl RoomListMessage {
+    pub fn from_reader(reader: crate::main_capnp::room_list_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            rooms: reader.get_rooms()?.iter().map(RoomListingInfo::from_reader).collect::<Result<_, _>>()?,
+            total: reader.get_total(),
+            page: reader.get_page(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::room_list_message::Builder<'_>) {
+        {
+            let mut list = builder.reborrow().init_rooms(self.rooms.len() as u32);
+            for (i, v) in self.rooms.iter().enumerate() {
+                v.encode(list.reborrow().get(i as u32));
+            }
+        }
+        builder.set_total(self.total);
+        builder.set_page(self.page);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::room_list_message::Builder<'static>>()
+            + composite_list_size(self.rooms.iter().map(RoomListingInfo::encoded_size).sum())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvitedMessage {
+    pub invited_by: PlayerAccountData,
+    pub token: u64,
+}
+
+impl Default for InvitedMessage {
+    fn default() -> Self {
+        Self {
+            invited_by: Default::default(),
+            token: 0,
+        }
+    }
+}
+
+impl InvitedMessage {
+    pub fn from_reader(reader: crate::main_capnp::invited_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            invited_by: PlayerAccountData::from_reader(reader.get_invited_by()?)?,
+            token: reader.get_token(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::invited_message::Builder<'_>) {
+        self.invited_by.encode(builder.reborrow().init_invited_by());
+        builder.set_token(self.token);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::invited_message::Builder<'static>>()
+            + self.invited_by.encoded_size()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InviteTokenCreatedMessage {
+    pub token: u64,
+}
+
+impl Default for InviteTokenCreatedMessage {
+    fn default() -> Self {
+        Self {
+            token: 0,
+        }
+    }
+}
+
+impl InviteTokenCreatedMessage {
+    pub fn from_reader(reader: crate::main_capnp::invite_token_created_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            token: reader.get_token(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::invite_token_created_message::Builder<'_>) {
+        builder.set_token(self.token);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::invite_token_created_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JoinSessionMessage {
+    pub session_id: u64,
+    pub author_id: i32,
+}
+
+impl Default for JoinSessionMessage {
+    fn default() -> Self {
+        Self {
+            session_id: 0,
+            author_id: 0,
+        }
+    }
+}
+
+impl JoinSessionMessage {
+    pub fn from_reader(reader: crate::main_capnp::join_session_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            session_id: reader.get_session_id(),
+            author_id: reader.get_author_id(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::join_session_message::Builder<'_>) {
+        builder.set_session_id(self.session_id);
+        builder.set_author_id(self.author_id);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::join_session_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JoinFailedMessage {
+    pub reason: crate::main_capnp::JoinSessionFailedReason,
+}
+
+impl Default for JoinFailedMessage {
+    fn default() -> Self {
+        Self {
+            reason: crate::main_capnp::JoinSessionFailedReason::InvalidRoom,
+        }
+    }
+}
+
+impl JoinFailedMessage {
+    pub fn from_reader(reader: crate::main_capnp::join_failed_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            reason: reader.get_reason()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::join_failed_message::Builder<'_>) {
+        builder.set_reason(self.reason);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::join_failed_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WarpPlayerMessage {
+    pub session: u64,
+}
+
+impl Default for WarpPlayerMessage {
+    fn default() -> Self {
+        Self {
+            session: 0,
+        }
+    }
+}
+
+impl WarpPlayerMessage {
+    pub fn from_reader(reader: crate::main_capnp::warp_player_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            session: reader.get_session(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::warp_player_message::Builder<'_>) {
+        builder.set_session(self.session);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::warp_player_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RoomWarpMessage {
+    pub session: u64,
+}
+
+impl Default for RoomWarpMessage {
+    fn default() -> Self {
+        Self {
+            session: 0,
+        }
+    }
+}
+
+impl RoomWarpMessage {
+    pub fn from_reader(reader: crate::main_capnp::room_warp_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            session: reader.get_session(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::room_warp_message::Builder<'_>) {
+        builder.set_session(self.session);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::room_warp_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlayerCountsMessage {
+    pub level_ids: Vec<u64>,
+    pub counts: Vec<u16>,
+}
+
+impl Default for PlayerCountsMessage {
+    fn default() -> Self {
+        Self {
+            level_ids: Default::default(),
+            counts: Default::default(),
+        }
+    }
+}
+
+impl PlayerCountsMessage {
+    pub fn from_reader(reader: crate::main_capnp::player_counts_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            level_ids: reader.get_level_ids()?.iter().collect(),
+            counts: reader.get_counts()?.iter().collect(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::player_counts_message::Builder<'_>) {
+        {
+            let mut list = builder.reborrow().init_level_ids(self.level_ids.len() as u32);
+            for (i, v) in self.level_ids.iter().enumerate() {
+                list.set(i as u32, *v);
+            }
+        }
+        {
+            let mut list = builder.reborrow().init_counts(self.counts.len() as u32);
+            for (i, v) in self.counts.iter().enumerate() {
+                list.set(i as u32, *v);
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::player_counts_message::Builder<'static>>()
+            + list_size::<u64>(self.level_ids.len())
+            + list_size::<u16>(self.counts.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GlobalPlayersMessage {
+    pub players: Vec<MinimalRoomPlayer>,
+}
+
+impl Default for GlobalPlayersMessage {
+    fn default() -> Self {
+        Self {
+            players: Default::default(),
+        }
+    }
+}
+
+impl GlobalPlayersMessage {
+    pub fn from_reader(reader: crate::main_capnp::global_players_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            players: reader.get_players()?.iter().map(MinimalRoomPlayer::from_reader).collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::global_players_message::Builder<'_>) {
+        {
+            let mut list = builder.reborrow().init_players(self.players.len() as u32);
+            for (i, v) in self.players.iter().enumerate() {
+                v.encode(list.reborrow().get(i as u32));
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::global_players_message::Builder<'static>>()
+            + composite_list_size(self.players.iter().map(MinimalRoomPlayer::encoded_size).sum())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LevelListMessage {
+    pub level_ids: Vec<u64>,
+    pub player_counts: Vec<u16>,
+}
+
+impl Default for LevelListMessage {
+    fn default() -> Self {
+        Self {
+            level_ids: Default::default(),
+            player_counts: Default::default(),
+        }
+    }
+}
+
+impl LevelListMessage {
+    pub fn from_reader(reader: crate::main_capnp::level_list_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            level_ids: reader.get_level_ids()?.iter().collect(),
+            player_counts: reader.get_player_counts()?.iter().collect(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::level_list_message::Builder<'_>) {
+        {
+            let mut list = builder.reborrow().init_level_ids(self.level_ids.len() as u32);
+            for (i, v) in self.level_ids.iter().enumerate() {
+                list.set(i as u32, *v);
+            }
+        }
+        {
+            let mut list = builder.reborrow().init_player_counts(self.player_counts.len() as u32);
+            for (i, v) in self.player_counts.iter().enumerate() {
+                list.set(i as u32, *v);
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::level_list_message::Builder<'static>>()
+            + list_size::<u64>(self.level_ids.len())
+            + list_size::<u16>(self.player_counts.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct KickedMessage {
+    pub reason: crate::main_capnp::KickReason,
+    pub message: String,
+}
+
+impl Default for KickedMessage {
+    fn default() -> Self {
+        Self {
+            reason: crate::main_capnp::KickReason::Custom,
+            message: Default::default(),
+        }
+    }
+}
+
+impl KickedMessage {
+    pub fn from_reader(reader: crate::main_capnp::kicked_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            reason: reader.get_reason()?,
+            message: reader.get_message()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::kicked_message::Builder<'_>) {
+        builder.set_reason(self.reason);
+        builder.set_message(self.message.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::kicked_message::Builder<'static>>()
+            + text_size(self.message.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NoticeMessage {
+    pub sender_id: i32,
+    pub sender_name: String,
+    pub message: String,
+    pub can_reply: bool,
+    pub is_reply: bool,
+}
+
+impl Default for NoticeMessage {
+    fn default() -> Self {
+        Self {
+            sender_id: 0,
+            sender_name: Default::default(),
+            message: Default::default(),
+            can_reply: false,
+            is_reply: false,
+        }
+    }
+}
+
+impl NoticeMessage {
+    pub fn from_reader(reader: crate::main_capnp::notice_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            sender_id: reader.get_sender_id(),
+            sender_name: reader.get_sender_name()?.to_str()?.to_owned(),
+            message: reader.get_message()?.to_str()?.to_owned(),
+            can_reply: reader.get_can_reply(),
+            is_reply: reader.get_is_reply(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::notice_message::Builder<'_>) {
+        builder.set_sender_id(self.sender_id);
+        builder.set_sender_name(self.sender_name.as_str());
+        builder.set_message(self.message.as_str());
+        builder.set_can_reply(self.can_reply);
+        builder.set_is_reply(self.is_reply);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::notice_message::Builder<'static>>()
+            + text_size(self.sender_name.len())
+            + text_size(self.message.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NoticeReplyResultMessage {
+    pub success: bool,
+    pub error: String,
+}
+
+impl Default for NoticeReplyResultMessage {
+    fn default() -> Self {
+        Self {
+            success: false,
+            error: Default::default(),
+        }
+    }
+}
+
+impl NoticeReplyResultMessage {
+    pub fn from_reader(reader: crate::main_capnp::notice_reply_result_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            success: reader.get_success(),
+            error: reader.get_error()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::notice_reply_result_message::Builder<'_>) {
+        builder.set_success(self.success);
+        builder.set_error(self.error.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::notice_reply_result_message::Builder<'static>>()
+            + text_size(self.error.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NoticeReplyMessage {
+    pub receiver_id: i32,
+    pub message: String,
+}
+
+impl Default for NoticeReplyMessage {
+    fn default() -> Self {
+        Self {
+            receiver_id: 0,
+            message: Default::default(),
+        }
+    }
+}
+
+impl NoticeReplyMessage {
+    pub fn from_reader(reader: crate::main_capnp::notice_reply_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            receiver_id: reader.get_receiver_id(),
+            message: reader.get_message()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::notice_reply_message::Builder<'_>) {
+        builder.set_receiver_id(self.receiver_id);
+        builder.set_message(self.message.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::notice_reply_message::Builder<'static>>()
+            + text_size(self.message.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct WarnMessage {
+    pub message: String,
+}
+
+impl Default for WarnMessage {
+    fn default() -> Self {
+        Self {
+            message: Default::default(),
+        }
+    }
+}
+
+impl WarnMessage {
+    pub fn from_reader(reader: crate::main_capnp::warn_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            message: reader.get_message()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::warn_message::Builder<'_>) {
+        builder.set_message(self.message.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::warn_message::Builder<'static>>()
+            + text_size(self.message.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreditsUser {
+    pub account_id: i32,
+    pub user_id: i32,
+    pub username: String,
+    pub display_name: String,
+    pub cube: i16,
+    pub color1: u16,
+    pub color2: u16,
+    pub glow_color: u16,
+}
+
+impl Default for CreditsUser {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+            user_id: 0,
+            username: Default::default(),
+            display_name: Default::default(),
+            cube: 0,
+            color1: 0,
+            color2: 0,
+            glow_color: 0,
+        }
+    }
+}
+
+impl CreditsUser {
+    pub fn from_reader(reader: crate::main_capnp::credits_user::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            user_id: reader.get_user_id(),
+            username: reader.get_username()?.to_str()?.to_owned(),
+            display_name: reader.get_display_name()?.to_str()?.to_owned(),
+            cube: reader.get_cube(),
+            color1: reader.get_color1(),
+            color2: reader.get_color2(),
+            glow_color: reader.get_glow_color(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::credits_user::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        builder.set_user_id(self.user_id);
+        builder.set_username(self.username.as_str());
+        builder.set_display_name(self.display_name.as_str());
+        builder.set_cube(self.cube);
+        builder.set_color1(self.color1);
+        builder.set_color2(self.color2);
+        builder.set_glow_color(self.glow_color);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::credits_user::Builder<'static>>()
+            + text_size(self.username.len())
+            + text_size(self.display_name.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreditsCategory {
+    pub name: String,
+    pub users: Vec<CreditsUser>,
+}
+
+impl Default for CreditsCategory {
+    fn default() -> Self {
+        Self {
+            name: Default::default(),
+            users: Default::default(),
+        }
+    }
+}
+
+impl CreditsCategory {
+    pub fn from_reader(reader: crate::main_capnp::credits_category::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            name: reader.get_name()?.to_str()?.to_owned(),
+            users: reader.get_users()?.iter().map(CreditsUser::from_reader).collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::credits_category::Builder<'_>) {
+        builder.set_name(self.name.as_str());
+        {
+            let mut list = builder.reborrow().init_users(self.users.len() as u32);
+            for (i, v) in self.users.iter().enumerate() {
+                v.encode(list.reborrow().get(i as u32));
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::credits_category::Builder<'static>>()
+            + text_size(self.name.len())
+            + composite_list_size(self.users.iter().map(CreditsUser::encoded_size).sum())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreditsMessage {
+    pub categories: Vec<CreditsCategory>,
+    pub unavailable: bool,
+}
+
+impl Default for CreditsMessage {
+    fn default() -> Self {
+        Self {
+            categories: Default::default(),
+            unavailable: false,
+        }
+    }
+}
+
+impl CreditsMessage {
+    pub fn from_reader(reader: crate::main_capnp::credits_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            categories: reader.get_categories()?.iter().map(CreditsCategory::from_reader).collect::<Result<_, _>>()?,
+            unavailable: reader.get_unavailable(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::credits_message::Builder<'_>) {
+        {
+            let mut list = builder.reborrow().init_categories(self.categories.len() as u32);
+            for (i, v) in self.categories.iter().enumerate() {
+                v.encode(list.reborrow().get(i as u32));
+            }
+        }
+        builder.set_unavailable(self.unavailable);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::credits_message::Builder<'static>>()
+            + composite_list_size(self.categories.iter().map(CreditsCategory::encoded_size).sum())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SetDiscordPairingStateMessage {
+    pub state: bool,
+}
+
+impl Default for SetDiscordPairingStateMessage {
+    fn default() -> Self {
+        Self {
+            state: false,
+        }
+    }
+}
+
+impl SetDiscordPairingStateMessage {
+    pub fn from_reader(reader: crate::main_capnp::set_discord_pairing_state_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            state: reader.get_state(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::set_discord_pairing_state_message::Builder<'_>) {
+        builder.set_state(self.state);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::set_discord_pairing_state_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DiscordLinkConfirmMessage {
+    pub id: u64,
+    pub accept: bool,
+}
+
+impl Default for DiscordLinkConfirmMessage {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            accept: false,
+        }
+    }
+}
+
+impl DiscordLinkConfirmMessage {
+    pub fn from_reader(reader: crate::main_capnp::discord_link_confirm_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            id: reader.get_id(),
+            accept: reader.get_accept(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::discord_link_confirm_message::Builder<'_>) {
+        builder.set_id(self.id);
+        builder.set_accept(self.accept);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::discord_link_confirm_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GetFeaturedListMessage {
+    pub page: u32,
+}
+
+impl Default for GetFeaturedListMessage {
+    fn default() -> Self {
+        Self {
+            page: 0,
+        }
+    }
+}
+
+impl GetFeaturedListMessage {
+    pub fn from_reader(reader: crate::main_capnp::get_featured_list_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            page: reader.get_page(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::get_featured_list_message::Builder<'_>) {
+        builder.set_page(self.page);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::get_featured_list_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SendFeaturedLevelMessage {
+    pub level_id: i32,
+    pub level_name: String,
+    pub author_id: i32,
+    pub author_name: String,
+    pub rate_tier: u8,
+    pub note: String,
+    pub queue: bool,
+}
+
+impl Default for SendFeaturedLevelMessage {
+    fn default() -> Self {
+        Self {
+            level_id: 0,
+            level_name: Default::default(),
+            author_id: 0,
+            author_name: Default::default(),
+            rate_tier: 0,
+            note: Default::default(),
+            queue: false,
+        }
+    }
+}
+
+impl SendFeaturedLevelMessage {
+    pub fn from_reader(reader: crate::main_capnp::send_featured_level_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            level_id: reader.get_level_id(),
+            level_name: reader.get_level_name()?.to_str()?.to_owned(),
+            author_id: reader.get_author_id(),
+            author_name: reader.get_author_name()?.to_str()?.to_owned(),
+            rate_tier: reader.get_rate_tier(),
+            note: reader.get_note()?.to_str()?.to_owned(),
+            queue: reader.get_queue(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::send_featured_level_message::Builder<'_>) {
+        builder.set_level_id(self.level_id);
+        builder.set_level_name(self.level_name.as_str());
+        builder.set_author_id(self.author_id);
+        builder.set_author_name(self.author_name.as_str());
+        builder.set_rate_tier(self.rate_tier);
+        builder.set_note(self.note.as_str());
+        builder.set_queue(self.queue);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::send_featured_level_message::Builder<'static>>()
+            + text_size(self.level_name.len())
+            + text_size(self.author_name.len())
+            + text_size(self.note.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiscordLinkStateMessage {
+    pub id: u64,
+    pub username: String,
+    pub avatar_url: String,
+}
+
+impl Default for DiscordLinkStateMessage {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            username: Default::default(),
+            avatar_url: Default::default(),
+        }
+    }
+}
+
+impl DiscordLinkStateMessage {
+    pub fn from_reader(reader: crate::main_capnp::discord_link_state_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            id: reader.get_id(),
+            username: reader.get_username()?.to_str()?.to_owned(),
+            avatar_url: reader.get_avatar_url()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::discord_link_state_message::Builder<'_>) {
+        builder.set_id(self.id);
+        builder.set_username(self.username.as_str());
+        builder.set_avatar_url(self.avatar_url.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::discord_link_state_message::Builder<'static>>()
+            + text_size(self.username.len())
+            + text_size(self.avatar_url.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiscordLinkAttemptMessage {
+    pub id: u64,
+    pub username: String,
+    pub avatar_url: String,
+}
+
+impl Default for DiscordLinkAttemptMessage {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            username: Default::default(),
+            avatar_url: Default::default(),
+        }
+    }
+}
+
+impl DiscordLinkAttemptMessage {
+    pub fn from_reader(reader: crate::main_capnp::discord_link_attempt_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            id: reader.get_id(),
+            username: reader.get_username()?.to_str()?.to_owned(),
+            avatar_url: reader.get_avatar_url()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::discord_link_attempt_message::Builder<'_>) {
+        builder.set_id(self.id);
+        builder.set_username(self.username.as_str());
+        builder.set_avatar_url(self.avatar_url.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::discord_link_attempt_message::Builder<'static>>()
+            + text_size(self.username.len())
+            + text_size(self.avatar_url.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiscordOauthUrlMessage {
+    pub url: String,
+}
+
+impl Default for DiscordOauthUrlMessage {
+    fn default() -> Self {
+        Self {
+            url: Default::default(),
+        }
+    }
+}
+
+impl DiscordOauthUrlMessage {
+    pub fn from_reader(reader: crate::main_capnp::discord_oauth_url_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            url: reader.get_url()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::discord_oauth_url_message::Builder<'_>) {
+        builder.set_url(self.url.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::discord_oauth_url_message::Builder<'static>>()
+            + text_size(self.url.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiscordUnlinkResultMessage {
+    pub success: bool,
+    pub error: String,
+}
+
+impl Default for DiscordUnlinkResultMessage {
+    fn default() -> Self {
+        Self {
+            success: false,
+            error: Default::default(),
+        }
+    }
+}
+
+impl DiscordUnlinkResultMessage {
+    pub fn from_reader(reader: crate::main_capnp::discord_unlink_result_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            success: reader.get_success(),
+            error: reader.get_error()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::discord_unlink_result_message::Builder<'_>) {
+        builder.set_success(self.success);
+        builder.set_error(self.error.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::discord_unlink_result_message::Builder<'static>>()
+            + text_size(self.error.len())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FeaturedLevelMessage {
+    pub level_id: i32,
+    pub rate_tier: u8,
+    pub edition: u32,
+}
+
+impl Default for FeaturedLevelMessage {
+    fn default() -> Self {
+        Self {
+            level_id: 0,
+            rate_tier: 0,
+            edition: 0,
+        }
+    }
+}
+
+impl FeaturedLevelMessage {
+    pub fn from_reader(reader: crate::main_capnp::featured_level_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            level_id: reader.get_level_id(),
+            rate_tier: reader.get_rate_tier(),
+            edition: reader.get_edition(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::featured_level_message::Builder<'_>) {
+        builder.set_level_id(self.level_id);
+        builder.set_rate_tier(self.rate_tier);
+        builder.set_edition(self.edition);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::featured_level_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeaturedListMessage {
+    pub level_ids: Vec<i32>,
+    pub rate_tiers: Vec<u8>,
+    pub feature_times: Vec<u64>,
+    pub page: u32,
+    pub total_pages: u32,
+}
+
+impl Default for FeaturedListMessage {
+    fn default() -> Self {
+        Self {
+            level_ids: Default::default(),
+            rate_tiers: Default::default(),
+            feature_times: Default::default(),
+            page: 0,
+            total_pages: 0,
+        }
+    }
+}
+
+impl FeaturedListMessage {
+    pub fn from_reader(reader: crate::main_capnp::featured_list_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            level_ids: reader.get_level_ids()?.iter().collect(),
+            rate_tiers: reader.get_rate_tiers()?.iter().collect(),
+            feature_times: reader.get_feature_times()?.iter().collect(),
+            page: reader.get_page(),
+            total_pages: reader.get_total_pages(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::featured_list_message::Builder<'_>) {
+        {
+            let mut list = builder.reborrow().init_level_ids(self.level_ids.len() as u32);
+            for (i, v) in self.level_ids.iter().enumerate() {
+                list.set(i as u32, *v);
+            }
+        }
+        {
+            let mut list = builder.reborrow().init_rate_tiers(self.rate_tiers.len() as u32);
+            for (i, v) in self.rate_tiers.iter().enumerate() {
+                list.set(i as u32, *v);
+            }
+        }
+        {
+            let mut list = builder.reborrow().init_feature_times(self.feature_times.len() as u32);
+            for (i, v) in self.feature_times.iter().enumerate() {
+                list.set(i as u32, *v);
+            }
+        }
+        builder.set_page(self.page);
+        builder.set_total_pages(self.total_pages);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::featured_list_message::Builder<'static>>()
+            + list_size::<i32>(self.level_ids.len())
+            + list_size::<u8>(self.rate_tiers.len())
+            + list_size::<u64>(self.feature_times.len())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FetchUserMessage {
+    pub account_id: i32,
+}
+
+impl Default for FetchUserMessage {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+        }
+    }
+}
+
+impl FetchUserMessage {
+    pub fn from_reader(reader: crate::main_capnp::fetch_user_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::fetch_user_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::fetch_user_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FetchUserResponseMessage {
+    pub account_id: i32,
+    pub found: bool,
+    pub roles: Vec<u8>,
+}
+
+impl Default for FetchUserResponseMessage {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+            found: false,
+            roles: Default::default(),
+        }
+    }
+}
+
+impl FetchUserResponseMessage {
+    pub fn from_reader(reader: crate::main_capnp::fetch_user_response_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            found: reader.get_found(),
+            roles: reader.get_roles()?.iter().collect(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::fetch_user_response_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        builder.set_found(self.found);
+        {
+            let mut list = builder.reborrow().init_roles(self.roles.len() as u32);
+            for (i, v) in self.roles.iter().enumerate() {
+                list.set(i as u32, *v);
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::fetch_user_response_message::Builder<'static>>()
+            + list_size::<u8>(self.roles.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminLoginMessage {
+    pub password: String,
+}
+
+impl Default for AdminLoginMessage {
+    fn default() -> Self {
+        Self {
+            password: Default::default(),
+        }
+    }
+}
+
+impl AdminLoginMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_login_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            password: reader.get_password()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_login_message::Builder<'_>) {
+        builder.set_password(self.password.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_login_message::Builder<'static>>()
+            + text_size(self.password.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminKickMessage {
+    pub account_id: i32,
+    pub message: String,
+}
+
+impl Default for AdminKickMessage {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+            message: Default::default(),
+        }
+    }
+}
+
+impl AdminKickMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_kick_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            message: reader.get_message()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_kick_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        builder.set_message(self.message.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_kick_message::Builder<'static>>()
+            + text_size(self.message.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminNoticeMessage {
+    pub target_user: String,
+    pub message: String,
+    pub room_id: u32,
+    pub level_id: i32,
+    pub can_reply: bool,
+    pub show_sender: bool,
+}
+
+impl Default for AdminNoticeMessage {
+    fn default() -> Self {
+        Self {
+            target_user: Default::default(),
+            message: Default::default(),
+            room_id: 0,
+            level_id: 0,
+            can_reply: false,
+            show_sender: false,
+        }
+    }
+}
+
+impl AdminNoticeMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_notice_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            target_user: reader.get_target_user()?.to_str()?.to_owned(),
+            message: reader.get_message()?.to_str()?.to_owned(),
+            room_id: reader.get_room_id(),
+            level_id: reader.get_level_id(),
+            can_reply: reader.get_can_reply(),
+            show_sender: reader.get_show_sender(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_notice_message::Builder<'_>) {
+        builder.set_target_user(self.target_user.as_str());
+        builder.set_message(self.message.as_str());
+        builder.set_room_id(self.room_id);
+        builder.set_level_id(self.level_id);
+        builder.set_can_reply(self.can_reply);
+        builder.set_show_sender(self.show_sender);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_notice_message::Builder<'static>>()
+            + text_size(self.target_user.len())
+            + text_size(self.message.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminNoticeEveryoneMessage {
+    pub message: String,
+}
+
+impl Default for AdminNoticeEveryoneMessage {
+    fn default() -> Self {
+        Self {
+            message: Default::default(),
+        }
+    }
+}
+
+impl AdminNoticeEveryoneMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_notice_everyone_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            message: reader.get_message()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_notice_everyone_message::Builder<'_>) {
+        builder.set_message(self.message.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_notice_everyone_message::Builder<'static>>()
+            + text_size(self.message.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminFetchUserMessage {
+    pub query: String,
+    pub query_num: i32,
+}
+
+impl Default for AdminFetchUserMessage {
+    fn default() -> Self {
+        Self {
+            query: Default::default(),
+            query_num: 0,
+        }
+    }
+}
+
+impl AdminFetchUserMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_fetch_user_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            query: reader.get_query()?.to_str()?.to_owned(),
+            query_num: reader.get_query_num(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_fetch_user_message::Builder<'_>) {
+        builder.set_query(self.query.as_str());
+        builder.set_query_num(self.query_num);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_fetch_user_message::Builder<'static>>()
+            + text_size(self.query.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FetchedMod {
+    pub account_id: i32,
+    pub username: String,
+    pub cube: i16,
+    pub color1: u16,
+    pub color2: u16,
+    pub glow_color: u16,
+}
+
+impl Default for FetchedMod {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+            username: Default::default(),
+            cube: 0,
+            color1: 0,
+            color2: 0,
+            glow_color: 0,
+        }
+    }
+}
+
+impl FetchedMod {
+    pub fn from_reader(reader: crate::main_capnp::fetched_mod::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            username: reader.get_username()?.to_str()?.to_owned(),
+            cube: reader.get_cube(),
+            color1: reader.get_color1(),
+            color2: reader.get_color2(),
+            glow_color: reader.get_glow_color(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::fetched_mod::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        builder.set_username(self.username.as_str());
+        builder.set_cube(self.cube);
+        builder.set_color1(self.color1);
+        builder.set_color2(self.color2);
+        builder.set_glow_color(self.glow_color);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::fetched_mod::Builder<'static>>()
+            + text_size(self.username.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminFetchModsResponseMessage {
+    pub users: Vec<FetchedMod>,
+}
+
+impl Default for AdminFetchModsResponseMessage {
+    fn default() -> Self {
+        Self {
+            users: Default::default(),
+        }
+    }
+}
+
+impl AdminFetchModsResponseMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_fetch_mods_response_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            users: reader.get_users()?.iter().map(FetchedMod::from_reader).collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_fetch_mods_response_message::Builder<'_>) {
+        {
+            let mut list = builder.reborrow().init_users(self.users.len() as u32);
+            for (i, v) in self.users.iter().enumerate() {
+                v.encode(list.reborrow().get(i as u32));
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_fetch_mods_response_message::Builder<'static>>()
+            + composite_list_size(self.users.iter().map(FetchedMod::encoded_size).sum())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminLogsResponseMessage {
+    pub logs: Vec<crate::messages::main::AuditLog>,
+    pub accounts: Vec<PlayerAccountData>,
+}
+
+impl Default for AdminLogsResponseMessage {
+    fn default() -> Self {
+        Self {
+            logs: Default::default(),
+            accounts: Default::default(),
+        }
+    }
+}
+
+impl AdminLogsResponseMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_logs_response_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            logs: reader.get_logs()?.iter().map(crate::messages::main::AuditLog::from_reader).collect::<Result<_, _>>()?,
+            accounts: reader.get_accounts()?.iter().map(PlayerAccountData::from_reader).collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_logs_response_message::Builder<'_>) {
+        {
+            let mut list = builder.reborrow().init_logs(self.logs.len() as u32);
+            for (i, v) in self.logs.iter().enumerate() {
+                v.encode(list.reborrow().get(i as u32));
+            }
+        }
+        {
+            let mut list = builder.reborrow().init_accounts(self.accounts.len() as u32);
+            for (i, v) in self.accounts.iter().enumerate() {
+                v.encode(list.reborrow().get(i as u32));
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_logs_response_message::Builder<'static>>()
+            + composite_list_size(self.logs.iter().map(crate::messages::main::AuditLog::encoded_size).sum())
+            + composite_list_size(self.accounts.iter().map(PlayerAccountData::encoded_size).sum())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminPunishmentReasonsMessage {
+    pub ban: Vec<String>,
+    pub mute: Vec<String>,
+    pub room_ban: Vec<String>,
+}
+
+impl Default for AdminPunishmentReasonsMessage {
+    fn default() -> Self {
+        Self {
+            ban: Default::default(),
+            mute: Default::default(),
+            room_ban: Default::default(),
+        }
+    }
+}
+
+impl AdminPunishmentReasonsMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_punishment_reasons_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            ban: reader.get_ban()?.iter().map(|s| Ok(s?.to_str()?.to_owned())).collect::<Result<_, DataDecodeError>>()?,
+            mute: reader.get_mute()?.iter().map(|s| Ok(s?.to_str()?.to_owned())).collect::<Result<_, DataDecodeError>>()?,
+            room_ban: reader.get_room_ban()?.iter().map(|s| Ok(s?.to_str()?.to_owned())).collect::<Result<_, DataDecodeError>>()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_punishment_reasons_message::Builder<'_>) {
+        {
+            let mut list = builder.reborrow().init_ban(self.ban.len() as u32);
+            for (i, v) in self.ban.iter().enumerate() {
+                list.set(i as u32, v.as_str());
+            }
+        }
+        {
+            let mut list = builder.reborrow().init_mute(self.mute.len() as u32);
+            for (i, v) in self.mute.iter().enumerate() {
+                list.set(i as u32, v.as_str());
+            }
+        }
+        {
+            let mut list = builder.reborrow().init_room_ban(self.room_ban.len() as u32);
+            for (i, v) in self.room_ban.iter().enumerate() {
+                list.set(i as u32, v.as_str());
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_punishment_reasons_message::Builder<'static>>()
+            + pointer_list_size(self.ban.len()) + self.ban.iter().map(|s| text_size(s.len())).sum::<usize>()
+            + pointer_list_size(self.mute.len()) + self.mute.iter().map(|s| text_size(s.len())).sum::<usize>()
+            + pointer_list_size(self.room_ban.len()) + self.room_ban.iter().map(|s| text_size(s.len())).sum::<usize>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminBanMessage {
+    pub account_id: i32,
+    pub reason: String,
+    /// 0 means permanent ban
+    pub expires_at: i64,
+}
+
+impl Default for AdminBanMessage {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+            reason: Default::default(),
+            expires_at: 0,
+        }
+    }
+}
+
+impl AdminBanMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_ban_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            reason: reader.get_reason()?.to_str()?.to_owned(),
+            expires_at: reader.get_expires_at(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_ban_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        builder.set_reason(self.reason.as_str());
+        builder.set_expires_at(self.expires_at);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_ban_message::Builder<'static>>()
+            + text_size(self.reason.len())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AdminUnbanMessage {
+    pub account_id: i32,
+}
+
+impl Default for AdminUnbanMessage {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+        }
+    }
+}
+
+impl AdminUnbanMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_unban_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_unban_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_unban_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminRoomBanMessage {
+    pub account_id: i32,
+    pub reason: String,
+    /// 0 means permanent ban
+    pub expires_at: i64,
+}
+
+impl Default for AdminRoomBanMessage {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+            reason: Default::default(),
+            expires_at: 0,
+        }
+    }
+}
+
+impl AdminRoomBanMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_room_ban_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            reason: reader.get_reason()?.to_str()?.to_owned(),
+            expires_at: reader.get_expires_at(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_room_ban_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        builder.set_reason(self.reason.as_str());
+        builder.set_expires_at(self.expires_at);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_room_ban_message::Builder<'static>>()
+            + text_size(self.reason.len())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AdminRoomUnbanMessage {
+    pub account_id: i32,
+}
+
+impl Default for AdminRoomUnbanMessage {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+        }
+    }
+}
+
+impl AdminRoomUnbanMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_room_unban_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_room_unban_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_room_unban_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminMuteMessage {
+    pub account_id: i32,
+    pub reason: String,
+    /// 0 means permanent mute
+    pub expires_at: i64,
+}
+
+impl Default for AdminMuteMessage {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+            reason: Default::default(),
+            expires_at: 0,
+        }
+    }
+}
+
+impl AdminMuteMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_mute_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            reason: reader.get_reason()?.to_str()?.to_owned(),
+            expires_at: reader.get_expires_at(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_mute_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        builder.set_reason(self.reason.as_str());
+        builder.set_expires_at(self.expires_at);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_mute_message::Builder<'static>>()
+            + text_size(self.reason.len())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AdminUnmuteMessage {
+    pub account_id: i32,
+}
+
+impl Default for AdminUnmuteMessage {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+        }
+    }
+}
+
+impl AdminUnmuteMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_unmute_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_unmute_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_unmute_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminEditRolesMessage {
+    pub account_id: i32,
+    pub roles: Vec<u8>,
+}
+
+impl Default for AdminEditRolesMessage {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+            roles: Default::default(),
+        }
+    }
+}
+
+impl AdminEditRolesMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_edit_roles_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            roles: reader.get_roles()?.iter().collect(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_edit_roles_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        {
+            let mut list = builder.reborrow().init_roles(self.roles.len() as u32);
+            for (i, v) in self.roles.iter().enumerate() {
+                list.set(i as u32, *v);
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_edit_roles_message::Builder<'static>>()
+            + list_size::<u8>(self.roles.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminSetPasswordMessage {
+    pub account_id: i32,
+    pub new_password: String,
+}
+
+impl Default for AdminSetPasswordMessage {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+            new_password: Default::default(),
+        }
+    }
+}
+
+impl AdminSetPasswordMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_set_password_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            new_password: reader.get_new_password()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_set_password_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        builder.set_new_password(self.new_password.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_set_password_message::Builder<'static>>()
+            + text_size(self.new_password.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminUpdateUserMessage {
+    pub account_id: i32,
+    pub username: String,
+    pub cube: i16,
+    pub color1: u16,
+    pub color2: u16,
+    pub glow_color: u16,
+}
+
+impl Default for AdminUpdateUserMessage {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+            username: Default::default(),
+            cube: 0,
+            color1: 0,
+            color2: 0,
+            glow_color: 0,
+        }
+    }
+}
+
+impl AdminUpdateUserMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_update_user_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            username: reader.get_username()?.to_str()?.to_owned(),
+            cube: reader.get_cube(),
+            color1: reader.get_color1(),
+            color2: reader.get_color2(),
+            glow_color: reader.get_glow_color(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_update_user_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        builder.set_username(self.username.as_str());
+        builder.set_cube(self.cube);
+        builder.set_color1(self.color1);
+        builder.set_color2(self.color2);
+        builder.set_glow_color(self.glow_color);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_update_user_message::Builder<'static>>()
+            + text_size(self.username.len())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AdminSetWhitelistedMessage {
+    pub account_id: i32,
+    pub whitelisted: bool,
+}
+
+impl Default for AdminSetWhitelistedMessage {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+            whitelisted: false,
+        }
+    }
+}
+
+impl AdminSetWhitelistedMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_set_whitelisted_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            whitelisted: reader.get_whitelisted(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_set_whitelisted_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        builder.set_whitelisted(self.whitelisted);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_set_whitelisted_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AdminCloseRoomMessage {
+    pub room_id: u32,
+}
+
+impl Default for AdminCloseRoomMessage {
+    fn default() -> Self {
+        Self {
+            room_id: 0,
+        }
+    }
+}
+
+impl AdminCloseRoomMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_close_room_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            room_id: reader.get_room_id(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_close_room_message::Builder<'_>) {
+        builder.set_room_id(self.room_id);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_close_room_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminResultMessage {
+    pub success: bool,
+    pub error: String,
+}
+
+impl Default for AdminResultMessage {
+    fn default() -> Self {
+        Self {
+            success: false,
+            error: Default::default(),
+        }
+    }
+}
+
+impl AdminResultMessage {
+    pub fn from_reader(reader: crate::main_capnp::admin_result_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            success: reader.get_success(),
+            error: reader.get_error()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::main_capnp::admin_result_message::Builder<'_>) {
+        builder.set_success(self.success);
+        builder.set_error(self.error.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::main_capnp::admin_result_message::Builder<'static>>()
+            + text_size(self.error.len())
+    }
+}
@@ -0,0 +1,184 @@
+// @generated by build.rs from structs annotated with $rustModel in shared.capnp.
+// DO NOT EDIT.
+
+use crate::encoding::{DataDecodeError, size::*};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlayerIconData {
+    pub cube: i16,
+    pub ship: i16,
+    pub ball: i16,
+    pub ufo: i16,
+    pub wave: i16,
+    pub robot: i16,
+    pub spider: i16,
+    pub swing: i16,
+    pub jetpack: i16,
+    pub color1: u16,
+    pub color2: u16,
+    pub glow_color: u16,
+    /// 255 means none/default
+    pub death_effect: u8,
+    pub trail: u8,
+    pub ship_trail: u8,
+    pub default_mini: bool,
+}
+
+impl Default for PlayerIconData {
+    fn default() -> Self {
+        Self {
+            cube: 0,
+            ship: 0,
+            ball: 0,
+            ufo: 0,
+            wave: 0,
+            robot: 0,
+            spider: 0,
+            swing: 0,
+            jetpack: 0,
+            color1: 0,
+            color2: 0,
+            glow_color: 0,
+            death_effect: 1,
+            trail: 255,
+            ship_trail: 255,
+            default_mini: false,
+        }
+    }
+}
+
+impl PlayerIconData {
+    pub fn from_reader(reader: crate::shared_capnp::player_icon_data::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            cube: reader.get_cube(),
+            ship: reader.get_ship(),
+            ball: reader.get_ball(),
+            ufo: reader.get_ufo(),
+            wave: reader.get_wave(),
+            robot: reader.get_robot(),
+            spider: reader.get_spider(),
+            swing: reader.get_swing(),
+            jetpack: reader.get_jetpack(),
+            color1: reader.get_color1(),
+            color2: reader.get_color2(),
+            glow_color: reader.get_glow_color(),
+            death_effect: reader.get_death_effect(),
+            trail: reader.get_trail(),
+            ship_trail: reader.get_ship_trail(),
+            default_mini: reader.get_default_mini(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::shared_capnp::player_icon_data::Builder<'_>) {
+        builder.set_cube(self.cube);
+        builder.set_ship(self.ship);
+        builder.set_ball(self.ball);
+        builder.set_ufo(self.ufo);
+        builder.set_wave(self.wave);
+        builder.set_robot(self.robot);
+        builder.set_spider(self.spider);
+        builder.set_swing(self.swing);
+        builder.set_jetpack(self.jetpack);
+        builder.set_color1(self.color1);
+        builder.set_color2(self.color2);
+        builder.set_glow_color(self.glow_color);
+        builder.set_death_effect(self.death_effect);
+        builder.set_trail(self.trail);
+        builder.set_ship_trail(self.ship_trail);
+        builder.set_default_mini(self.default_mini);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::shared_capnp::player_icon_data::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserRole {
+    pub string_id: String,
+    pub icon: String,
+    /// Encoded `MultiColor`, empty if the role has no name color
+    pub name_color: Vec<u8>,
+    pub hide: bool,
+}
+
+impl Default for UserRole {
+    fn default() -> Self {
+        Self {
+            string_id: Default::default(),
+            icon: Default::default(),
+            name_color: Default::default(),
+            hide: false,
+        }
+    }
+}
+
+impl UserRole {
+    pub fn from_reader(reader: crate::shared_capnp::user_role::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            string_id: reader.get_string_id()?.to_str()?.to_owned(),
+            icon: reader.get_icon()?.to_str()?.to_owned(),
+            name_color: reader.get_name_color()?.to_vec(),
+            hide: reader.get_hide(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::shared_capnp::user_role::Builder<'_>) {
+        builder.set_string_id(self.string_id.as_str());
+        builder.set_icon(self.icon.as_str());
+        builder.set_name_color(&self.name_color);
+        builder.set_hide(self.hide);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::shared_capnp::user_role::Builder<'static>>()
+            + text_size(self.string_id.len())
+            + text_size(self.icon.len())
+            + data_size(self.name_color.len())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UserSettings {
+    pub hide_in_level: bool,
+    pub hide_in_menus: bool,
+    pub hide_roles: bool,
+    pub disable_notices: bool,
+    pub friends_only_vc: bool,
+}
+
+impl Default for UserSettings {
+    fn default() -> Self {
+        Self {
+            hide_in_level: false,
+            hide_in_menus: false,
+            hide_roles: false,
+            disable_notices: false,
+            friends_only_vc: false,
+        }
+    }
+}
+
+impl UserSettings {
+    pub fn from_reader(reader: crate::shared_capnp::user_settings::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            hide_in_level: reader.get_hide_in_level(),
+            hide_in_menus: reader.get_hide_in_menus(),
+            hide_roles: reader.get_hide_roles(),
+            disable_notices: reader.get_disable_notices(),
+            friends_only_vc: reader.get_friends_only_vc(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::shared_capnp::user_settings::Builder<'_>) {
+        builder.set_hide_in_level(self.hide_in_level);
+        builder.set_hide_in_menus(self.hide_in_menus);
+        builder.set_hide_roles(self.hide_roles);
+        builder.set_disable_notices(self.disable_notices);
+        builder.set_friends_only_vc(self.friends_only_vc);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::shared_capnp::user_settings::Builder<'static>>()
+    }
+}
@@ -0,0 +1,310 @@
+// @generated by build.rs from structs annotated with $rustModel in srvc.capnp.
+// DO NOT EDIT.
+
+use crate::encoding::{DataDecodeError, size::*};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoginChallengeMessage {
+    /// 32 random bytes, valid for a single login attempt
+    pub nonce: Vec<u8>,
+}
+
+impl Default for LoginChallengeMessage {
+    fn default() -> Self {
+        Self {
+            nonce: Default::default(),
+        }
+    }
+}
+
+impl LoginChallengeMessage {
+    pub fn from_reader(reader: crate::srvc_capnp::login_challenge_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            nonce: reader.get_nonce()?.to_vec(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::srvc_capnp::login_challenge_message::Builder<'_>) {
+        builder.set_nonce(&self.nonce);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::srvc_capnp::login_challenge_message::Builder<'static>>()
+            + data_size(self.nonce.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoginFailedMessage {
+    /// human readable details
+    pub reason: String,
+    pub kind: crate::srvc_capnp::LoginFailedReason,
+}
+
+impl Default for LoginFailedMessage {
+    fn default() -> Self {
+        Self {
+            reason: Default::default(),
+            kind: crate::srvc_capnp::LoginFailedReason::Other,
+        }
+    }
+}
+
+impl LoginFailedMessage {
+    pub fn from_reader(reader: crate::srvc_capnp::login_failed_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            reason: reader.get_reason()?.to_str()?.to_owned(),
+            kind: reader.get_kind()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::srvc_capnp::login_failed_message::Builder<'_>) {
+        builder.set_reason(self.reason.as_str());
+        builder.set_kind(self.kind);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::srvc_capnp::login_failed_message::Builder<'static>>()
+            + text_size(self.reason.len())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NotifyRoomCreatedMessage {
+    pub room_id: u32,
+    pub passcode: u32,
+    pub owner: i32,
+}
+
+impl Default for NotifyRoomCreatedMessage {
+    fn default() -> Self {
+        Self {
+            room_id: 0,
+            passcode: 0,
+            owner: 0,
+        }
+    }
+}
+
+impl NotifyRoomCreatedMessage {
+    pub fn from_reader(reader: crate::srvc_capnp::notify_room_created_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            room_id: reader.get_room_id(),
+            passcode: reader.get_passcode(),
+            owner: reader.get_owner(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::srvc_capnp::notify_room_created_message::Builder<'_>) {
+        builder.set_room_id(self.room_id);
+        builder.set_passcode(self.passcode);
+        builder.set_owner(self.owner);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::srvc_capnp::notify_room_created_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NotifyRoomDeletedMessage {
+    pub room_id: u32,
+}
+
+impl Default for NotifyRoomDeletedMessage {
+    fn default() -> Self {
+        Self {
+            room_id: 0,
+        }
+    }
+}
+
+impl NotifyRoomDeletedMessage {
+    pub fn from_reader(reader: crate::srvc_capnp::notify_room_deleted_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            room_id: reader.get_room_id(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::srvc_capnp::notify_room_deleted_message::Builder<'_>) {
+        builder.set_room_id(self.room_id);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::srvc_capnp::notify_room_deleted_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RoomCreatedAckMessage {
+    pub room_id: u32,
+}
+
+impl Default for RoomCreatedAckMessage {
+    fn default() -> Self {
+        Self {
+            room_id: 0,
+        }
+    }
+}
+
+impl RoomCreatedAckMessage {
+    pub fn from_reader(reader: crate::srvc_capnp::room_created_ack_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            room_id: reader.get_room_id(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::srvc_capnp::room_created_ack_message::Builder<'_>) {
+        builder.set_room_id(self.room_id);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::srvc_capnp::room_created_ack_message::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NotifyUserDataMessage {
+    pub data: crate::data::SrvUserData,
+}
+
+impl Default for NotifyUserDataMessage {
+    fn default() -> Self {
+        Self {
+            data: Default::default(),
+        }
+    }
+}
+
+impl NotifyUserDataMessage {
+    pub fn from_reader(reader: crate::srvc_capnp::notify_user_data_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            data: crate::data::SrvUserData::from_reader(reader.get_data()?)?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::srvc_capnp::notify_user_data_message::Builder<'_>) {
+        self.data.encode(builder.reborrow().init_data());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::srvc_capnp::notify_user_data_message::Builder<'static>>()
+            + self.data.encoded_size()
+    }
+}
+
+/// Batched user data notification, prefer this over `NotifyUserDataMessage` when syncing many users
+#[derive(Clone, Debug, PartialEq)]
+pub struct NotifyUserDataBatchMessage {
+    /// Users the game server may not know about yet, replacing any cached data
+    pub data: Vec<crate::data::SrvUserData>,
+    /// Changes to users the game server already knows about
+    pub diffs: Vec<crate::data::SrvUserDataDiff>,
+}
+
+impl Default for NotifyUserDataBatchMessage {
+    fn default() -> Self {
+        Self {
+            data: Default::default(),
+            diffs: Default::default(),
+        }
+    }
+}
+
+impl NotifyUserDataBatchMessage {
+    pub fn from_reader(reader: crate::srvc_capnp::notify_user_data_batch_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            data: reader.get_data()?.iter().map(crate::data::SrvUserData::from_reader).collect::<Result<_, _>>()?,
+            diffs: reader.get_diffs()?.iter().map(crate::data::SrvUserDataDiff::from_reader).collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::srvc_capnp::notify_user_data_batch_message::Builder<'_>) {
+        {
+            let mut list = builder.reborrow().init_data(self.data.len() as u32);
+            for (i, v) in self.data.iter().enumerate() {
+                v.encode(list.reborrow().get(i as u32));
+            }
+        }
+        {
+            let mut list = builder.reborrow().init_diffs(self.diffs.len() as u32);
+            for (i, v) in self.diffs.iter().enumerate() {
+                v.encode(list.reborrow().get(i as u32));
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::srvc_capnp::notify_user_data_batch_message::Builder<'static>>()
+            + composite_list_size(self.data.iter().map(crate::data::SrvUserData::encoded_size).sum())
+            + composite_list_size(self.diffs.iter().map(crate::data::SrvUserDataDiff::encoded_size).sum())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NotifyKickUserMessage {
+    pub account_id: i32,
+}
+
+impl Default for NotifyKickUserMessage {
+    fn default() -> Self {
+        Self {
+            account_id: 0,
+        }
+    }
+}
+
+impl NotifyKickUserMessage {
+    pub fn from_reader(reader: crate::srvc_capnp::notify_kick_user_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::srvc_capnp::notify_kick_user_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::srvc_capnp::notify_kick_user_message::Builder<'static>>()
+    }
+}
+
+/// Sent by a game server that is shutting down, or by the central server to put a game server
+/// into maintenance mode. The game server stops accepting new sessions until it receives `resume`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DrainMessage {
+    /// Unix timestamp (seconds) after which remaining sessions may be dropped, 0 if unknown
+    pub deadline: u64,
+    /// Human readable reason, can be shown to players
+    pub reason: String,
+}
+
+impl Default for DrainMessage {
+    fn default() -> Self {
+        Self {
+            deadline: 0,
+            reason: Default::default(),
+        }
+    }
+}
+
+impl DrainMessage {
+    pub fn from_reader(reader: crate::srvc_capnp::drain_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            deadline: reader.get_deadline(),
+            reason: reader.get_reason()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: crate::srvc_capnp::drain_message::Builder<'_>) {
+        builder.set_deadline(self.deadline);
+        builder.set_reason(self.reason.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<crate::srvc_capnp::drain_message::Builder<'static>>()
+            + text_size(self.reason.len())
+    }
+}
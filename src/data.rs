@@ -1,116 +1,147 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 #[cfg(feature = "srvc")]
-use crate::schema::srvc::{srv_user_data, status_message};
+use crate::{
+    config::make_memory_limits,
+    schema::srvc::{
+        login_ok_message, server_role, session_player_count, srv_user_data, srv_user_data_diff,
+        status_message,
+    },
+    token_issuer::TokenIssuer,
+};
+
+use qunet::buffers::{ByteReader, ByteWriter};
 
 use crate::{
-    encoding::{DataDecodeError, heapless_str_from_reader},
-    schema::shared::{player_display_data, player_icon_data},
+    MultiColor,
+    encoding::{DataDecodeError, heapless_str_from_reader, size::*},
+    schema::shared::{game_server, player_display_data, special_user_data},
 };
 
+#[cfg(feature = "main")]
+use crate::schema::main::user_punishment;
+
+pub use crate::shared_models::{PlayerIconData, UserRole};
+
 #[cfg(feature = "srvc")]
 pub const SRVC_MAGIC: u64 = 0x92869be51214ba4f;
 #[cfg(feature = "srvc")]
-pub const SRVC_PROTOCOL_VERSION: u32 = 2;
+pub const SRVC_PROTOCOL_VERSION: u32 = 4;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct GameServerData {
     pub id: u8,
     pub address: heapless::String<64>,
     pub string_id: heapless::String<32>,
     pub name: heapless::String<64>,
     pub region: heapless::String<32>,
+    /// Whether the server is shutting down or in maintenance, and new sessions should go elsewhere
+    pub draining: bool,
 }
 
-#[derive(Clone, Copy, Debug)]
-pub struct PlayerIconData {
-    pub cube: i16,
-    pub ship: i16,
-    pub ball: i16,
-    pub ufo: i16,
-    pub wave: i16,
-    pub robot: i16,
-    pub spider: i16,
-    pub swing: i16,
-    pub jetpack: i16,
-    pub color1: u16,
-    pub color2: u16,
-    pub glow_color: u16,
-    pub death_effect: u8,
-    pub trail: u8,
-    pub ship_trail: u8,
-    pub default_mini: bool,
-}
-
-impl Default for PlayerIconData {
-    fn default() -> Self {
-        Self {
-            cube: 0,
-            ship: 0,
-            ball: 0,
-            ufo: 0,
-            wave: 0,
-            robot: 0,
-            spider: 0,
-            swing: 0,
-            jetpack: 0,
-            color1: 0,
-            color2: 0,
-            glow_color: 0,
-            death_effect: 1,
-            trail: 255,
-            ship_trail: 255,
-            default_mini: false,
-        }
-    }
-}
-
-impl PlayerIconData {
-    pub fn from_reader(reader: player_icon_data::Reader<'_>) -> Result<Self, DataDecodeError> {
+impl GameServerData {
+    pub fn from_reader(reader: game_server::Reader<'_>) -> Result<Self, DataDecodeError> {
         Ok(Self {
-            cube: reader.get_cube(),
-            ship: reader.get_ship(),
-            ball: reader.get_ball(),
-            ufo: reader.get_ufo(),
-            wave: reader.get_wave(),
-            robot: reader.get_robot(),
-            spider: reader.get_spider(),
-            swing: reader.get_swing(),
-            jetpack: reader.get_jetpack(),
-            color1: reader.get_color1(),
-            color2: reader.get_color2(),
-            glow_color: reader.get_glow_color(),
-            death_effect: reader.get_death_effect(),
-            trail: reader.get_trail(),
-            ship_trail: reader.get_ship_trail(),
-            default_mini: reader.get_default_mini(),
+            id: reader.get_id(),
+            address: heapless_str_from_reader(reader.get_address()?)?,
+            string_id: heapless_str_from_reader(reader.get_string_id()?)?,
+            name: heapless_str_from_reader(reader.get_name()?)?,
+            region: heapless_str_from_reader(reader.get_region()?)?,
+            draining: reader.get_draining(),
         })
     }
 
-    pub fn encode(&self, mut builder: player_icon_data::Builder<'_>) {
-        builder.set_cube(self.cube);
-        builder.set_ship(self.ship);
-        builder.set_ball(self.ball);
-        builder.set_ufo(self.ufo);
-        builder.set_wave(self.wave);
-        builder.set_robot(self.robot);
-        builder.set_spider(self.spider);
-        builder.set_swing(self.swing);
-        builder.set_jetpack(self.jetpack);
-        builder.set_color1(self.color1);
-        builder.set_color2(self.color2);
-        builder.set_glow_color(self.glow_color);
-        builder.set_death_effect(self.death_effect);
-        builder.set_trail(self.trail);
-        builder.set_ship_trail(self.ship_trail);
-        builder.set_default_mini(self.default_mini);
+    pub fn encode(&self, mut builder: game_server::Builder<'_>) {
+        builder.set_id(self.id);
+        builder.set_address(self.address.as_str());
+        builder.set_string_id(self.string_id.as_str());
+        builder.set_name(self.name.as_str());
+        builder.set_region(self.region.as_str());
+        builder.set_draining(self.draining);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<game_server::Builder<'static>>()
+            + text_size(self.address.len())
+            + text_size(self.string_id.len())
+            + text_size(self.name.len())
+            + text_size(self.region.len())
     }
 }
 
-#[derive(Clone, Debug, Default)]
+/// Current unix time in seconds, the unit of all punishment timestamps
+pub fn unix_timestamp() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+/// Whether a punishment expiring at `expires_at` is over at `now`, an expiry of 0 means permanent
+pub fn punishment_expired(expires_at: i64, now: i64) -> bool {
+    expires_at != 0 && now >= expires_at
+}
+
+/// A ban, room ban or mute. `expires_at` is in unix seconds, 0 means the punishment is permanent.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Punishment {
+    pub issued_by: i32,
+    pub issued_at: i64,
+    pub reason: String,
+    pub expires_at: i64,
+}
+
+impl Punishment {
+    pub fn is_permanent(&self) -> bool {
+        self.expires_at == 0
+    }
+
+    pub fn is_expired_at(&self, now: i64) -> bool {
+        punishment_expired(self.expires_at, now)
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(unix_timestamp())
+    }
+
+    /// Time left until the punishment expires, `None` if it is permanent
+    pub fn remaining_at(&self, now: i64) -> Option<Duration> {
+        (!self.is_permanent())
+            .then(|| Duration::from_secs(self.expires_at.saturating_sub(now).max(0) as u64))
+    }
+}
+
+#[cfg(feature = "main")]
+impl Punishment {
+    pub fn from_reader(reader: user_punishment::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            issued_by: reader.get_issued_by(),
+            issued_at: reader.get_issued_at(),
+            reason: reader.get_reason()?.to_str()?.to_owned(),
+            expires_at: reader.get_expires_at(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: user_punishment::Builder<'_>) {
+        builder.set_issued_by(self.issued_by);
+        builder.set_issued_at(self.issued_at);
+        builder.set_reason(self.reason.as_str());
+        builder.set_expires_at(self.expires_at);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<user_punishment::Builder<'static>>() + text_size(self.reason.len())
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct PlayerDisplayData {
     pub account_id: i32,
     pub user_id: i32,
     pub username: heapless::String<32>,
     pub icons: PlayerIconData,
+    /// Roles and name color, `None` for regular users
+    pub special_data: Option<SpecialUserData>,
 }
 
 impl PlayerDisplayData {
@@ -121,6 +152,11 @@ impl PlayerDisplayData {
             username: heapless_str_from_reader(reader.get_username()?)?,
             icons: PlayerIconData::from_reader(reader.get_icons()?)
                 .map_err(|_| DataDecodeError::ValidationFailed)?,
+            special_data: if reader.has_special_data() {
+                Some(SpecialUserData::from_reader(reader.get_special_data()?)?)
+            } else {
+                None
+            },
         })
     }
 
@@ -129,11 +165,155 @@ impl PlayerDisplayData {
         builder.set_user_id(self.user_id);
         builder.set_username(self.username.as_str());
         self.icons.encode(builder.reborrow().init_icons());
+
+        if let Some(special) = &self.special_data {
+            special.encode(builder.reborrow().init_special_data());
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<player_display_data::Builder<'static>>()
+            + text_size(self.username.len())
+            + self.icons.encoded_size()
+            + self.special_data.as_ref().map_or(0, |v| v.encoded_size())
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SpecialUserData {
+    pub roles: Vec<u8>,
+    pub name_color: Option<MultiColor>,
+}
+
+impl SpecialUserData {
+    pub fn from_reader(reader: special_user_data::Reader<'_>) -> Result<Self, DataDecodeError> {
+        let name_color = reader.get_name_color()?;
+
+        let name_color = if name_color.is_empty() {
+            None
+        } else {
+            let mut color_reader = ByteReader::new(name_color);
+            let color = MultiColor::decode(&mut color_reader)
+                .map_err(|_| DataDecodeError::ValidationFailed)?;
+
+            if color_reader.remaining() != 0 {
+                return Err(DataDecodeError::ValidationFailed);
+            }
+
+            Some(color)
+        };
+
+        Ok(Self {
+            roles: reader.get_roles()?.iter().collect(),
+            name_color,
+        })
+    }
+
+    pub fn encode(&self, mut builder: special_user_data::Builder<'_>) {
+        let mut roles = builder.reborrow().init_roles(self.roles.len() as u32);
+        for (i, role) in self.roles.iter().enumerate() {
+            roles.set(i as u32, *role);
+        }
+
+        if let Some(color) = &self.name_color {
+            let mut buf = [0u8; 256];
+            let mut writer = ByteWriter::new(&mut buf);
+            color.encode(&mut writer);
+
+            builder.set_name_color(writer.written());
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<special_user_data::Builder<'static>>()
+            + data_size(self.roles.len())
+            + self
+                .name_color
+                .as_ref()
+                .map_or(0, |c| data_size(c.encoded_len()))
+    }
+}
+
+#[cfg(feature = "srvc")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServerRole {
+    pub id: u8,
+    pub string_id: String,
+    pub can_moderate: bool,
+}
+
+#[cfg(feature = "srvc")]
+impl ServerRole {
+    pub fn from_reader(reader: server_role::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            id: reader.get_id(),
+            string_id: reader.get_string_id()?.to_str()?.to_owned(),
+            can_moderate: reader.get_can_moderate(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: server_role::Builder<'_>) {
+        builder.set_id(self.id);
+        builder.set_string_id(self.string_id.as_str());
+        builder.set_can_moderate(self.can_moderate);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<server_role::Builder<'static>>() + text_size(self.string_id.len())
     }
 }
 
 #[cfg(feature = "srvc")]
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SrvLoginOk {
+    pub token_key: String,
+    pub token_expiry: u64,
+    pub roles: Vec<ServerRole>,
+    pub script_key: String,
+}
+
+#[cfg(feature = "srvc")]
+impl SrvLoginOk {
+    pub fn from_reader(reader: login_ok_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            token_key: reader.get_token_key()?.to_str()?.to_owned(),
+            token_expiry: reader.get_token_expiry(),
+            roles: reader
+                .get_roles()?
+                .iter()
+                .map(ServerRole::from_reader)
+                .collect::<Result<_, _>>()?,
+            script_key: reader.get_script_key()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: login_ok_message::Builder<'_>) {
+        builder.set_token_key(self.token_key.as_str());
+        builder.set_token_expiry(self.token_expiry);
+
+        let mut roles = builder.reborrow().init_roles(self.roles.len() as u32);
+        for (i, v) in self.roles.iter().enumerate() {
+            v.encode(roles.reborrow().get(i as u32));
+        }
+
+        builder.set_script_key(self.script_key.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<login_ok_message::Builder<'static>>()
+            + text_size(self.token_key.len())
+            + composite_list_size(self.roles.iter().map(ServerRole::encoded_size).sum())
+            + text_size(self.script_key.len())
+    }
+
+    /// Creates an issuer for validating user tokens, with the key and expiry sent by the central server
+    pub fn token_issuer(&self) -> Result<TokenIssuer, &'static str> {
+        TokenIssuer::new(&self.token_key, Duration::from_secs(self.token_expiry))
+    }
+}
+
+#[cfg(feature = "srvc")]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct SrvUserData {
     pub account_id: i32,
     pub can_use_voice: bool,
@@ -141,10 +321,34 @@ pub struct SrvUserData {
     pub is_banned: bool,
     pub is_muted: bool,
     pub is_linked: bool,
+    /// Unix seconds, 0 means the mute is permanent. Ignored if `is_muted` is false.
+    pub mute_expires_at: i64,
 }
 
 #[cfg(feature = "srvc")]
 impl SrvUserData {
+    /// Sets the mute state from the user's active mute, if any
+    pub fn set_mute(&mut self, mute: Option<&Punishment>) {
+        self.is_muted = mute.is_some();
+        self.mute_expires_at = mute.map_or(0, |m| m.expires_at);
+    }
+
+    /// Whether the user is muted at `now`, taking the mute expiry into account
+    pub fn is_muted_at(&self, now: i64) -> bool {
+        self.is_muted && !punishment_expired(self.mute_expires_at, now)
+    }
+
+    /// Lifts the mute if it has expired, returns whether it did
+    pub fn expire_mute(&mut self, now: i64) -> bool {
+        if self.is_muted && !self.is_muted_at(now) {
+            self.is_muted = false;
+            self.mute_expires_at = 0;
+            true
+        } else {
+            false
+        }
+    }
+
     pub fn from_reader(reader: srv_user_data::Reader<'_>) -> Result<Self, DataDecodeError> {
         Ok(Self {
             account_id: reader.get_account_id(),
@@ -153,6 +357,7 @@ impl SrvUserData {
             is_banned: reader.get_is_banned(),
             is_muted: reader.get_is_muted(),
             is_linked: reader.get_is_linked(),
+            mute_expires_at: reader.get_mute_expires_at(),
         })
     }
 
@@ -163,11 +368,163 @@ impl SrvUserData {
         builder.set_is_banned(self.is_banned);
         builder.set_is_muted(self.is_muted);
         builder.set_is_linked(self.is_linked);
+        builder.set_mute_expires_at(self.mute_expires_at);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<srv_user_data::Builder<'static>>()
     }
 }
 
+/// Changes to a single user's `SrvUserData`, only the fields set in `changed` are applied
 #[cfg(feature = "srvc")]
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SrvUserDataDiff {
+    pub account_id: i32,
+    pub changed: u8,
+    pub can_use_voice: bool,
+    pub can_use_quick_chat: bool,
+    pub is_banned: bool,
+    pub is_linked: bool,
+    pub is_muted: bool,
+    pub mute_expires_at: i64,
+}
+
+#[cfg(feature = "srvc")]
+impl SrvUserDataDiff {
+    pub const CAN_USE_VOICE: u8 = 1 << 0;
+    pub const CAN_USE_QUICK_CHAT: u8 = 1 << 1;
+    pub const IS_BANNED: u8 = 1 << 2;
+    pub const IS_LINKED: u8 = 1 << 3;
+    pub const IS_MUTED: u8 = 1 << 4;
+
+    /// Diff that turns `old` into `new`, or `None` if nothing changed
+    pub fn between(old: &SrvUserData, new: &SrvUserData) -> Option<Self> {
+        let mut changed = 0;
+
+        for (flag, a, b) in [
+            (Self::CAN_USE_VOICE, old.can_use_voice, new.can_use_voice),
+            (
+                Self::CAN_USE_QUICK_CHAT,
+                old.can_use_quick_chat,
+                new.can_use_quick_chat,
+            ),
+            (Self::IS_BANNED, old.is_banned, new.is_banned),
+            (Self::IS_LINKED, old.is_linked, new.is_linked),
+        ] {
+            if a != b {
+                changed |= flag;
+            }
+        }
+
+        if old.is_muted != new.is_muted || old.mute_expires_at != new.mute_expires_at {
+            changed |= Self::IS_MUTED;
+        }
+
+        (changed != 0).then(|| Self {
+            account_id: new.account_id,
+            changed,
+            can_use_voice: new.can_use_voice,
+            can_use_quick_chat: new.can_use_quick_chat,
+            is_banned: new.is_banned,
+            is_linked: new.is_linked,
+            is_muted: new.is_muted,
+            mute_expires_at: new.mute_expires_at,
+        })
+    }
+
+    pub fn apply(&self, data: &mut SrvUserData) {
+        if self.changed & Self::CAN_USE_VOICE != 0 {
+            data.can_use_voice = self.can_use_voice;
+        }
+
+        if self.changed & Self::CAN_USE_QUICK_CHAT != 0 {
+            data.can_use_quick_chat = self.can_use_quick_chat;
+        }
+
+        if self.changed & Self::IS_BANNED != 0 {
+            data.is_banned = self.is_banned;
+        }
+
+        if self.changed & Self::IS_LINKED != 0 {
+            data.is_linked = self.is_linked;
+        }
+
+        if self.changed & Self::IS_MUTED != 0 {
+            data.is_muted = self.is_muted;
+            data.mute_expires_at = self.mute_expires_at;
+        }
+    }
+
+    pub fn from_reader(reader: srv_user_data_diff::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            changed: reader.get_changed(),
+            can_use_voice: reader.get_can_use_voice(),
+            can_use_quick_chat: reader.get_can_use_quick_chat(),
+            is_banned: reader.get_is_banned(),
+            is_linked: reader.get_is_linked(),
+            is_muted: reader.get_is_muted(),
+            mute_expires_at: reader.get_mute_expires_at(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: srv_user_data_diff::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        builder.set_changed(self.changed);
+        builder.set_can_use_voice(self.can_use_voice);
+        builder.set_can_use_quick_chat(self.can_use_quick_chat);
+        builder.set_is_banned(self.is_banned);
+        builder.set_is_linked(self.is_linked);
+        builder.set_is_muted(self.is_muted);
+        builder.set_mute_expires_at(self.mute_expires_at);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<srv_user_data_diff::Builder<'static>>()
+    }
+}
+
+#[cfg(feature = "srvc")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SessionPlayerCount {
+    pub session_id: u64,
+    pub players: u32,
+}
+
+#[cfg(feature = "srvc")]
+impl SessionPlayerCount {
+    pub fn from_reader(reader: session_player_count::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            session_id: reader.get_session_id(),
+            players: reader.get_players(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: session_player_count::Builder<'_>) {
+        builder.set_session_id(self.session_id);
+        builder.set_players(self.players);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<session_player_count::Builder<'static>>()
+    }
+}
+
+/// Limits of a game server, against which `SrvStatusData::compute_server_load` measures usage
+#[cfg(feature = "srvc")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ServerCapacity {
+    /// Authenticated clients the server is expected to handle
+    pub max_clients: u32,
+    /// Time a single tick may take, in microseconds
+    pub tick_budget: u32,
+    /// Outgoing traffic the server can sustain, in bytes per second, or 0 if unlimited
+    pub max_bandwidth: u64,
+}
+
+#[cfg(feature = "srvc")]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct SrvStatusData {
     pub clients: u32,
     pub auth_clients: u32,
@@ -176,6 +533,15 @@ pub struct SrvStatusData {
     pub total_connections: u64,
     pub total_data_messages: u64,
     pub server_load: f32,
+    pub session_players: Vec<SessionPlayerCount>,
+    pub tick_p50: u32,
+    pub tick_p95: u32,
+    pub tick_p99: u32,
+    pub bandwidth_in: u64,
+    pub bandwidth_out: u64,
+    pub memory_usage: u64,
+    pub memory_limit: u64,
+    pub uptime: u64,
 }
 
 #[cfg(feature = "srvc")]
@@ -189,6 +555,19 @@ impl SrvStatusData {
             total_connections: reader.get_total_connections(),
             total_data_messages: reader.get_total_data_messages(),
             server_load: reader.get_server_load(),
+            session_players: reader
+                .get_session_players()?
+                .iter()
+                .map(SessionPlayerCount::from_reader)
+                .collect::<Result<_, _>>()?,
+            tick_p50: reader.get_tick_p50(),
+            tick_p95: reader.get_tick_p95(),
+            tick_p99: reader.get_tick_p99(),
+            bandwidth_in: reader.get_bandwidth_in(),
+            bandwidth_out: reader.get_bandwidth_out(),
+            memory_usage: reader.get_memory_usage(),
+            memory_limit: reader.get_memory_limit(),
+            uptime: reader.get_uptime(),
         })
     }
 
@@ -200,5 +579,311 @@ impl SrvStatusData {
         builder.set_total_connections(self.total_connections);
         builder.set_total_data_messages(self.total_data_messages);
         builder.set_server_load(self.server_load);
+
+        let mut session_players = builder
+            .reborrow()
+            .init_session_players(self.session_players.len() as u32);
+        for (i, v) in self.session_players.iter().enumerate() {
+            v.encode(session_players.reborrow().get(i as u32));
+        }
+
+        builder.set_tick_p50(self.tick_p50);
+        builder.set_tick_p95(self.tick_p95);
+        builder.set_tick_p99(self.tick_p99);
+        builder.set_bandwidth_in(self.bandwidth_in);
+        builder.set_bandwidth_out(self.bandwidth_out);
+        builder.set_memory_usage(self.memory_usage);
+        builder.set_memory_limit(self.memory_limit);
+        builder.set_uptime(self.uptime);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<status_message::Builder<'static>>()
+            + composite_list_size(
+                self.session_players
+                    .iter()
+                    .map(SessionPlayerCount::encoded_size)
+                    .sum(),
+            )
+    }
+
+    /// Sets the tick percentiles from a window of tick durations in microseconds, sorting it in place
+    pub fn set_tick_times(&mut self, samples: &mut [u32]) {
+        samples.sort_unstable();
+
+        let percentile = |p: usize| {
+            samples
+                .len()
+                .checked_sub(1)
+                .map_or(0, |last| samples[last * p / 100])
+        };
+
+        self.tick_p50 = percentile(50);
+        self.tick_p95 = percentile(95);
+        self.tick_p99 = percentile(99);
+    }
+
+    /// Sets the memory usage, with the limit taken from the `memory_usage` config tier,
+    /// as passed to `make_memory_limits`
+    pub fn set_memory_usage(&mut self, usage: u64, memory_usage_tier: u32) {
+        self.memory_usage = usage;
+        self.memory_limit = make_memory_limits(memory_usage_tier).1 as u64;
+    }
+
+    /// Estimated load from 0 to 1, which is the highest utilization of any of the limited resources:
+    /// clients, tick time, outgoing bandwidth and memory.
+    pub fn compute_server_load(&self, capacity: &ServerCapacity) -> f32 {
+        fn ratio(value: u64, limit: u64) -> f32 {
+            if limit == 0 {
+                0.0
+            } else {
+                value as f32 / limit as f32
+            }
+        }
+
+        let load = ratio(self.auth_clients as u64, capacity.max_clients as u64)
+            .max(ratio(self.tick_p95 as u64, capacity.tick_budget as u64))
+            .max(ratio(self.bandwidth_out, capacity.max_bandwidth))
+            .max(ratio(self.memory_usage, self.memory_limit));
+
+        load.clamp(0.0, 1.0)
+    }
+
+    /// Updates `server_load`, should be called after all the other fields are filled in
+    pub fn update_server_load(&mut self, capacity: &ServerCapacity) {
+        self.server_load = self.compute_server_load(capacity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn punishment(expires_at: i64) -> Punishment {
+        Punishment {
+            issued_by: 1,
+            issued_at: 100,
+            reason: "reason".into(),
+            expires_at,
+        }
+    }
+
+    #[test]
+    fn zero_expiry_is_permanent() {
+        let p = punishment(0);
+
+        assert!(p.is_permanent());
+        assert!(!p.is_expired_at(0));
+        assert!(!p.is_expired_at(i64::MAX));
+        assert_eq!(p.remaining_at(i64::MAX), None);
+    }
+
+    #[test]
+    fn expired_at_expiry_time() {
+        let p = punishment(1000);
+
+        assert!(!p.is_permanent());
+        assert!(!p.is_expired_at(999));
+        assert!(p.is_expired_at(1000));
+        assert!(p.is_expired_at(1001));
+    }
+
+    #[test]
+    fn remaining_time() {
+        let p = punishment(1000);
+
+        assert_eq!(p.remaining_at(400), Some(Duration::from_secs(600)));
+        assert_eq!(p.remaining_at(999), Some(Duration::from_secs(1)));
+        assert_eq!(p.remaining_at(1000), Some(Duration::ZERO));
+        assert_eq!(p.remaining_at(5000), Some(Duration::ZERO));
+    }
+
+    #[cfg(feature = "srvc")]
+    mod srvc {
+        use super::*;
+
+        fn muted(expires_at: i64) -> SrvUserData {
+            SrvUserData {
+                account_id: 7,
+                is_muted: true,
+                mute_expires_at: expires_at,
+                ..Default::default()
+            }
+        }
+
+        #[test]
+        fn set_mute() {
+            let mut data = SrvUserData::default();
+
+            data.set_mute(Some(&punishment(1000)));
+            assert!(data.is_muted);
+            assert_eq!(data.mute_expires_at, 1000);
+
+            data.set_mute(None);
+            assert!(!data.is_muted);
+            assert_eq!(data.mute_expires_at, 0);
+        }
+
+        #[test]
+        fn mute_expires() {
+            let mut data = muted(1000);
+
+            assert!(data.is_muted_at(999));
+            assert!(!data.is_muted_at(1000));
+
+            assert!(!data.expire_mute(999));
+            assert!(data.is_muted);
+
+            assert!(data.expire_mute(1000));
+            assert!(!data.is_muted);
+            assert_eq!(data.mute_expires_at, 0);
+
+            assert!(!data.expire_mute(1001));
+        }
+
+        #[test]
+        fn permanent_mute_never_expires() {
+            let mut data = muted(0);
+
+            assert!(data.is_muted_at(i64::MAX));
+            assert!(!data.expire_mute(i64::MAX));
+            assert!(data.is_muted);
+        }
+
+        #[test]
+        fn mute_expiry_change_is_diffed() {
+            let old = muted(1000);
+
+            for new in [muted(2000), muted(0)] {
+                let diff = SrvUserDataDiff::between(&old, &new).unwrap();
+                assert_eq!(diff.changed, SrvUserDataDiff::IS_MUTED);
+                assert_eq!(diff.mute_expires_at, new.mute_expires_at);
+
+                let mut applied = old.clone();
+                diff.apply(&mut applied);
+                assert_eq!(applied, new);
+            }
+        }
+
+        #[test]
+        fn unchanged_is_not_diffed() {
+            assert_eq!(SrvUserDataDiff::between(&muted(1000), &muted(1000)), None);
+        }
+
+        const CAPACITY: ServerCapacity = ServerCapacity {
+            max_clients: 100,
+            tick_budget: 1000,
+            max_bandwidth: 10_000,
+        };
+
+        fn status() -> SrvStatusData {
+            SrvStatusData {
+                memory_limit: 1000,
+                ..Default::default()
+            }
+        }
+
+        #[test]
+        fn idle_server_has_no_load() {
+            assert_eq!(status().compute_server_load(&CAPACITY), 0.0);
+        }
+
+        #[test]
+        fn load_at_capacity() {
+            let at_limit = [
+                SrvStatusData {
+                    auth_clients: 100,
+                    ..status()
+                },
+                SrvStatusData {
+                    tick_p95: 1000,
+                    ..status()
+                },
+                SrvStatusData {
+                    bandwidth_out: 10_000,
+                    ..status()
+                },
+                SrvStatusData {
+                    memory_usage: 1000,
+                    ..status()
+                },
+            ];
+
+            for s in at_limit {
+                assert_eq!(s.compute_server_load(&CAPACITY), 1.0, "{s:?}");
+            }
+
+            let half = SrvStatusData {
+                auth_clients: 50,
+                tick_p95: 250,
+                ..status()
+            };
+            assert_eq!(half.compute_server_load(&CAPACITY), 0.5);
+        }
+
+        #[test]
+        fn load_over_capacity_is_clamped() {
+            let s = SrvStatusData {
+                auth_clients: 500,
+                bandwidth_out: 1_000_000,
+                ..status()
+            };
+
+            assert_eq!(s.compute_server_load(&CAPACITY), 1.0);
+        }
+
+        #[test]
+        fn zero_capacity_is_ignored() {
+            let capacity = ServerCapacity {
+                max_clients: 0,
+                tick_budget: 0,
+                max_bandwidth: 0,
+            };
+            let s = SrvStatusData {
+                auth_clients: 10,
+                tick_p95: 10,
+                bandwidth_out: 10,
+                memory_usage: 10,
+                memory_limit: 0,
+                ..Default::default()
+            };
+
+            assert_eq!(s.compute_server_load(&capacity), 0.0);
+        }
+
+        #[test]
+        fn tick_percentiles() {
+            let mut samples: Vec<u32> = (1..=100).rev().collect();
+            let mut s = status();
+            s.set_tick_times(&mut samples);
+
+            assert_eq!((s.tick_p50, s.tick_p95, s.tick_p99), (50, 95, 99));
+        }
+
+        #[test]
+        fn no_tick_samples() {
+            let mut s = SrvStatusData {
+                tick_p50: 1,
+                tick_p95: 2,
+                tick_p99: 3,
+                ..status()
+            };
+            s.set_tick_times(&mut []);
+
+            assert_eq!((s.tick_p50, s.tick_p95, s.tick_p99), (0, 0, 0));
+        }
+
+        #[test]
+        fn memory_limit_from_tier() {
+            let mut s = status();
+
+            s.set_memory_usage(1234, 3);
+            assert_eq!(s.memory_usage, 1234);
+            assert_eq!(s.memory_limit, 1024 * 1024);
+
+            // out of range tiers are clamped like in the config
+            s.set_memory_usage(0, 0);
+            assert_eq!(s.memory_limit, 64 * 1024);
+        }
     }
 }
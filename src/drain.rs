@@ -0,0 +1,201 @@
+use rustc_hash::FxHashMap;
+
+#[cfg(feature = "main")]
+use crate::messages::main::WarpPlayerMessage;
+use crate::{
+    SessionId,
+    data::GameServerData,
+    load_balancer::{PlacementRequest, SelectionStrategy, ServerCandidate, rank_servers},
+};
+
+/// A session on a draining server and the session its players should be warped to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SessionMigration {
+    pub from: SessionId,
+    pub to: SessionId,
+}
+
+impl SessionMigration {
+    /// Message for the main server to send to every player in the `from` session
+    #[cfg(feature = "main")]
+    pub fn warp_message(&self) -> WarpPlayerMessage {
+        WarpPlayerMessage {
+            session: self.to.as_u64(),
+        }
+    }
+}
+
+/// Moves the sessions of a draining server to the remaining servers, the draining server itself
+/// is skipped as long as its `draining` flag is set. All sessions of a room end up on the same server,
+/// except in the global room, where sessions are independent. Rooms are spread over the ranked servers
+/// in a round-robin fashion, since server load is not updated until the next status message.
+/// Returns an empty list if no other server can take the sessions.
+pub fn plan_migration<'a>(
+    sessions: impl IntoIterator<Item = SessionId>,
+    candidates: impl IntoIterator<Item = ServerCandidate<'a>>,
+    request: &PlacementRequest<'_>,
+    strategy: &dyn SelectionStrategy,
+) -> Vec<SessionMigration> {
+    let ranked: Vec<&GameServerData> = rank_servers(candidates, request, strategy)
+        .into_iter()
+        .map(|c| c.server)
+        .collect();
+
+    if ranked.is_empty() {
+        return Vec::new();
+    }
+
+    let mut room_servers = FxHashMap::default();
+
+    sessions
+        .into_iter()
+        .map(|from| {
+            let next = room_servers.len() % ranked.len();
+            let key = match from.room_id() {
+                0 => (0, from.level_id()),
+                room => (room, 0),
+            };
+
+            let server = *room_servers.entry(key).or_insert_with(|| ranked[next].id);
+
+            SessionMigration {
+                from,
+                to: from.with_server_id(server),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{data::SrvStatusData, load_balancer::LeastLoad};
+
+    fn server(id: u8, load: f32, draining: bool) -> (GameServerData, SrvStatusData) {
+        let server = GameServerData {
+            id,
+            address: "127.0.0.1:4202".try_into().unwrap(),
+            string_id: format!("server-{id}").as_str().try_into().unwrap(),
+            name: "Server".try_into().unwrap(),
+            region: "eu".try_into().unwrap(),
+            draining,
+        };
+
+        let status = SrvStatusData {
+            server_load: load,
+            ..Default::default()
+        };
+
+        (server, status)
+    }
+
+    fn plan(
+        sessions: &[SessionId],
+        servers: &[(GameServerData, SrvStatusData)],
+    ) -> Vec<SessionMigration> {
+        let candidates = servers
+            .iter()
+            .map(|(server, status)| ServerCandidate { server, status });
+
+        plan_migration(
+            sessions.iter().copied(),
+            candidates,
+            &PlacementRequest::default(),
+            &LeastLoad,
+        )
+    }
+
+    #[test]
+    fn round_robin() {
+        let servers = [
+            server(1, 0.0, true),
+            server(2, 0.5, false),
+            server(3, 0.1, false),
+            server(4, 0.3, false),
+        ];
+
+        let sessions: Vec<_> = (1..=7)
+            .map(|room| SessionId::from_parts(1, 100, room))
+            .collect();
+
+        let targets: Vec<u8> = plan(&sessions, &servers)
+            .iter()
+            .map(|m| m.to.server_id())
+            .collect();
+
+        // ranked by load: 3, 4, 2
+        assert_eq!(targets, [3, 4, 2, 3, 4, 2, 3]);
+    }
+
+    #[test]
+    fn rooms_stay_together() {
+        let servers = [
+            server(1, 0.0, true),
+            server(2, 0.1, false),
+            server(3, 0.2, false),
+        ];
+
+        let sessions = [
+            SessionId::from_parts(1, 100, 5),
+            SessionId::from_parts(1, 200, 6),
+            SessionId::from_parts(1, 300, 5),
+            SessionId::from_parts(1, 400, 6),
+            SessionId::from_parts(1, 500, 5),
+        ];
+
+        let plan = plan(&sessions, &servers);
+        assert_eq!(plan.len(), sessions.len());
+
+        for m in &plan {
+            let expected = if m.from.room_id() == 5 { 2 } else { 3 };
+            assert_eq!(m.to.server_id(), expected, "{m:?}");
+        }
+    }
+
+    #[test]
+    fn global_room_sessions_are_independent() {
+        let servers = [
+            server(1, 0.0, true),
+            server(2, 0.1, false),
+            server(3, 0.2, false),
+        ];
+
+        let sessions = [
+            SessionId::from_parts(1, 100, 0),
+            SessionId::from_parts(1, 200, 0),
+            SessionId::from_parts(1, 100, 0),
+        ];
+
+        let targets: Vec<u8> = plan(&sessions, &servers)
+            .iter()
+            .map(|m| m.to.server_id())
+            .collect();
+
+        assert_eq!(targets, [2, 3, 2]);
+    }
+
+    #[test]
+    fn keeps_room_and_level() {
+        let servers = [server(2, 0.1, false)];
+        let from = SessionId::from_parts(1, -5, 0xabcdef);
+
+        let plan = plan(&[from], &servers);
+        assert_eq!(
+            plan,
+            [SessionMigration {
+                from,
+                to: SessionId::from_parts(2, -5, 0xabcdef),
+            }]
+        );
+    }
+
+    #[test]
+    fn no_targets() {
+        let sessions = [SessionId::from_parts(1, 100, 5)];
+
+        assert!(plan(&sessions, &[]).is_empty());
+        assert!(plan(&sessions, &[server(1, 0.0, true), server(2, 0.1, true)]).is_empty());
+        assert!(plan(&sessions, &[server(2, 1.0, false)]).is_empty());
+        assert!(plan(&[], &[server(2, 0.1, false)]).is_empty());
+    }
+}
@@ -1,31 +1,100 @@
+use std::cell::RefCell;
+
 use capnp::message::Allocator;
+use tracing::warn;
+
+const MAX_POOLED_SEGMENTS: usize = 8;
+const MAX_POOLED_SEGMENT_WORDS: usize = 16384; // 128 KiB
+
+thread_local! {
+    static SEGMENT_POOL: RefCell<Vec<Box<[u64]>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Hands out heap segments once the primary buffer of an allocator runs out of space,
+/// so that a bad capacity estimate results in a slower encode rather than a failure.
+/// Freed segments are zeroed and kept in a small thread-local pool for reuse.
+struct FallbackSegments {
+    next_size: u32,
+}
+
+impl FallbackSegments {
+    const fn new() -> Self {
+        Self { next_size: 64 }
+    }
+
+    fn allocate(&mut self, size_words: u32, primary_size: usize) -> (*mut u8, u32) {
+        let size = size_words.max(self.next_size);
+
+        warn!(
+            "capnp buffer of {primary_size} bytes is too small, allocating a fallback segment of {} bytes",
+            size as usize * 8
+        );
+
+        let segment = SEGMENT_POOL
+            .with_borrow_mut(|pool| {
+                pool.iter()
+                    .position(|s| s.len() >= size as usize)
+                    .map(|i| pool.swap_remove(i))
+            })
+            .unwrap_or_else(|| vec![0u64; size as usize].into_boxed_slice());
+
+        self.next_size = self.next_size.saturating_add(size);
+
+        let len = segment.len() as u32;
+        (Box::into_raw(segment).cast::<u8>(), len)
+    }
 
+    /// Safety: `ptr` must have been returned by `allocate` with the given size
+    unsafe fn deallocate(ptr: *mut u8, word_size: u32, words_used: u32) {
+        let mut segment = unsafe {
+            Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+                ptr.cast::<u64>(),
+                word_size as usize,
+            ))
+        };
+
+        if segment.len() > MAX_POOLED_SEGMENT_WORDS {
+            return;
+        }
+
+        segment[..words_used as usize].fill(0);
+
+        SEGMENT_POOL.with_borrow_mut(|pool| {
+            if pool.len() < MAX_POOLED_SEGMENTS {
+                pool.push(segment);
+            }
+        });
+    }
+}
+
+/// Allocator that uses an inline buffer of `N` bytes, falling back to heap segments if the message doesn't fit.
 #[repr(align(8))]
 pub struct CapnpAlloc<const N: usize> {
     buf: [u8; N],
     called: bool,
+    fallback: FallbackSegments,
 }
 
 unsafe impl<const N: usize> Allocator for CapnpAlloc<N> {
     #[inline]
     fn allocate_segment(&mut self, size_words: u32) -> (*mut u8, u32) {
-        if self.called {
-            panic!("CapnpAlloc::allocate_segment called multiple times");
-        }
-
         let size = (size_words * 8) as usize;
 
-        self.called = true;
-
-        if size > N {
-            panic!("Not enough space in CapnpAlloc");
+        if self.called || size > N {
+            return self.fallback.allocate(size_words, N);
         }
 
+        self.called = true;
+
         (self.buf.as_mut_ptr(), (N / 8) as u32)
     }
 
     #[inline]
-    unsafe fn deallocate_segment(&mut self, _ptr: *mut u8, _word_size: u32, _words_used: u32) {}
+    unsafe fn deallocate_segment(&mut self, ptr: *mut u8, word_size: u32, words_used: u32) {
+        if ptr != self.buf.as_mut_ptr() {
+            unsafe { FallbackSegments::deallocate(ptr, word_size, words_used) }
+        }
+    }
 }
 
 impl<const N: usize> CapnpAlloc<N> {
@@ -33,6 +102,7 @@ impl<const N: usize> CapnpAlloc<N> {
         Self {
             buf: [0; N],
             called: false,
+            fallback: FallbackSegments::new(),
         }
     }
 }
@@ -43,31 +113,33 @@ impl<const N: usize> Default for CapnpAlloc<N> {
     }
 }
 
+/// Allocator that uses a borrowed buffer, falling back to heap segments if the message doesn't fit.
 pub struct CapnpBorrowAlloc<'a> {
     buf: &'a mut [u8],
     called: bool,
+    fallback: FallbackSegments,
 }
 
 unsafe impl<'a> Allocator for CapnpBorrowAlloc<'a> {
     #[inline]
     fn allocate_segment(&mut self, size_words: u32) -> (*mut u8, u32) {
-        if self.called {
-            panic!("CapnpAlloc::allocate_segment called multiple times");
-        }
-
         let size = (size_words * 8) as usize;
 
-        self.called = true;
-
-        if size > self.buf.len() {
-            panic!("Not enough space in CapnpAlloc");
+        if self.called || size > self.buf.len() {
+            return self.fallback.allocate(size_words, self.buf.len());
         }
 
+        self.called = true;
+
         (self.buf.as_mut_ptr(), (self.buf.len() / 8) as u32)
     }
 
     #[inline]
-    unsafe fn deallocate_segment(&mut self, _ptr: *mut u8, _word_size: u32, _words_used: u32) {}
+    unsafe fn deallocate_segment(&mut self, ptr: *mut u8, word_size: u32, words_used: u32) {
+        if ptr != self.buf.as_mut_ptr() {
+            unsafe { FallbackSegments::deallocate(ptr, word_size, words_used) }
+        }
+    }
 }
 
 impl<'a> CapnpBorrowAlloc<'a> {
@@ -87,7 +159,11 @@ impl<'a> CapnpBorrowAlloc<'a> {
             }
         }
 
-        Self { buf, called: false }
+        Self {
+            buf,
+            called: false,
+            fallback: FallbackSegments::new(),
+        }
     }
 }
 
@@ -137,3 +213,62 @@ impl Default for CapnpHeapAlloc {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use capnp::{any_pointer, data, message::Builder};
+
+    use super::*;
+
+    fn pool_len() -> usize {
+        SEGMENT_POOL.with_borrow(|pool| pool.len())
+    }
+
+    /// Builds a message with a data blob of `len` bytes, which doesn't fit in the single word primary buffer.
+    /// Returns the address of the fallback segment.
+    fn build_with_fallback(len: u32) -> usize {
+        let mut builder = Builder::new(CapnpAlloc::<8>::new());
+
+        let blob: data::Builder<'_> = builder
+            .init_root::<any_pointer::Builder<'_>>()
+            .initn_as(len);
+
+        // reused segments must come back zeroed
+        assert!(blob.iter().all(|&b| b == 0));
+        blob.fill(0xff);
+
+        let segments = builder.get_segments_for_output();
+        assert_eq!(segments.len(), 2);
+
+        segments[1].as_ptr() as usize
+    }
+
+    #[test]
+    fn fallback_reused_after_drop() {
+        assert_eq!(pool_len(), 0);
+
+        let first = build_with_fallback(800);
+        assert_eq!(pool_len(), 1);
+
+        let second = build_with_fallback(800);
+        assert_eq!(second, first);
+        assert_eq!(pool_len(), 1);
+
+        // a smaller message can reuse the larger pooled segment
+        let third = build_with_fallback(80);
+        assert_eq!(third, first);
+        assert_eq!(pool_len(), 1);
+    }
+
+    #[test]
+    fn oversized_fallback_not_pooled() {
+        let len = ((MAX_POOLED_SEGMENT_WORDS + 1) * 8) as u32;
+
+        build_with_fallback(len);
+        assert_eq!(pool_len(), 0);
+
+        // regular segments are still pooled afterwards
+        build_with_fallback(800);
+        assert_eq!(pool_len(), 1);
+    }
+}
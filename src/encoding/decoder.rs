@@ -0,0 +1,141 @@
+use capnp::{
+    message::{ReaderOptions, ReaderSegments, TypedReader},
+    traits::Owned,
+};
+use qunet::buffers::ByteReader;
+
+use super::{DataDecodeError, MAX_MESSAGE_SIZE};
+
+/// Decodes length-prefixed packed capnp messages, the same format that `decode_message_match!` accepts,
+/// but synchronously and without requiring a qunet server for buffers.
+#[derive(Clone, Copy, Debug)]
+pub struct MessageDecoder {
+    /// Maximum size of the unpacked message in bytes
+    pub max_message_size: usize,
+    /// Maximum amount of words that may be read from the message, `None` means unlimited
+    pub traversal_limit_words: Option<usize>,
+    /// Maximum nesting depth of pointers in the message
+    pub nesting_limit: i32,
+}
+
+impl Default for MessageDecoder {
+    fn default() -> Self {
+        let opts = ReaderOptions::new();
+
+        Self {
+            max_message_size: MAX_MESSAGE_SIZE,
+            traversal_limit_words: opts.traversal_limit_in_words,
+            nesting_limit: opts.nesting_limit,
+        }
+    }
+}
+
+impl MessageDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn reader_options(&self) -> ReaderOptions {
+        let mut opts = ReaderOptions::new();
+        opts.traversal_limit_in_words(self.traversal_limit_words)
+            .nesting_limit(self.nesting_limit);
+        opts
+    }
+
+    /// Decodes a message with root type `T`, using `buf` as the storage for the unpacked message.
+    /// The returned reader borrows from `buf`, so the buffer can be reused between messages.
+    pub fn decode<'a, T: Owned>(
+        &self,
+        data: &[u8],
+        buf: &'a mut Vec<u8>,
+    ) -> Result<TypedReader<impl ReaderSegments + 'a, T>, DataDecodeError> {
+        let mut reader = ByteReader::new(data);
+        let unpacked_len = reader.read_varuint()? as usize;
+
+        if unpacked_len > self.max_message_size {
+            return Err(DataDecodeError::MessageTooLong(unpacked_len));
+        }
+
+        buf.clear();
+        buf.resize(unpacked_len, 0);
+
+        let mut rembuf = reader.remaining_bytes();
+        let message = capnp::serialize_packed::read_message_no_alloc(
+            &mut rembuf,
+            buf,
+            self.reader_options(),
+        )?;
+
+        Ok(TypedReader::new(message))
+    }
+
+    /// Decodes a message of the central server protocol
+    #[cfg(feature = "main")]
+    pub fn decode_main<'a>(
+        &self,
+        data: &[u8],
+        buf: &'a mut Vec<u8>,
+    ) -> Result<TypedReader<impl ReaderSegments + 'a, crate::main::message::Owned>, DataDecodeError>
+    {
+        self.decode(data, buf)
+    }
+
+    /// Decodes a message of the game server protocol
+    #[cfg(feature = "game")]
+    pub fn decode_game<'a>(
+        &self,
+        data: &[u8],
+        buf: &'a mut Vec<u8>,
+    ) -> Result<TypedReader<impl ReaderSegments + 'a, crate::game::message::Owned>, DataDecodeError>
+    {
+        self.decode(data, buf)
+    }
+
+    /// Decodes a message of the central server <-> game server protocol
+    #[cfg(feature = "srvc")]
+    pub fn decode_srvc<'a>(
+        &self,
+        data: &[u8],
+        buf: &'a mut Vec<u8>,
+    ) -> Result<TypedReader<impl ReaderSegments + 'a, crate::srvc::message::Owned>, DataDecodeError>
+    {
+        self.decode(data, buf)
+    }
+}
+
+#[cfg(all(test, feature = "game"))]
+mod tests {
+    use super::*;
+    use crate::messages::{
+        MAX_META_REQUESTS,
+        game::{GameMessage, PlayerUpdateMetaMessage},
+    };
+
+    #[test]
+    fn message_size_limit() {
+        let msg = GameMessage::PlayerUpdateMeta(PlayerUpdateMetaMessage {
+            requests: (0..MAX_META_REQUESTS as i32).collect(),
+            ..Default::default()
+        });
+
+        let mut buf = Vec::new();
+        msg.encode_to_vec(&mut buf).unwrap();
+        let size = ByteReader::new(&buf).read_varuint().unwrap() as usize;
+
+        let mut scratch = Vec::new();
+        let at_limit = MessageDecoder {
+            max_message_size: size,
+            ..Default::default()
+        };
+        assert!(at_limit.decode_game(&buf, &mut scratch).is_ok());
+
+        let over_limit = MessageDecoder {
+            max_message_size: size - 1,
+            ..Default::default()
+        };
+        assert!(matches!(
+            over_limit.decode_game(&buf, &mut scratch),
+            Err(DataDecodeError::MessageTooLong(len)) if len == size
+        ));
+    }
+}
@@ -1,15 +1,23 @@
-use std::{any::Any, fmt::Display};
+use std::{fmt::Display, panic::Location};
 
 pub use allocators::{CapnpAlloc, CapnpBorrowAlloc};
+pub use decoder::MessageDecoder;
+pub use packing::{MessageSegments, PackStrategy};
 
 #[cfg(feature = "dyn-encoding")]
 pub use allocators::CapnpHeapAlloc;
 
 use capnp::message::{Allocator, Builder};
-use qunet::buffers::ByteReaderError;
+use qunet::buffers::{ByteReaderError, ByteWriter};
 use thiserror::Error;
 
 mod allocators;
+mod decoder;
+pub mod packing;
+pub mod size;
+
+/// Default limit for the size of an unpacked incoming message
+pub const MAX_MESSAGE_SIZE: usize = 1024 * 1024;
 
 // Encoding macros
 
@@ -35,6 +43,8 @@ pub enum DataDecodeError {
     InvalidFloat,
     #[error("data is logically invalid, validation failed")]
     ValidationFailed,
+    #[error("validation failed: {0}")]
+    Validation(#[from] crate::messages::ValidationError),
 }
 
 impl From<capnp::NotInSchema> for DataDecodeError {
@@ -53,7 +63,7 @@ macro_rules! decode_message_match {
             let mut reader = $crate::qunet::buffers::ByteReader::new($data.as_bytes());
             let unpacked_len = reader.read_varuint()? as usize;
 
-            if unpacked_len > 1024 * 1024 {
+            if unpacked_len > $crate::encoding::MAX_MESSAGE_SIZE {
                 Err($crate::encoding::DataDecodeError::MessageTooLong(unpacked_len))?;
             }
 
@@ -89,69 +99,102 @@ macro_rules! decode_message_match {
     }};
 }
 
-#[derive(Debug)]
-pub enum EncodeMessageError {
-    Panic {
-        payload: Box<dyn Any + Send + 'static>,
-        file: &'static str,
-        line: u32,
-    },
+/// Source location of an encoding call, used for error reporting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncodeLocation {
+    pub file: &'static str,
+    pub line: u32,
+    pub column: u32,
+}
 
+impl From<&'static Location<'static>> for EncodeLocation {
+    fn from(loc: &'static Location<'static>) -> Self {
+        Self {
+            file: loc.file(),
+            line: loc.line(),
+            column: loc.column(),
+        }
+    }
+}
+
+impl Display for EncodeLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum EncodeErrorKind {
+    #[error("capnp error: {0}")]
+    Capnp(#[from] capnp::Error),
+    #[error("message too long")]
     MessageTooLong,
 }
 
-impl std::error::Error for EncodeMessageError {}
+#[derive(Debug, Error)]
+#[error(
+    "failed to encode {message} at {location} ({needed} bytes needed, {requested} requested): {kind}"
+)]
+pub struct EncodeMessageError {
+    pub kind: EncodeErrorKind,
+    /// Name of the message variant being encoded, e.g. `LoginOk`, or the schema path when the variant is not given
+    pub message: &'static str,
+    /// Capacity estimate passed by the caller, in bytes. `usize::MAX` for dynamic allocation
+    pub requested: usize,
+    /// Unpacked size of the message at the point of failure, in bytes
+    pub needed: usize,
+    pub location: EncodeLocation,
+}
 
-impl Display for EncodeMessageError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Panic {
-                payload,
-                file,
-                line,
-            } => {
-                if let Some(err) = payload.downcast_ref::<String>() {
-                    write!(f, "error: {err} ({file}:{line})")
-                } else if let Some(err) = payload.downcast_ref::<&str>() {
-                    write!(f, "error: {err} ({file}:{line})")
-                } else {
-                    write!(
-                        f,
-                        "unknown error type: {:?} ({}:{})",
-                        (**payload).type_id(),
-                        file,
-                        line
-                    )
-                }
-            }
+/// Allows the code passed to the encoding macros to either return nothing or a `capnp::Result`
+pub trait IntoEncodeResult {
+    fn _into_encode_result(self) -> capnp::Result<()>;
+}
 
-            Self::MessageTooLong => {
-                write!(f, "message too long, could not encode")
-            }
-        }
+impl IntoEncodeResult for () {
+    fn _into_encode_result(self) -> capnp::Result<()> {
+        Ok(())
+    }
+}
+
+impl IntoEncodeResult for capnp::Result<()> {
+    fn _into_encode_result(self) -> capnp::Result<()> {
+        self
     }
 }
 
 #[macro_export]
 #[doc(hidden)]
 macro_rules! encode_with_builder {
-    ($($schema:ident)::*, $srvr:expr, $estcap:expr, $builder:expr, $msg:ident => $code:expr) => {{
+    ($($schema:ident)::*, $srvr:expr, $estcap:expr, $strategy:expr, $builder:expr, $name:expr, $msg:ident => $code:expr) => {{
         use $($schema::)*{self as schema};
+        use $crate::encoding::IntoEncodeResult;
 
         let _res: Result<$crate::qunet::message::BufferKind, $crate::encoding::EncodeMessageError> = (|| {
             let server = $srvr;
 
-            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let code_res = {
                 let mut $msg = $builder.init_root::<schema::message::Builder>();
                 $code
-            }))
-            .map_err(|e| $crate::encoding::EncodeMessageError::Panic {
-                payload: e,
-                file: file!(),
-                line: line!(),
-            })?;
+            }
+            ._into_encode_result();
+
+            let segments = $crate::encoding::MessageSegments::new(&$builder);
+            let ser_size = segments.unpacked_size();
+
+            let make_err = |kind| $crate::encoding::EncodeMessageError {
+                kind,
+                message: $name,
+                requested: $estcap,
+                needed: ser_size,
+                location: $crate::encoding::EncodeLocation {
+                    file: file!(),
+                    line: line!(),
+                    column: column!(),
+                },
+            };
 
-            let ser_size = $crate::capnp::serialize::compute_serialized_size_in_words(&$builder) * 8;
+            code_res.map_err(|e| make_err(e.into()))?;
 
             #[cfg(debug_assertions)]
             {
@@ -169,17 +212,22 @@ macro_rules! encode_with_builder {
                 }
             }
 
-            // the constant here is added for the varuint length prefix (4) and for potential packing overhead (4)
-            let mut buf = server.request_buffer(ser_size + 8);
+            let (strategy, out_size) = segments.plan($strategy);
+
+            // the constant here is added for the varuint length prefix
+            let mut buf = server.request_buffer(out_size + 4);
 
             let mut tmp_len_buf = [0u8; 4];
             let mut len_buf = $crate::qunet::buffers::ByteWriter::new(&mut tmp_len_buf);
-            len_buf.write_varuint(ser_size as u64).map_err(|_| $crate::encoding::EncodeMessageError::MessageTooLong)?;
+            len_buf
+                .write_varuint(ser_size as u64)
+                .map_err(|_| make_err($crate::encoding::EncodeErrorKind::MessageTooLong))?;
             let len_written = len_buf.written();
             buf.append_bytes(len_written);
 
-            // this must never fail at this point
-            $crate::capnp::serialize_packed::write_message(&mut buf, &$builder).expect("capnp write failed");
+            // pack straight from the arena into the outgoing buffer, the size is exact so the window gets fully written
+            let wnd = unsafe { buf.write_window(out_size).expect("buffer too small for the message") };
+            segments.write(strategy, wnd);
 
             Ok(buf)
         })();
@@ -188,24 +236,103 @@ macro_rules! encode_with_builder {
     }};
 }
 
+/// Builds a message with `f` and appends it to `out`, prefixed with its unpacked length, as expected by the receiving end.
+/// Unlike the encoding macros, this works with any output buffer and reports the location of the caller on failure.
+/// `message` and `requested` are only used for error reporting.
+#[track_caller]
+pub fn encode_message_into<A, T, F>(
+    builder: &mut Builder<A>,
+    message: &'static str,
+    requested: usize,
+    strategy: PackStrategy,
+    out: &mut Vec<u8>,
+    f: F,
+) -> Result<(), EncodeMessageError>
+where
+    A: Allocator,
+    T: capnp::traits::Owned,
+    F: for<'a> FnOnce(T::Builder<'a>) -> capnp::Result<()>,
+{
+    let location = EncodeLocation::from(Location::caller());
+
+    let res = f(builder.init_root::<T::Builder<'_>>());
+
+    let segments = MessageSegments::new(builder);
+    let needed = segments.unpacked_size();
+
+    let make_err = |kind| EncodeMessageError {
+        kind,
+        message,
+        requested,
+        needed,
+        location,
+    };
+
+    res.map_err(|e| make_err(e.into()))?;
+
+    let mut tmp_len_buf = [0u8; 4];
+    let mut len_buf = ByteWriter::new(&mut tmp_len_buf);
+    len_buf
+        .write_varuint(needed as u64)
+        .map_err(|_| make_err(EncodeErrorKind::MessageTooLong))?;
+    out.extend_from_slice(len_buf.written());
+
+    let (strategy, size) = segments.plan(strategy);
+    let start = out.len();
+    out.resize(start + size, 0);
+    segments.write(strategy, &mut out[start..]);
+
+    Ok(())
+}
+
 /// Encodes a message into a buffer allocated by the qunet server, using the provided closure.
+/// The message variant is named like in `decode_message_match!`, `Variant(msg) => ...`, where `msg` is the root
+/// message builder on which the code initializes that variant. The name is only used for error reporting.
+/// The older `msg => ...` form is still accepted, errors then name the schema instead of the variant.
 /// You are required to pass in the estimated maximum message size in bytes, if it proves to be too small,
-/// a panic will occur and subsequently be caught and returned as an error.
+/// the allocator falls back to heap segments and a warning is logged.
 #[macro_export]
 macro_rules! encode_message_unsafe {
+    ($($schema:ident)::*, $srvr:expr, $estcap:expr, $variant:ident($msg:ident) => $code:expr) => {{
+        let mut builder = $crate::encoding::builder($crate::encoding::CapnpAlloc::<$estcap>::new());
+
+        $crate::encode_with_builder!($($schema)::*, $srvr, $estcap, $crate::encoding::PackStrategy::Auto, builder, stringify!($variant), $msg => $code)
+    }};
+
     ($($schema:ident)::*, $srvr:expr, $estcap:expr, $msg:ident => $code:expr) => {{
         let mut builder = $crate::encoding::builder($crate::encoding::CapnpAlloc::<$estcap>::new());
 
-        $crate::encode_with_builder!($($schema)::*, $srvr, $estcap, builder, $msg => $code)
+        $crate::encode_with_builder!($($schema)::*, $srvr, $estcap, $crate::encoding::PackStrategy::Auto, builder, concat!(stringify!($($schema)::*), "::message"), $msg => $code)
     }};
 }
 
 /// Like `encode_message_unsafe!`, but uses heap buffers from server's bufferpool.
 /// You are required to pass in the estimated maximum message size in bytes, if it proves to be too small,
-/// a panic will occur and subsequently be caught and returned as an error.
+/// the allocator falls back to heap segments and a warning is logged.
 #[macro_export]
 macro_rules! encode_message_heap {
+    ($($schema:ident)::*, $srvr:expr, $estcap:expr, $variant:ident($msg:ident) => $code:expr) => {{
+        $crate::encode_message_with!($($schema)::*, $srvr, $estcap, $crate::encoding::PackStrategy::Auto, $variant($msg) => $code)
+    }};
+
     ($($schema:ident)::*, $srvr:expr, $estcap:expr, $msg:ident => $code:expr) => {{
+        $crate::encode_message_with!($($schema)::*, $srvr, $estcap, $crate::encoding::PackStrategy::Auto, $msg => $code)
+    }};
+}
+
+/// Like `encode_message_heap!`, but lets you force a `PackStrategy` instead of picking the smaller output automatically.
+/// `PackStrategy::Literal` skips the packing pass for messages known to be made of incompressible data, such as voice frames.
+#[macro_export]
+macro_rules! encode_message_with {
+    ($($schema:ident)::*, $srvr:expr, $estcap:expr, $strategy:expr, $variant:ident($msg:ident) => $code:expr) => {{
+        $crate::encode_message_with!(@named stringify!($variant), $($schema)::*, $srvr, $estcap, $strategy, $msg => $code)
+    }};
+
+    ($($schema:ident)::*, $srvr:expr, $estcap:expr, $strategy:expr, $msg:ident => $code:expr) => {{
+        $crate::encode_message_with!(@named concat!(stringify!($($schema)::*), "::message"), $($schema)::*, $srvr, $estcap, $strategy, $msg => $code)
+    }};
+
+    (@named $name:expr, $($schema:ident)::*, $srvr:expr, $estcap:expr, $strategy:expr, $msg:ident => $code:expr) => {{
         let server = $srvr;
 
         // round up to a multiple of 8
@@ -219,7 +346,7 @@ macro_rules! encode_message_heap {
 
         let mut builder = $crate::encoding::builder_borrow(wnd);
 
-        $crate::encode_with_builder!($($schema)::*, server, estcap, builder, $msg => $code)
+        $crate::encode_with_builder!($($schema)::*, server, estcap, $strategy, builder, $name, $msg => $code)
     }};
 }
 
@@ -228,17 +355,36 @@ macro_rules! encode_message_heap {
 #[macro_export]
 #[cfg(feature = "dyn-encoding")]
 macro_rules! encode_message_dyn {
+    ($($schema:ident)::*, $srvr:expr, $variant:ident($msg:ident) => $code:expr) => {{
+        let mut builder = $crate::encoding::builder_dyn();
+
+        $crate::encode_with_builder!($($schema)::*, $srvr, usize::MAX, $crate::encoding::PackStrategy::Auto, builder, stringify!($variant), $msg => $code)
+    }};
+
     ($($schema:ident)::*, $srvr:expr, $msg:ident => $code:expr) => {{
         let mut builder = $crate::encoding::builder_dyn();
 
-        $crate::encode_with_builder!($($schema)::*, $srvr, usize::MAX, builder, $msg => $code)
+        $crate::encode_with_builder!($($schema)::*, $srvr, usize::MAX, $crate::encoding::PackStrategy::Auto, builder, concat!(stringify!($($schema)::*), "::message"), $msg => $code)
     }};
 }
 
 /// Resolves to either `encode_message_unsafe!` or `encode_message_heap!` depending on the size of the allocation.
-/// Size must be a constant expression.
+/// Size must be a constant expression. For sizes only known at runtime, compute them with the helpers in `encoding::size`.
+/// `encode_message!(schema::game, server, 64, LoginOk(msg) => msg.init_login_ok())`
+///
+/// When given an owned message from `crate::messages` instead of a size and a closure, the message is encoded
+/// with a buffer of its `capacity()`, so no size has to be estimated:
+/// `encode_message!(schema::game, server, msg)`
 #[macro_export]
 macro_rules! encode_message {
+    ($($schema:ident)::*, $srvr:expr, $estcap:expr, $variant:ident($msg:ident) => $code:expr) => {{
+        if $estcap <= 2048 {
+            $crate::encode_message_unsafe!($($schema)::*, $srvr, $estcap, $variant($msg) => $code)
+        } else {
+            $crate::encode_message_heap!($($schema)::*, $srvr, $estcap, $variant($msg) => $code)
+        }
+    }};
+
     ($($schema:ident)::*, $srvr:expr, $estcap:expr, $msg:ident => $code:expr) => {{
         if $estcap <= 2048 {
             $crate::encode_message_unsafe!($($schema)::*, $srvr, $estcap, $msg => $code)
@@ -246,6 +392,11 @@ macro_rules! encode_message {
             $crate::encode_message_heap!($($schema)::*, $srvr, $estcap, $msg => $code)
         }
     }};
+
+    ($($schema:ident)::*, $srvr:expr, $owned:expr) => {{
+        let owned = &$owned;
+        $crate::encode_message_with!(@named owned.name(), $($schema)::*, $srvr, owned.capacity(), $crate::encoding::PackStrategy::Auto, msg => owned.encode(msg))
+    }};
 }
 
 pub fn heapless_str_from_reader<'a, const N: usize>(
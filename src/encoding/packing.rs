@@ -0,0 +1,386 @@
+use capnp::message::{Allocator, Builder};
+use smallvec::SmallVec;
+
+/// How a message is laid out on the wire. All strategies produce valid packed capnp data,
+/// so the receiving side decodes them the same way.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PackStrategy {
+    /// Packs the message, unless packing would not make it smaller, in which case it is written like `Literal`
+    #[default]
+    Auto,
+    /// Regular capnp packing, zero bytes are elided. Best for most messages, as they mostly consist of small integers and padding.
+    Packed,
+    /// Words are copied as-is, with only the framing required by the packed format.
+    /// Cheaper to encode and avoids size overhead for data that does not pack well (e.g. voice frames or compressed events).
+    Literal,
+}
+
+/// Segments of a built message, along with its segment table, ready to be written out.
+pub struct MessageSegments<'a> {
+    table: SmallVec<[u8; 16]>,
+    segments: SmallVec<[&'a [u8]; 1]>,
+}
+
+impl<'a> MessageSegments<'a> {
+    pub fn new<A: Allocator>(builder: &'a Builder<A>) -> Self {
+        let segments: SmallVec<[&'a [u8]; 1]> =
+            builder.get_segments_for_output().iter().copied().collect();
+
+        // segment count - 1, then the size of each segment in words, padded to a whole word
+        let mut table = SmallVec::new();
+        table.extend_from_slice(&(segments.len() as u32 - 1).to_le_bytes());
+
+        for seg in &segments {
+            table.extend_from_slice(&((seg.len() / 8) as u32).to_le_bytes());
+        }
+
+        if table.len() % 8 != 0 {
+            table.extend_from_slice(&[0; 4]);
+        }
+
+        Self { table, segments }
+    }
+
+    fn parts(&self) -> impl Iterator<Item = &[u8]> {
+        std::iter::once(&self.table[..]).chain(self.segments.iter().copied())
+    }
+
+    /// Size of the unpacked message, including the segment table
+    pub fn unpacked_size(&self) -> usize {
+        self.parts().map(<[u8]>::len).sum()
+    }
+
+    /// Resolves `PackStrategy::Auto` to the strategy that produces the smaller output, preferring packing on a tie.
+    /// Returns the resolved strategy along with the exact size of the output when written with it.
+    pub fn plan(&self, strategy: PackStrategy) -> (PackStrategy, usize) {
+        let packed = || -> usize { self.parts().map(packed_size).sum() };
+        let literal = || -> usize { self.parts().map(literal_size).sum() };
+
+        match strategy {
+            PackStrategy::Auto => {
+                let (packed, literal) = (packed(), literal());
+
+                if packed <= literal {
+                    (PackStrategy::Packed, packed)
+                } else {
+                    (PackStrategy::Literal, literal)
+                }
+            }
+            PackStrategy::Packed => (PackStrategy::Packed, packed()),
+            PackStrategy::Literal => (PackStrategy::Literal, literal()),
+        }
+    }
+
+    /// Writes the message into `out` with a strategy resolved by `plan`, `out` must be exactly as long as the planned size
+    pub fn write(&self, strategy: PackStrategy, out: &mut [u8]) {
+        debug_assert_ne!(
+            strategy,
+            PackStrategy::Auto,
+            "the strategy must be resolved with `plan`"
+        );
+        let mut pos = 0;
+
+        for part in self.parts() {
+            pos += match strategy {
+                PackStrategy::Literal => write_literal_into(part, &mut out[pos..]),
+                _ => pack_into(part, &mut out[pos..]),
+            };
+        }
+
+        debug_assert_eq!(pos, out.len());
+    }
+}
+
+#[inline]
+fn word_at(words: &[u8], idx: usize) -> &[u8] {
+    &words[idx * 8..idx * 8 + 8]
+}
+
+#[inline]
+fn is_zero(word: &[u8]) -> bool {
+    word.iter().all(|&b| b == 0)
+}
+
+/// A word is worth copying literally if at most one of its bytes is zero, same as in the reference implementation
+#[inline]
+fn is_dense(word: &[u8]) -> bool {
+    word.iter().filter(|&&b| b == 0).count() <= 1
+}
+
+/// Computes the exact size of `words` after packing, without writing anything
+pub fn packed_size(words: &[u8]) -> usize {
+    debug_assert!(words.len() % 8 == 0);
+
+    let count = words.len() / 8;
+    let mut size = 0;
+    let mut i = 0;
+
+    while i < count {
+        let word = word_at(words, i);
+        let nonzero = word.iter().filter(|&&b| b != 0).count();
+
+        // tag + nonzero bytes
+        size += 1 + nonzero;
+        i += 1;
+
+        if nonzero == 0 {
+            // run of zero words
+            let run = (i..count)
+                .take(255)
+                .take_while(|&j| is_zero(word_at(words, j)))
+                .count();
+
+            size += 1;
+            i += run;
+        } else if nonzero == 8 {
+            // run of literal words
+            let run = (i..count)
+                .take(255)
+                .take_while(|&j| is_dense(word_at(words, j)))
+                .count();
+
+            size += 1 + run * 8;
+            i += run;
+        }
+    }
+
+    size
+}
+
+/// Packs `words` into `out`, returning the amount of bytes written. `out` must be at least `packed_size(words)` bytes long.
+pub fn pack_into(words: &[u8], out: &mut [u8]) -> usize {
+    debug_assert!(words.len() % 8 == 0);
+
+    let count = words.len() / 8;
+    let mut pos = 0;
+    let mut i = 0;
+
+    while i < count {
+        let word = word_at(words, i);
+
+        let tag_pos = pos;
+        pos += 1;
+
+        let mut tag = 0u8;
+        for (bit, &b) in word.iter().enumerate() {
+            if b != 0 {
+                tag |= 1 << bit;
+                out[pos] = b;
+                pos += 1;
+            }
+        }
+
+        out[tag_pos] = tag;
+        i += 1;
+
+        if tag == 0 {
+            let run = (i..count)
+                .take(255)
+                .take_while(|&j| is_zero(word_at(words, j)))
+                .count();
+
+            out[pos] = run as u8;
+            pos += 1;
+            i += run;
+        } else if tag == 0xff {
+            let run = (i..count)
+                .take(255)
+                .take_while(|&j| is_dense(word_at(words, j)))
+                .count();
+
+            out[pos] = run as u8;
+            pos += 1;
+
+            let len = run * 8;
+            out[pos..pos + len].copy_from_slice(&words[i * 8..i * 8 + len]);
+            pos += len;
+            i += run;
+        }
+    }
+
+    pos
+}
+
+/// Computes the exact size of `words` when written with `write_literal_into`
+pub fn literal_size(words: &[u8]) -> usize {
+    // every chunk of up to 256 words gets a tag and a count byte
+    words.len() + (words.len() / 8).div_ceil(256) * 2
+}
+
+/// Writes `words` as a sequence of literal runs, returning the amount of bytes written.
+/// `out` must be at least `literal_size(words)` bytes long.
+pub fn write_literal_into(words: &[u8], out: &mut [u8]) -> usize {
+    debug_assert!(words.len() % 8 == 0);
+
+    let mut pos = 0;
+
+    for chunk in words.chunks(256 * 8) {
+        // a 0xff tag with the first word, followed by the count and the remaining words of the run
+        out[pos] = 0xff;
+        out[pos + 1..pos + 9].copy_from_slice(&chunk[..8]);
+        out[pos + 9] = (chunk.len() / 8 - 1) as u8;
+        out[pos + 10..pos + 2 + chunk.len()].copy_from_slice(&chunk[8..]);
+
+        pos += 2 + chunk.len();
+    }
+
+    pos
+}
+
+#[cfg(test)]
+mod tests {
+    use capnp::{
+        any_pointer, data_list,
+        message::{Builder, ReaderOptions},
+        serialize, serialize_packed,
+    };
+
+    use super::*;
+    use crate::encoding::CapnpAlloc;
+
+    fn xorshift(len: usize) -> Vec<u8> {
+        let mut state = 0x2545f4914f6cdd1du64;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect()
+    }
+
+    /// Builds a message whose root is a list of data blobs, `N` being the size of the primary buffer.
+    /// Blobs that don't fit in the primary buffer end up in fallback segments.
+    fn build<const N: usize>(blobs: &[Vec<u8>]) -> Builder<CapnpAlloc<N>> {
+        let mut builder = Builder::new(CapnpAlloc::<N>::new());
+
+        let mut list: data_list::Builder<'_> = builder
+            .init_root::<any_pointer::Builder<'_>>()
+            .initn_as(blobs.len() as u32);
+
+        for (i, blob) in blobs.iter().enumerate() {
+            list.reborrow()
+                .init(i as u32, blob.len() as u32)
+                .copy_from_slice(blob);
+        }
+
+        builder
+    }
+
+    /// Checks the packer against the reference implementation, and that every strategy unpacks back to the same message
+    fn check<A: Allocator>(builder: &Builder<A>) {
+        let mut reference_packed = Vec::new();
+        serialize_packed::write_message(&mut reference_packed, builder).unwrap();
+
+        let mut unpacked = Vec::new();
+        serialize::write_message(&mut unpacked, builder).unwrap();
+
+        let segments = MessageSegments::new(builder);
+        assert_eq!(segments.unpacked_size(), unpacked.len());
+
+        for strategy in [
+            PackStrategy::Packed,
+            PackStrategy::Literal,
+            PackStrategy::Auto,
+        ] {
+            let (strategy, size) = segments.plan(strategy);
+            let mut out = vec![0u8; size];
+            segments.write(strategy, &mut out);
+
+            match strategy {
+                PackStrategy::Packed => assert_eq!(out, reference_packed),
+                _ => assert_eq!(out.len(), literal_size_of(&segments)),
+            }
+
+            let mut buf = vec![0u8; unpacked.len()];
+            let mut input = &out[..];
+            let reader =
+                serialize_packed::read_message_no_alloc(&mut input, &mut buf, ReaderOptions::new())
+                    .unwrap();
+            drop(reader);
+
+            assert!(input.is_empty(), "{strategy:?}");
+            assert_eq!(buf, unpacked, "{strategy:?}");
+        }
+    }
+
+    fn literal_size_of(segments: &MessageSegments<'_>) -> usize {
+        segments.parts().map(literal_size).sum()
+    }
+
+    #[test]
+    fn zero_runs() {
+        // runs longer than 255 words are split
+        check(&build::<8192>(&[
+            vec![0; 8],
+            vec![0; 255 * 8],
+            vec![0; 600 * 8],
+        ]));
+    }
+
+    #[test]
+    fn dense_runs() {
+        let mut one_zero_per_word = vec![0xab; 300 * 8];
+        for word in one_zero_per_word.chunks_mut(8) {
+            word[3] = 0;
+        }
+
+        check(&build::<16384>(&[
+            vec![0xff; 8],
+            vec![0xff; 255 * 8],
+            vec![0xff; 600 * 8],
+            one_zero_per_word,
+        ]));
+    }
+
+    #[test]
+    fn mixed_words() {
+        let mut sparse = vec![0; 64 * 8];
+        for (i, b) in sparse.iter_mut().enumerate().step_by(5) {
+            *b = i as u8 | 1;
+        }
+
+        let mut alternating = Vec::new();
+        for i in 0..100 {
+            alternating.extend_from_slice(&[if i % 2 == 0 { 0xff } else { 0 }; 8]);
+        }
+
+        // odd sizes leave zero padding at the end of the last word
+        check(&build::<8192>(&[
+            sparse,
+            alternating,
+            xorshift(999),
+            vec![1; 3],
+        ]));
+    }
+
+    #[test]
+    fn segment_boundaries() {
+        // the primary buffer only fits the list, so every blob lands in its own fallback segment
+        let builder = build::<32>(&[vec![0xff; 300 * 8], vec![0; 300 * 8], xorshift(2048)]);
+        assert!(builder.get_segments_for_output().len() > 1);
+
+        check(&builder);
+    }
+
+    #[test]
+    fn auto_picks_smaller() {
+        let sparse = build::<8192>(&[vec![0; 1024]]);
+        let segments = MessageSegments::new(&sparse);
+        assert_eq!(segments.plan(PackStrategy::Auto).0, PackStrategy::Packed);
+
+        // every dense word ends its run with a count byte, and the next word still costs 7 bytes packed
+        let mut dense = Vec::new();
+        for _ in 0..256 {
+            dense.extend_from_slice(&[0xff; 8]);
+            dense.extend_from_slice(&[0x11, 0, 0x22, 0x33, 0, 0x44, 0x55, 0x66]);
+        }
+
+        let dense = build::<8192>(&[dense]);
+        let segments = MessageSegments::new(&dense);
+        let (strategy, size) = segments.plan(PackStrategy::Auto);
+        assert_eq!(strategy, PackStrategy::Literal);
+        assert!(size < segments.plan(PackStrategy::Packed).1);
+    }
+}
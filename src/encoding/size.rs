@@ -0,0 +1,80 @@
+// Helpers for computing upper bounds of encoded message sizes.
+// All sizes are in bytes and refer to the unpacked size inside a capnp arena, which is always a multiple of a word.
+
+use capnp::traits::HasStructSize;
+
+pub const WORD_SIZE: usize = 8;
+
+const fn round_to_words(bytes: usize) -> usize {
+    bytes.div_ceil(WORD_SIZE) * WORD_SIZE
+}
+
+/// Size of the inline part of a struct (data and pointer sections). Pass the builder type, e.g. `shared::user_role::Builder<'static>`
+pub fn struct_size<T: HasStructSize>() -> usize {
+    let size = T::STRUCT_SIZE;
+    (size.data as usize + size.pointers as usize) * WORD_SIZE
+}
+
+/// Size of a text field, including the NUL terminator
+pub const fn text_size(len: usize) -> usize {
+    round_to_words(len + 1)
+}
+
+pub const fn data_size(len: usize) -> usize {
+    round_to_words(len)
+}
+
+/// Size of a list of primitives (integers, floats or enums), `T` being the element type
+pub const fn list_size<T>(count: usize) -> usize {
+    round_to_words(count * size_of::<T>())
+}
+
+pub const fn bool_list_size(count: usize) -> usize {
+    round_to_words(count.div_ceil(8))
+}
+
+/// Size of a list of pointers (text, data or nested lists), not including the pointed-to data
+pub const fn pointer_list_size(count: usize) -> usize {
+    count * WORD_SIZE
+}
+
+/// Size of a list of structs, given the sum of the encoded sizes of all the elements
+pub const fn composite_list_size(elements: usize) -> usize {
+    WORD_SIZE + elements
+}
+
+/// Arena capacity needed for a message with a root struct of the given encoded size
+pub const fn message_capacity(root: usize) -> usize {
+    WORD_SIZE + root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_includes_terminator() {
+        assert_eq!(text_size(0), 8);
+        assert_eq!(text_size(7), 8);
+        assert_eq!(text_size(8), 16);
+    }
+
+    #[test]
+    fn data_rounds_to_words() {
+        assert_eq!(data_size(0), 0);
+        assert_eq!(data_size(8), 8);
+        assert_eq!(data_size(9), 16);
+    }
+
+    #[test]
+    fn lists_round_to_words() {
+        assert_eq!(list_size::<i32>(2), 8);
+        assert_eq!(list_size::<i32>(3), 16);
+        assert_eq!(list_size::<u64>(3), 24);
+        assert_eq!(bool_list_size(64), 8);
+        assert_eq!(bool_list_size(65), 16);
+        assert_eq!(pointer_list_size(3), 24);
+        assert_eq!(composite_list_size(0), 8);
+        assert_eq!(composite_list_size(32), 40);
+    }
+}
@@ -1,60 +1,186 @@
 use super::*;
 
-pub(super) const CENTRAL_BUILTINS_MAX: u32 = 1;
-pub(super) const GAME_BUILTINS_MAX: u32 = 1;
+/// Prefix used for placeholder events, that stand in for builtins from newer versions unknown to this server
+pub(super) const PLACEHOLDER_PREFIX: &str = "globed/?placeholder-";
 
-pub(super) fn build_central_builtins(
+/// A builtin event, along with whether clients are meant to send it.
+/// Any other `globed/` event may only originate from the server.
+pub(super) struct Builtin {
+    pub id: &'static str,
+    pub client_sendable: bool,
+}
+
+const fn client(id: &'static str) -> Builtin {
+    Builtin {
+        id,
+        client_sendable: true,
+    }
+}
+
+const fn server(id: &'static str) -> Builtin {
+    Builtin {
+        id,
+        client_sendable: false,
+    }
+}
+
+/// Builtin events of the central server, every entry is a version and lists the events added in that version.
+/// New events must only ever be appended as a new version, never inserted into an existing one.
+const CENTRAL_BUILTINS: &[&[Builtin]] = &[
+    // v1
+    &[client("globed/test")],
+];
+
+/// Builtin events of the game server, see `CENTRAL_BUILTINS`
+const GAME_BUILTINS: &[&[Builtin]] = &[
+    // v1
+    &[
+        client("globed/counter-change"),
+        server("globed/display-data-refreshed"),
+        client("globed/scripting.custom"),
+        client("globed/scripting.spawn-group"),
+        client("globed/scripting.set-item"),
+        client("globed/scripting.request-script-logs"),
+        client("globed/scripting.move-group"),
+        client("globed/scripting.follow-player"),
+        client("globed/scripting.follow-rotation"),
+        client("globed/scripting.follow-absolute"),
+        client("globed/2p.link"),
+        client("globed/2p.unlink"),
+        client("globed/switcheroo.full-state"),
+        client("globed/switcheroo.switch"),
+    ],
+];
+
+/// All builtins of every version that clients are meant to send
+pub(super) fn client_builtins() -> impl Iterator<Item = &'static str> {
+    CENTRAL_BUILTINS
+        .iter()
+        .chain(GAME_BUILTINS)
+        .copied()
+        .flatten()
+        .filter(|b| b.client_sendable)
+        .map(|b| b.id)
+}
+
+/// Latest builtins version supported by the central server
+pub const CENTRAL_BUILTINS_MAX: u32 = CENTRAL_BUILTINS.len() as u32;
+/// Latest builtins version supported by the game server
+pub const GAME_BUILTINS_MAX: u32 = GAME_BUILTINS.len() as u32;
+
+/// Pushes builtin events up to the given version (or the latest supported one, whichever is lower),
+/// returning the version that was actually used.
+fn build_builtins(
+    versions: &[&[Builtin]],
     version: u32,
     out: &mut Vec<Arc<str>>,
     cache: &EventStringCache,
-) -> Result<(), EventDictionaryBuildError> {
-    if version > CENTRAL_BUILTINS_MAX {
-        return Err(EventDictionaryBuildError::UnsupportedBuiltinsVersion(
-            version,
-        ));
-    }
+) -> u32 {
+    let version = version.min(versions.len() as u32);
 
-    let mut push = |s: &str| out.push(cache.get(s));
-
-    if version >= 1 {
-        push("globed/test");
+    for events in &versions[..version as usize] {
+        out.extend(events.iter().map(|b| cache.get(b.id)));
     }
 
-    Ok(())
+    version
+}
+
+pub(super) fn build_central_builtins(
+    version: u32,
+    out: &mut Vec<Arc<str>>,
+    cache: &EventStringCache,
+) -> u32 {
+    build_builtins(CENTRAL_BUILTINS, version, out, cache)
 }
 
 pub(super) fn build_game_builtins(
     version: u32,
     out: &mut Vec<Arc<str>>,
     cache: &EventStringCache,
-) -> Result<(), EventDictionaryBuildError> {
-    if version > GAME_BUILTINS_MAX {
-        return Err(EventDictionaryBuildError::UnsupportedBuiltinsVersion(
-            version,
-        ));
+) -> u32 {
+    build_builtins(GAME_BUILTINS, version, out, cache)
+}
+
+/// Pushes placeholders for `count` builtins unknown to this server, starting at index `start`
+pub(super) fn push_placeholders(
+    start: usize,
+    count: usize,
+    out: &mut Vec<Arc<str>>,
+    cache: &EventStringCache,
+) {
+    for i in start..start + count {
+        out.push(cache.get(&format!("{PLACEHOLDER_PREFIX}{i}")));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VERSIONS: &[&[Builtin]] = &[
+        &[client("globed/a"), client("globed/b")],
+        &[server("globed/c")],
+        &[client("globed/d"), client("globed/e")],
+    ];
+
+    fn ids(events: &[Arc<str>]) -> Vec<&str> {
+        events.iter().map(|e| &**e).collect()
     }
 
-    let mut push = |s: &str| out.push(cache.get(s));
+    #[test]
+    fn older_peer_gets_its_version() {
+        let cache = EventStringCache::new();
+        let mut out = Vec::new();
 
-    if version >= 1 {
-        push("globed/counter-change");
-        push("globed/display-data-refreshed");
+        assert_eq!(build_builtins(VERSIONS, 2, &mut out, &cache), 2);
+        assert_eq!(ids(&out), ["globed/a", "globed/b", "globed/c"]);
 
-        push("globed/scripting.custom");
-        push("globed/scripting.spawn-group");
-        push("globed/scripting.set-item");
-        push("globed/scripting.request-script-logs");
-        push("globed/scripting.move-group");
-        push("globed/scripting.follow-player");
-        push("globed/scripting.follow-rotation");
-        push("globed/scripting.follow-absolute");
+        out.clear();
+        assert_eq!(build_builtins(VERSIONS, 0, &mut out, &cache), 0);
+        assert!(out.is_empty());
+    }
 
-        push("globed/2p.link");
-        push("globed/2p.unlink");
+    #[test]
+    fn newer_peer_is_downgraded() {
+        let cache = EventStringCache::new();
+        let mut out = Vec::new();
 
-        push("globed/switcheroo.full-state");
-        push("globed/switcheroo.switch");
+        assert_eq!(build_builtins(VERSIONS, 5, &mut out, &cache), 3);
+        assert_eq!(out.len(), 5);
     }
 
-    Ok(())
+    #[test]
+    fn unknown_builtins_become_placeholders() {
+        let cache = EventStringCache::new();
+        let mut out = Vec::new();
+
+        // the peer knows all three versions, we only know the first one
+        let known = build_builtins(&VERSIONS[..1], 3, &mut out, &cache);
+        assert_eq!(known, 1);
+
+        let start = out.len();
+        push_placeholders(start, 3, &mut out, &cache);
+        out.push(cache.get("some.mod/event"));
+
+        assert_eq!(
+            ids(&out),
+            [
+                "globed/a",
+                "globed/b",
+                "globed/?placeholder-2",
+                "globed/?placeholder-3",
+                "globed/?placeholder-4",
+                "some.mod/event",
+            ]
+        );
+
+        // mod events keep the ids the peer assigned to them
+        let peer_builtins: usize = VERSIONS.iter().map(|v| v.len()).sum();
+        assert_eq!(&*out[peer_builtins], "some.mod/event");
+        assert!(
+            out[start..peer_builtins]
+                .iter()
+                .all(|e| EventEncoder::is_placeholder(e))
+        );
+    }
 }
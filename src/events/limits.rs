@@ -0,0 +1,29 @@
+const MAX_EVENT_LENGTH: usize = 1024;
+const MAX_EVENT_COUNT: usize = 128;
+const MAX_DICTIONARY_SIZE: u32 = 1024;
+/// Upper bound for the size of a compressed event after decompression
+const MAX_DECOMPRESSED_EVENT_LENGTH: usize = 16384;
+
+/// Limits enforced by an `EventEncoder` when building the dictionary and decoding events.
+#[derive(Clone, Debug)]
+pub struct EventLimits {
+    /// Maximum size of the data of a single event, as sent on the wire
+    pub max_event_length: usize,
+    /// Maximum size of the data of a single compressed event, after decompression
+    pub max_decompressed_length: usize,
+    /// Maximum amount of events in a single batch
+    pub max_event_count: usize,
+    /// Maximum amount of events in the dictionary, including builtins
+    pub max_dictionary_size: u32,
+}
+
+impl Default for EventLimits {
+    fn default() -> Self {
+        Self {
+            max_event_length: MAX_EVENT_LENGTH,
+            max_decompressed_length: MAX_DECOMPRESSED_EVENT_LENGTH,
+            max_event_count: MAX_EVENT_COUNT,
+            max_dictionary_size: MAX_DICTIONARY_SIZE,
+        }
+    }
+}
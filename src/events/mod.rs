@@ -9,20 +9,28 @@ use std::{
 };
 
 use arc_swap::ArcSwap;
-use bitpiece::{B1, bitpiece};
+use bitpiece::{B1, B7, bitpiece};
 use heapless::CapacityError;
 use qunet::buffers::{BinaryWriter, ByteReader, ByteReaderError, HeapByteWriter};
 use thiserror::Error;
 
 mod builtins;
+mod limits;
+mod permissions;
 mod rate_limiter;
+mod recorder;
 use builtins::*;
-use tracing::trace;
+use tracing::{debug, trace};
 
+pub use builtins::{CENTRAL_BUILTINS_MAX, GAME_BUILTINS_MAX};
+
+pub use limits::*;
+pub use permissions::*;
 pub use rate_limiter::*;
+pub use recorder::*;
 
-const MAX_EVENT_LENGTH: usize = 1024;
-const MAX_EVENT_COUNT: usize = 128;
+/// Events with less data than this are never compressed, as it's unlikely to help
+const MIN_COMPRESS_LENGTH: usize = 128;
 
 #[bitpiece(8)]
 #[derive(Default)]
@@ -39,6 +47,15 @@ pub struct EventFlags {
     pub more_flags: bool,
 }
 
+/// Additional flags, only present on the wire if `EventFlags::more_flags` is set
+#[bitpiece(8)]
+#[derive(Default)]
+pub struct ExtEventFlags {
+    pub compressed: bool,
+
+    pub padding: B7,
+}
+
 #[derive(Default, Clone)]
 pub struct EventOptions {
     pub reliable: bool,
@@ -46,6 +63,8 @@ pub struct EventOptions {
     pub send_back: bool,
     pub target_players: Vec<i32>,
     pub sent_by_player: Option<NonZero<i32>>,
+    /// Whether to compress the event data. This is only a hint, compression is skipped if it doesn't reduce the size.
+    pub compress: bool,
 }
 
 #[derive(Default)]
@@ -57,10 +76,8 @@ pub struct EventStringCache {
 pub enum EventDictionaryBuildError {
     #[error("Failed to decode event dictionary: {0}")]
     Decode(#[from] ByteReaderError),
-    #[error("Too many events ({0})")]
-    TooManyEvents(u32),
-    #[error("Unsupported builtins version ({0})")]
-    UnsupportedBuiltinsVersion(u32),
+    #[error("Too many events ({0}, limit: {1})")]
+    TooManyEvents(u32, u32),
     #[error("Total event count at the end does not match the count in the dictionary")]
     EventCountMismatch,
     #[error("A string was too long")]
@@ -79,6 +96,10 @@ pub enum EventEncodingError {
     UnknownEvent,
     #[error("Failed to write event data: {0}")]
     WriteError(#[from] std::io::Error),
+    #[error("Data too long in event ({0} bytes, limit: {1})")]
+    DataTooLong(usize, usize),
+    #[error("Too many events sent ({0}, limit: {1})")]
+    TooManyEvents(usize, usize),
     #[error("{0}")]
     Custom(String),
 }
@@ -89,10 +110,16 @@ pub enum EventDecodingError {
     Decode(#[from] ByteReaderError),
     #[error("Unknown event: {0}")]
     UnknownEvent(u32),
-    #[error("Data too long in event ({0} bytes)")]
-    DataTooLong(usize),
-    #[error("Too many events sent ({0})")]
-    TooManyEvents(usize),
+    #[error("Data too long in event ({0} bytes, limit: {1})")]
+    DataTooLong(usize, usize),
+    #[error("Too many events sent ({0}, limit: {1})")]
+    TooManyEvents(usize, usize),
+    #[error("Decompressed data too long in event ({0} bytes, limit: {1})")]
+    DecompressedTooLong(usize, usize),
+    #[error("Failed to decompress event data: {0}")]
+    Decompress(#[from] lz4_flex::block::DecompressError),
+    #[error("Decompressed size mismatch (expected {0}, got {1})")]
+    DecompressedSizeMismatch(usize, usize),
 }
 
 pub struct RawDecodedEvent<'a> {
@@ -108,9 +135,13 @@ pub struct OwnedEvent {
     pub options: EventOptions,
 }
 
+#[derive(Clone)]
 pub struct EventEncoder {
     mapping: Vec<Arc<str>>,
     inv_mapping: HashMap<Arc<str>, u32>,
+    limits: EventLimits,
+    builtins_version: u32,
+    downgraded: bool,
 }
 
 impl EventEncoder {
@@ -118,22 +149,29 @@ impl EventEncoder {
         data: &[u8],
         cache: &EventStringCache,
         game: bool,
+        limits: EventLimits,
     ) -> Result<Self, EventDictionaryBuildError> {
         let mut reader = ByteReader::new(data);
 
         let builtins_version = reader.read_u32()?;
         let total_events = reader.read_u32()?;
-        if total_events > 1024 {
-            return Err(EventDictionaryBuildError::TooManyEvents(total_events));
+        if total_events > limits.max_dictionary_size {
+            return Err(EventDictionaryBuildError::TooManyEvents(
+                total_events,
+                limits.max_dictionary_size,
+            ));
         }
 
         let mut mapping = Vec::with_capacity(total_events as usize);
 
-        if game {
-            build_game_builtins(builtins_version, &mut mapping, cache)?;
+        let negotiated_version = if game {
+            build_game_builtins(builtins_version, &mut mapping, cache)
         } else {
-            build_central_builtins(builtins_version, &mut mapping, cache)?;
-        }
+            build_central_builtins(builtins_version, &mut mapping, cache)
+        };
+
+        let known_builtins = mapping.len();
+        let mut mod_events = Vec::new();
 
         while reader.remaining() > 0 {
             let mod_id = heapless::String::<256>::try_from(reader.read_string_u8()?)?;
@@ -148,14 +186,41 @@ impl EventEncoder {
                 full_id.push('/')?;
                 full_id.push_str(event_id)?;
 
-                mapping.push(cache.get(&full_id));
+                if known_builtins + mod_events.len() >= total_events as usize {
+                    return Err(EventDictionaryBuildError::EventCountMismatch);
+                }
+
+                mod_events.push(cache.get(&full_id));
             }
         }
 
-        if mapping.len() != total_events as usize {
+        // whatever is left over are builtins from a newer version that we don't know about
+        let unknown_builtins = (total_events as usize)
+            .checked_sub(known_builtins + mod_events.len())
+            .ok_or(EventDictionaryBuildError::EventCountMismatch)?;
+        let downgraded = negotiated_version < builtins_version;
+
+        if unknown_builtins > 0 && !downgraded {
             return Err(EventDictionaryBuildError::EventCountMismatch);
         }
 
+        if downgraded {
+            debug!(
+                "client builtins version {builtins_version} is newer than ours, downgrading to {negotiated_version} ({unknown_builtins} placeholders)"
+            );
+        }
+
+        push_placeholders(known_builtins, unknown_builtins, &mut mapping, cache);
+        mapping.extend(mod_events);
+
+        let mut this = Self::from_mapping(mapping, limits);
+        this.builtins_version = negotiated_version;
+        this.downgraded = downgraded;
+
+        Ok(this)
+    }
+
+    fn from_mapping(mapping: Vec<Arc<str>>, limits: EventLimits) -> Self {
         // build inverse mapping for speed
         let inv_mapping = mapping
             .iter()
@@ -163,10 +228,38 @@ impl EventEncoder {
             .map(|(i, s)| (s.clone(), i as u32))
             .collect();
 
-        Ok(Self {
+        Self {
             mapping,
             inv_mapping,
-        })
+            limits,
+            builtins_version: 0,
+            downgraded: false,
+        }
+    }
+
+    /// Returns the builtins version in use, which may be lower than the one requested by the client
+    pub fn builtins_version(&self) -> u32 {
+        self.builtins_version
+    }
+
+    /// Returns whether the client has a newer builtins version than this server supports.
+    /// In this case, builtins unknown to the server are mapped to placeholder events, see `is_placeholder`.
+    pub fn is_downgraded(&self) -> bool {
+        self.downgraded
+    }
+
+    /// Returns whether the event is a placeholder for a builtin event unknown to this server
+    pub fn is_placeholder(event: &str) -> bool {
+        event.starts_with(PLACEHOLDER_PREFIX)
+    }
+
+    pub fn limits(&self) -> &EventLimits {
+        &self.limits
+    }
+
+    /// Returns all events known by this encoder, in the order of their numeric IDs
+    pub fn events(&self) -> &[Arc<str>] {
+        &self.mapping
     }
 
     fn lookup(&self, event_id: u32) -> Option<&Arc<str>> {
@@ -190,6 +283,25 @@ impl EventEncoder {
     ) -> Result<(), EventEncodingError> {
         let mut writer = BinaryWriter::new(writer);
         let event_id = self.lookup_id(id).ok_or(EventEncodingError::UnknownEvent)?;
+
+        let compressed = if options.compress
+            && data.len() >= MIN_COMPRESS_LENGTH
+            && data.len() <= self.limits.max_decompressed_length
+        {
+            let compressed = lz4_flex::block::compress(data);
+            (compressed.len() < data.len()).then_some(compressed)
+        } else {
+            None
+        };
+
+        let wire_len = compressed.as_ref().map_or(data.len(), |c| c.len());
+        if wire_len > self.limits.max_event_length {
+            return Err(EventEncodingError::DataTooLong(
+                wire_len,
+                self.limits.max_event_length,
+            ));
+        }
+
         let total = self.mapping.len();
 
         if total < 256 {
@@ -217,7 +329,17 @@ impl EventEncoder {
             flags.set_sent_by_player(true);
         }
 
+        let mut ext_flags = ExtEventFlags::default();
+        if compressed.is_some() {
+            ext_flags.set_compressed(true);
+            flags.set_more_flags(true);
+        }
+
         writer.write_bits(flags)?;
+        if flags.more_flags() {
+            writer.write_bits(ext_flags)?;
+        }
+
         if !options.target_players.is_empty() {
             writer.write_varuint(options.target_players.len() as u64)?;
             for player in &options.target_players {
@@ -229,7 +351,11 @@ impl EventEncoder {
             writer.write_i32(player_id.get())?;
         }
 
-        if !data.is_empty() {
+        if let Some(compressed) = compressed {
+            writer.write_varuint(compressed.len() as u64)?;
+            writer.write_varuint(data.len() as u64)?;
+            writer.write_bytes(&compressed)?;
+        } else if !data.is_empty() {
             writer.write_varuint(data.len() as u64)?;
             writer.write_bytes(data)?;
         }
@@ -242,6 +368,13 @@ impl EventEncoder {
         events: &[OwnedEvent],
         writer: &mut impl Write,
     ) -> Result<(), EventEncodingError> {
+        if events.len() > self.limits.max_event_count {
+            return Err(EventEncodingError::TooManyEvents(
+                events.len(),
+                self.limits.max_event_count,
+            ));
+        }
+
         let mut writer = BinaryWriter::new(writer);
         writer.write_varuint(events.len() as u64)?;
 
@@ -252,10 +385,13 @@ impl EventEncoder {
         Ok(())
     }
 
-    pub fn decode_event<'b>(
+    /// Decodes a single event from the reader. If the event data is compressed,
+    /// it is decompressed into `scratch` and the returned event borrows from it.
+    pub fn decode_event<'a, 'b: 'a>(
         &self,
         reader: &mut ByteReader<'b>,
-    ) -> Result<RawDecodedEvent<'b>, EventDecodingError> {
+        scratch: &'a mut Vec<u8>,
+    ) -> Result<RawDecodedEvent<'a>, EventDecodingError> {
         let event_id = if self.mapping.len() < 256 {
             reader.read_u8()? as u32
         } else if self.mapping.len() < 65536 {
@@ -270,6 +406,11 @@ impl EventEncoder {
             .clone();
 
         let flags: EventFlags = reader.read_bits()?;
+        let ext_flags: ExtEventFlags = if flags.more_flags() {
+            reader.read_bits()?
+        } else {
+            ExtEventFlags::default()
+        };
 
         let mut target_players = Vec::new();
         if flags.target_players() {
@@ -285,10 +426,45 @@ impl EventEncoder {
             None
         };
 
-        let data = if !flags.no_data() {
+        let data = if ext_flags.compressed() {
+            let len = reader.read_varuint()? as usize;
+            if len > self.limits.max_event_length {
+                return Err(EventDecodingError::DataTooLong(
+                    len,
+                    self.limits.max_event_length,
+                ));
+            }
+
+            let decompressed_len = reader.read_varuint()? as usize;
+            if decompressed_len > self.limits.max_decompressed_length {
+                return Err(EventDecodingError::DecompressedTooLong(
+                    decompressed_len,
+                    self.limits.max_decompressed_length,
+                ));
+            }
+
+            let rem = reader.remaining_bytes();
+            reader.skip_bytes(len)?;
+
+            scratch.clear();
+            scratch.resize(decompressed_len, 0);
+
+            let written = lz4_flex::block::decompress_into(&rem[..len], scratch)?;
+            if written != decompressed_len {
+                return Err(EventDecodingError::DecompressedSizeMismatch(
+                    decompressed_len,
+                    written,
+                ));
+            }
+
+            &scratch[..]
+        } else if !flags.no_data() {
             let len = reader.read_varuint()? as usize;
-            if len > MAX_EVENT_LENGTH {
-                return Err(EventDecodingError::DataTooLong(len));
+            if len > self.limits.max_event_length {
+                return Err(EventDecodingError::DataTooLong(
+                    len,
+                    self.limits.max_event_length,
+                ));
             }
 
             // grab the remainder (which is data bytes + rest of data), then after skipping we know we have 'len' bytes left
@@ -308,6 +484,7 @@ impl EventEncoder {
                 send_back: flags.send_back(),
                 sent_by_player,
                 target_players,
+                compress: ext_flags.compressed(),
             },
         })
     }
@@ -322,20 +499,39 @@ impl EventEncoder {
         let mut reader = ByteReader::new(data);
         let mut events = Vec::new();
 
-        let count = reader.read_varuint()?;
-        if count as usize > MAX_EVENT_COUNT {
-            return Err(EventDecodingError::TooManyEvents(count as usize));
+        let count = reader.read_varuint()? as usize;
+        if count > self.limits.max_event_count {
+            return Err(EventDecodingError::TooManyEvents(
+                count,
+                self.limits.max_event_count,
+            ));
         }
 
-        events.reserve(count as usize);
+        events.reserve(count);
+
+        let mut scratch = Vec::new();
 
         for _ in 0..count {
-            let event = self.decode_event(&mut reader)?;
+            let event = self.decode_event(&mut reader, &mut scratch)?;
             events.push(event.into());
         }
 
         Ok(events)
     }
+
+    /// Like `decode_events_owned`, but drops events the sender is not permitted to send, according to `perms`.
+    /// Returns the permitted events and a rejection for every dropped event.
+    pub fn decode_events_checked(
+        &self,
+        data: &[u8],
+        perms: &EventPermissions,
+        roles: &[u8],
+    ) -> Result<(Vec<OwnedEvent>, Vec<EventRejection>), EventDecodingError> {
+        let mut events = self.decode_events_owned(data)?;
+        let rejections = perms.filter(&mut events, roles);
+
+        Ok((events, rejections))
+    }
 }
 
 impl EventStringCache {
@@ -345,8 +541,8 @@ impl EventStringCache {
 
         // fill the cache with built-in events immediately
         let mut vec = Vec::new();
-        build_central_builtins(CENTRAL_BUILTINS_MAX, &mut vec, &this).unwrap();
-        build_game_builtins(GAME_BUILTINS_MAX, &mut vec, &this).unwrap();
+        build_central_builtins(CENTRAL_BUILTINS_MAX, &mut vec, &this);
+        build_game_builtins(GAME_BUILTINS_MAX, &mut vec, &this);
 
         // disregard value of the vector, since the functions already call .get and will pre-fill the cache
 
@@ -413,6 +609,12 @@ impl OwnedEvent {
             count += 4;
         }
 
+        // compressed data is only sent if it's smaller than the original,
+        // but it needs an extra flags byte and an extra length prefix
+        if self.options.compress {
+            count += 1 + 4;
+        }
+
         count += self.data.len();
 
         count
@@ -426,3 +628,151 @@ pub trait EventEncode {
 
     fn encode(&self, writer: &mut HeapByteWriter);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EVENT: &str = "test/event";
+
+    fn encoder() -> EventEncoder {
+        EventEncoder::from_mapping(vec![Arc::from(EVENT)], EventLimits::default())
+    }
+
+    fn compressible(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 7) as u8).collect()
+    }
+
+    fn incompressible(len: usize) -> Vec<u8> {
+        let mut state = 0x2545f4914f6cdd1du64;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect()
+    }
+
+    fn encode(data: &[u8], compress: bool) -> Vec<u8> {
+        let options = EventOptions {
+            compress,
+            ..Default::default()
+        };
+
+        let mut buf = Vec::new();
+        encoder()
+            .encode_event(EVENT, data, &options, &mut buf)
+            .unwrap();
+        buf
+    }
+
+    /// Decodes a single event, returning its data and whether it was sent compressed
+    fn decode(buf: &[u8]) -> Result<(Vec<u8>, bool), EventDecodingError> {
+        let encoder = encoder();
+        let mut reader = ByteReader::new(buf);
+        let mut scratch = Vec::new();
+
+        let event = encoder.decode_event(&mut reader, &mut scratch)?;
+        Ok((event.data.to_vec(), event.options.compress))
+    }
+
+    /// Builds an event with the compressed flag set and the given payload, bypassing the encoder checks
+    fn compressed_frame(payload: &[u8], wire_len: usize, decompressed_len: usize) -> Vec<u8> {
+        let mut flags = EventFlags::default();
+        flags.set_more_flags(true);
+        let mut ext_flags = ExtEventFlags::default();
+        ext_flags.set_compressed(true);
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = BinaryWriter::new(&mut buf);
+            writer.write_u8(0).unwrap();
+            writer.write_bits(flags).unwrap();
+            writer.write_bits(ext_flags).unwrap();
+            writer.write_varuint(wire_len as u64).unwrap();
+            writer.write_varuint(decompressed_len as u64).unwrap();
+            writer.write_bytes(payload).unwrap();
+        }
+        buf
+    }
+
+    #[test]
+    fn compressed_roundtrip() {
+        let data = compressible(1000);
+        let buf = encode(&data, true);
+        assert!(buf.len() < data.len());
+
+        let (decoded, compressed) = decode(&buf).unwrap();
+        assert!(compressed);
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn uncompressed_roundtrip() {
+        let data = compressible(1000);
+        let (decoded, compressed) = decode(&encode(&data, false)).unwrap();
+        assert!(!compressed);
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn short_data_is_not_compressed() {
+        let data = compressible(MIN_COMPRESS_LENGTH - 1);
+        let (decoded, compressed) = decode(&encode(&data, true)).unwrap();
+        assert!(!compressed);
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn incompressible_data_is_sent_uncompressed() {
+        let data = incompressible(512);
+        let (decoded, compressed) = decode(&encode(&data, true)).unwrap();
+        assert!(!compressed);
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn garbage_payload_is_rejected() {
+        let payload = [0xff; 16];
+        let buf = compressed_frame(&payload, payload.len(), 100);
+        assert!(decode(&buf).is_err());
+    }
+
+    #[test]
+    fn truncated_payload_is_rejected() {
+        let payload = lz4_flex::block::compress(&compressible(1000));
+        let buf = compressed_frame(&payload[..payload.len() / 2], payload.len(), 1000);
+        assert!(matches!(decode(&buf), Err(EventDecodingError::Decode(_))));
+    }
+
+    #[test]
+    fn wrong_decompressed_length_is_rejected() {
+        let payload = lz4_flex::block::compress(&compressible(1000));
+
+        let buf = compressed_frame(&payload, payload.len(), 1001);
+        assert!(matches!(
+            decode(&buf),
+            Err(EventDecodingError::DecompressedSizeMismatch(1001, 1000))
+        ));
+
+        let buf = compressed_frame(&payload, payload.len(), 999);
+        assert!(matches!(
+            decode(&buf),
+            Err(EventDecodingError::Decompress(_))
+        ));
+    }
+
+    #[test]
+    fn oversized_decompressed_length_is_rejected() {
+        let payload = lz4_flex::block::compress(&compressible(1000));
+        let limit = EventLimits::default().max_decompressed_length;
+
+        let buf = compressed_frame(&payload, payload.len(), limit + 1);
+        assert!(matches!(
+            decode(&buf),
+            Err(EventDecodingError::DecompressedTooLong(_, _))
+        ));
+    }
+}
@@ -0,0 +1,224 @@
+use std::{collections::HashMap, sync::Arc};
+
+use thiserror::Error;
+
+use super::{OwnedEvent, client_builtins};
+
+/// Determines who is allowed to send events in a certain namespace (mod ID).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NamespaceRule {
+    /// Any client may send events in this namespace
+    Allowed,
+    /// Only the server may send events in this namespace, clients are always rejected
+    ServerOnly,
+    /// Clients may send events in this namespace only if they have the given role
+    RequiresRole(u8),
+    /// Nobody is allowed to send events in this namespace
+    Denied,
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum EventRejection {
+    #[error("Event '{0}' can only be sent by the server")]
+    ServerOnly(Arc<str>),
+    #[error("Event '{0}' requires role {1}")]
+    MissingRole(Arc<str>, u8),
+    #[error("Event '{0}' is not allowed")]
+    Denied(Arc<str>),
+}
+
+/// A set of rules that restricts which event namespaces a client may send.
+/// The namespace of an event is the part of its ID before the first `/`, aka the mod ID.
+/// Rules for a single event take precedence over the rule of its namespace.
+#[derive(Clone, Debug)]
+pub struct EventPermissions {
+    rules: HashMap<Box<str>, NamespaceRule>,
+    event_rules: HashMap<Box<str>, NamespaceRule>,
+    default_rule: NamespaceRule,
+}
+
+impl Default for EventPermissions {
+    /// Allows everything, except for `globed/` builtins, which are server-only unless clients are meant to send them
+    fn default() -> Self {
+        let mut this =
+            Self::new(NamespaceRule::Allowed).with_rule("globed", NamespaceRule::ServerOnly);
+
+        for event in client_builtins() {
+            this.set_event_rule(event, NamespaceRule::Allowed);
+        }
+
+        this
+    }
+}
+
+impl EventPermissions {
+    /// Creates an empty set of rules, where the given rule is applied to all namespaces without an explicit rule
+    pub fn new(default_rule: NamespaceRule) -> Self {
+        Self {
+            rules: HashMap::new(),
+            event_rules: HashMap::new(),
+            default_rule,
+        }
+    }
+
+    pub fn with_rule(mut self, namespace: &str, rule: NamespaceRule) -> Self {
+        self.set_rule(namespace, rule);
+        self
+    }
+
+    pub fn set_rule(&mut self, namespace: &str, rule: NamespaceRule) {
+        self.rules.insert(namespace.into(), rule);
+    }
+
+    pub fn with_event_rule(mut self, event_id: &str, rule: NamespaceRule) -> Self {
+        self.set_event_rule(event_id, rule);
+        self
+    }
+
+    /// Sets the rule for a single event, overriding the rule of its namespace
+    pub fn set_event_rule(&mut self, event_id: &str, rule: NamespaceRule) {
+        self.event_rules.insert(event_id.into(), rule);
+    }
+
+    pub fn rule_for(&self, event_id: &str) -> &NamespaceRule {
+        if let Some(rule) = self.event_rules.get(event_id) {
+            return rule;
+        }
+
+        let namespace = event_id.split_once('/').map_or(event_id, |(ns, _)| ns);
+
+        self.rules.get(namespace).unwrap_or(&self.default_rule)
+    }
+
+    /// Checks whether a client with the given roles is allowed to send the event
+    pub fn check(&self, event_id: &Arc<str>, roles: &[u8]) -> Result<(), EventRejection> {
+        match self.rule_for(event_id) {
+            NamespaceRule::Allowed => Ok(()),
+            NamespaceRule::ServerOnly => Err(EventRejection::ServerOnly(event_id.clone())),
+            NamespaceRule::RequiresRole(role) => {
+                if roles.contains(role) {
+                    Ok(())
+                } else {
+                    Err(EventRejection::MissingRole(event_id.clone(), *role))
+                }
+            }
+            NamespaceRule::Denied => Err(EventRejection::Denied(event_id.clone())),
+        }
+    }
+
+    /// Removes all events that the client is not allowed to send, returning a rejection for each removed event
+    pub fn filter(&self, events: &mut Vec<OwnedEvent>, roles: &[u8]) -> Vec<EventRejection> {
+        let mut rejections = Vec::new();
+
+        events.retain(|event| match self.check(&event.id, roles) {
+            Ok(()) => true,
+            Err(e) => {
+                rejections.push(e);
+                false
+            }
+        });
+
+        rejections
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use qunet::buffers::BinaryWriter;
+
+    use super::*;
+    use crate::events::{
+        EventEncoder, EventLimits, EventOptions, EventStringCache, GAME_BUILTINS_MAX,
+    };
+
+    fn check(perms: &EventPermissions, event: &str, roles: &[u8]) -> Result<(), EventRejection> {
+        perms.check(&Arc::from(event), roles)
+    }
+
+    #[test]
+    fn default_allows_client_builtins() {
+        let perms = EventPermissions::default();
+
+        for event in client_builtins() {
+            assert_eq!(check(&perms, event, &[]), Ok(()), "{event}");
+        }
+    }
+
+    #[test]
+    fn default_rejects_server_builtins() {
+        let perms = EventPermissions::default();
+
+        assert!(matches!(
+            check(&perms, "globed/display-data-refreshed", &[]),
+            Err(EventRejection::ServerOnly(_))
+        ));
+        assert!(matches!(
+            check(&perms, "globed/?placeholder-20", &[]),
+            Err(EventRejection::ServerOnly(_))
+        ));
+    }
+
+    #[test]
+    fn default_allows_mod_events() {
+        assert_eq!(
+            check(&EventPermissions::default(), "some.mod/event", &[]),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn event_rule_overrides_namespace() {
+        let perms = EventPermissions::new(NamespaceRule::Allowed)
+            .with_rule("some.mod", NamespaceRule::Denied)
+            .with_event_rule("some.mod/open", NamespaceRule::RequiresRole(3));
+
+        assert!(matches!(
+            check(&perms, "some.mod/other", &[]),
+            Err(EventRejection::Denied(_))
+        ));
+        assert!(matches!(
+            check(&perms, "some.mod/open", &[1]),
+            Err(EventRejection::MissingRole(_, 3))
+        ));
+        assert_eq!(check(&perms, "some.mod/open", &[1, 3]), Ok(()));
+    }
+
+    #[test]
+    fn default_server_accepts_client_builtins() {
+        let cache = EventStringCache::new();
+
+        let mut dictionary = Vec::new();
+        {
+            let mut writer = BinaryWriter::new(&mut dictionary);
+            let mut builtins = Vec::new();
+            super::super::build_game_builtins(GAME_BUILTINS_MAX, &mut builtins, &cache);
+
+            writer.write_u32(GAME_BUILTINS_MAX).unwrap();
+            writer.write_u32(builtins.len() as u32).unwrap();
+        }
+
+        let encoder =
+            EventEncoder::create_with_dictionary(&dictionary, &cache, true, EventLimits::default())
+                .unwrap();
+
+        let events: Vec<_> = client_builtins()
+            .filter(|e| encoder.knows_event(e))
+            .map(|e| OwnedEvent {
+                id: cache.get(e),
+                data: vec![1, 2, 3],
+                options: EventOptions::default(),
+            })
+            .collect();
+        assert!(!events.is_empty());
+
+        let mut buf = Vec::new();
+        encoder.encode_events(&events, &mut buf).unwrap();
+
+        let (accepted, rejections) = encoder
+            .decode_events_checked(&buf, &EventPermissions::default(), &[])
+            .unwrap();
+
+        assert!(rejections.is_empty(), "{rejections:?}");
+        assert_eq!(accepted.len(), events.len());
+    }
+}
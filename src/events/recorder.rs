@@ -0,0 +1,358 @@
+use std::{
+    io::Read,
+    time::{Duration, Instant},
+};
+
+use super::*;
+
+const RECORDING_MAGIC: u32 = 0x47455652; // 'GEVR'
+const RECORDING_VERSION: u8 = 1;
+
+#[derive(Error, Debug)]
+pub enum EventRecordError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Failed to read recording: {0}")]
+    Decode(#[from] ByteReaderError),
+    #[error("Invalid recording magic")]
+    InvalidMagic,
+    #[error("Unsupported recording version: {0}")]
+    UnsupportedVersion(u8),
+    #[error("Failed to encode event: {0}")]
+    Encoding(#[from] EventEncodingError),
+    #[error("Failed to decode event: {0}")]
+    Decoding(#[from] EventDecodingError),
+}
+
+/// A single event read from a recording
+#[derive(Clone)]
+pub struct RecordedEvent {
+    /// Time since the start of the recording
+    pub timestamp: Duration,
+    /// Account ID of the sender, or 0 if the event was sent by the server
+    pub sender: i32,
+    pub event: OwnedEvent,
+}
+
+/// Writes events to a compact binary file, along with a snapshot of the dictionary used to encode them.
+/// Wrap the writer in a `BufWriter` if it's a file, as every event results in multiple small writes.
+pub struct EventRecorder<W: Write> {
+    writer: W,
+    encoder: EventEncoder,
+    started_at: Instant,
+    buf: Vec<u8>,
+}
+
+impl<W: Write> EventRecorder<W> {
+    pub fn new(mut writer: W, encoder: &EventEncoder) -> Result<Self, EventRecordError> {
+        let mut bw = BinaryWriter::new(&mut writer);
+        bw.write_u32(RECORDING_MAGIC)?;
+        bw.write_u8(RECORDING_VERSION)?;
+
+        let limits = encoder.limits();
+        bw.write_varuint(limits.max_event_length as u64)?;
+        bw.write_varuint(limits.max_decompressed_length as u64)?;
+
+        bw.write_varuint(encoder.events().len() as u64)?;
+        for id in encoder.events() {
+            bw.write_varuint(id.len() as u64)?;
+            bw.write_bytes(id.as_bytes())?;
+        }
+
+        Ok(Self {
+            writer,
+            encoder: encoder.clone(),
+            started_at: Instant::now(),
+            buf: Vec::new(),
+        })
+    }
+
+    /// Records an event. `sender` should be the account ID of the player that sent the event, or 0 for the server.
+    pub fn record(
+        &mut self,
+        sender: i32,
+        id: &str,
+        data: &[u8],
+        options: &EventOptions,
+    ) -> Result<(), EventRecordError> {
+        self.buf.clear();
+        self.encoder
+            .encode_event(id, data, options, &mut self.buf)?;
+
+        let timestamp = self.started_at.elapsed().as_micros() as u64;
+
+        let mut bw = BinaryWriter::new(&mut self.writer);
+        bw.write_varuint(timestamp)?;
+        bw.write_i32(sender)?;
+        bw.write_varuint(self.buf.len() as u64)?;
+        bw.write_bytes(&self.buf)?;
+
+        Ok(())
+    }
+
+    pub fn record_owned(
+        &mut self,
+        sender: i32,
+        event: &OwnedEvent,
+    ) -> Result<(), EventRecordError> {
+        self.record(sender, &event.id, &event.data, &event.options)
+    }
+
+    pub fn flush(&mut self) -> Result<(), EventRecordError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// A recording loaded into memory, created by `EventRecorder`
+pub struct EventRecording {
+    /// Encoder built from the dictionary snapshot stored in the recording
+    snapshot: EventEncoder,
+    events: Vec<RecordedEvent>,
+}
+
+impl EventRecording {
+    pub fn read(mut reader: impl Read, cache: &EventStringCache) -> Result<Self, EventRecordError> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        Self::decode(&data, cache)
+    }
+
+    pub fn decode(data: &[u8], cache: &EventStringCache) -> Result<Self, EventRecordError> {
+        let mut reader = ByteReader::new(data);
+
+        if reader.read_u32()? != RECORDING_MAGIC {
+            return Err(EventRecordError::InvalidMagic);
+        }
+
+        let version = reader.read_u8()?;
+        if version != RECORDING_VERSION {
+            return Err(EventRecordError::UnsupportedVersion(version));
+        }
+
+        let limits = EventLimits {
+            max_event_length: reader.read_varuint()? as usize,
+            max_decompressed_length: reader.read_varuint()? as usize,
+            ..Default::default()
+        };
+
+        let count = reader.read_varuint()? as usize;
+        let mut mapping: Vec<Arc<str>> =
+            Vec::with_capacity(count.min(limits.max_dictionary_size as usize));
+
+        for _ in 0..count {
+            mapping.push(cache.get(reader.read_string_var()?));
+        }
+
+        let snapshot = EventEncoder::from_mapping(mapping, limits);
+
+        let mut events = Vec::new();
+        let mut scratch = Vec::new();
+
+        while reader.remaining() > 0 {
+            let timestamp = Duration::from_micros(reader.read_varuint()?);
+            let sender = reader.read_i32()?;
+            let len = reader.read_varuint()? as usize;
+
+            let rem = reader.remaining_bytes();
+            reader.skip_bytes(len)?;
+
+            let mut ev_reader = ByteReader::new(&rem[..len]);
+            let event = snapshot.decode_event(&mut ev_reader, &mut scratch)?.into();
+
+            events.push(RecordedEvent {
+                timestamp,
+                sender,
+                event,
+            });
+        }
+
+        Ok(Self { snapshot, events })
+    }
+
+    /// Returns the dictionary that was in use when the recording was made
+    pub fn dictionary(&self) -> &[Arc<str>] {
+        self.snapshot.events()
+    }
+
+    pub fn events(&self) -> &[RecordedEvent] {
+        &self.events
+    }
+
+    /// Feeds every recorded event through the given encoder, by encoding and then decoding it again,
+    /// invoking the callback with the original recorded event and the result of decoding.
+    /// Stops at the first event that fails to encode or decode, for example because it's missing from the encoder's dictionary.
+    pub fn replay(
+        &self,
+        encoder: &EventEncoder,
+        mut f: impl FnMut(&RecordedEvent, RawDecodedEvent<'_>),
+    ) -> Result<(), EventRecordError> {
+        let mut buf = Vec::new();
+        let mut scratch = Vec::new();
+
+        for rec in &self.events {
+            buf.clear();
+            encoder.encode_event(&rec.event.id, &rec.event.data, &rec.event.options, &mut buf)?;
+
+            let mut reader = ByteReader::new(&buf);
+            let decoded = encoder.decode_event(&mut reader, &mut scratch)?;
+
+            f(rec, decoded);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encoder(events: &[&str]) -> EventEncoder {
+        EventEncoder::from_mapping(
+            events.iter().map(|e| Arc::from(*e)).collect(),
+            EventLimits::default(),
+        )
+    }
+
+    fn recording() -> Vec<u8> {
+        let mut recorder = EventRecorder::new(Vec::new(), &encoder(&["test/a", "test/b"])).unwrap();
+
+        let options = EventOptions {
+            reliable: true,
+            ..Default::default()
+        };
+
+        recorder.record(0, "test/a", &[1, 2, 3], &options).unwrap();
+        recorder
+            .record(71, "test/b", &[4; 200], &EventOptions::default())
+            .unwrap();
+        recorder.record(72, "test/a", &[], &options).unwrap();
+
+        recorder.into_inner()
+    }
+
+    #[test]
+    fn roundtrip() {
+        let cache = EventStringCache::default();
+        let recording = EventRecording::decode(&recording(), &cache).unwrap();
+
+        let dictionary: Vec<&str> = recording.dictionary().iter().map(|e| &**e).collect();
+        assert_eq!(dictionary, ["test/a", "test/b"]);
+
+        let events: Vec<_> = recording
+            .events()
+            .iter()
+            .map(|e| {
+                (
+                    e.sender,
+                    &*e.event.id,
+                    e.event.data.clone(),
+                    e.event.options.reliable,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            events,
+            [
+                (0, "test/a", vec![1, 2, 3], true),
+                (71, "test/b", vec![4; 200], false),
+                (72, "test/a", vec![], true),
+            ]
+        );
+
+        assert!(
+            recording
+                .events()
+                .windows(2)
+                .all(|w| w[0].timestamp <= w[1].timestamp)
+        );
+
+        // replay through an encoder with a different dictionary layout
+        let mut replayed = Vec::new();
+        recording
+            .replay(&encoder(&["test/b", "other", "test/a"]), |rec, ev| {
+                replayed.push((rec.sender, ev.id.to_string(), ev.data.to_vec()));
+            })
+            .unwrap();
+
+        assert_eq!(
+            replayed,
+            [
+                (0, "test/a".to_owned(), vec![1, 2, 3]),
+                (71, "test/b".to_owned(), vec![4; 200]),
+                (72, "test/a".to_owned(), vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn replay_missing_event() {
+        let cache = EventStringCache::default();
+        let recording = EventRecording::decode(&recording(), &cache).unwrap();
+
+        let mut replayed = 0;
+        let result = recording.replay(&encoder(&["test/a"]), |_, _| replayed += 1);
+
+        assert!(matches!(result, Err(EventRecordError::Encoding(_))));
+        assert_eq!(replayed, 1);
+    }
+
+    #[test]
+    fn bad_magic() {
+        let mut data = recording();
+        data[0] ^= 0xff;
+
+        let result = EventRecording::decode(&data, &EventStringCache::default());
+        assert!(matches!(result, Err(EventRecordError::InvalidMagic)));
+    }
+
+    #[test]
+    fn unknown_version() {
+        let mut data = recording();
+        data[4] = RECORDING_VERSION + 1;
+
+        let result = EventRecording::decode(&data, &EventStringCache::default());
+        assert!(matches!(
+            result,
+            Err(EventRecordError::UnsupportedVersion(v)) if v == RECORDING_VERSION + 1
+        ));
+    }
+
+    #[test]
+    fn truncated() {
+        let data = recording();
+        let cache = EventStringCache::default();
+
+        // the header alone, up to and including the dictionary, is a valid empty recording
+        let header_len = EventRecorder::new(Vec::new(), &encoder(&["test/a", "test/b"]))
+            .unwrap()
+            .into_inner()
+            .len();
+
+        for len in 0..header_len {
+            assert!(
+                EventRecording::decode(&data[..len], &cache).is_err(),
+                "{len}"
+            );
+        }
+
+        let empty = EventRecording::decode(&data[..header_len], &cache).unwrap();
+        assert!(empty.events().is_empty());
+
+        // cutting anywhere else either fails or drops the incomplete events, but never panics
+        for len in header_len..data.len() {
+            if let Ok(recording) = EventRecording::decode(&data[..len], &cache) {
+                assert!(recording.events().len() < 3, "{len}");
+            }
+        }
+
+        assert!(EventRecording::decode(&data[..data.len() - 1], &cache).is_err());
+    }
+}
@@ -0,0 +1,352 @@
+use std::time::{Duration, Instant};
+
+use thiserror::Error;
+
+use crate::{
+    data::{GameServerData, SRVC_MAGIC, SRVC_PROTOCOL_VERSION, SrvLoginOk},
+    hmac_signer::HmacSigner,
+    messages::srvc::{LoginChallengeMessage, LoginFailedMessage, LoginSrvMessage, SrvcMessage},
+    schema::srvc::LoginFailedReason,
+};
+
+/// How long a game server has to answer a login challenge
+pub const CHALLENGE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Reasons for the central server to reject a game server login
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum HandshakeError {
+    #[error("invalid magic ({0:#x})")]
+    InvalidMagic(u64),
+    #[error("game server protocol v{game} is older than the central server (v{central})")]
+    OutdatedServer { game: u32, central: u32 },
+    #[error("central server protocol v{central} is older than the game server (v{game})")]
+    OutdatedCentral { game: u32, central: u32 },
+    #[error("invalid password")]
+    InvalidPassword,
+    #[error("invalid server data: {0}")]
+    InvalidData(&'static str),
+    #[error("login challenge expired")]
+    ChallengeExpired,
+}
+
+impl HandshakeError {
+    pub fn kind(&self) -> LoginFailedReason {
+        match self {
+            Self::InvalidMagic(_) => LoginFailedReason::InvalidMagic,
+            Self::OutdatedServer { .. } => LoginFailedReason::OutdatedServer,
+            Self::OutdatedCentral { .. } => LoginFailedReason::OutdatedCentral,
+            Self::InvalidPassword => LoginFailedReason::InvalidPassword,
+            Self::InvalidData(_) => LoginFailedReason::InvalidData,
+            Self::ChallengeExpired => LoginFailedReason::ChallengeExpired,
+        }
+    }
+}
+
+impl From<&HandshakeError> for LoginFailedMessage {
+    fn from(value: &HandshakeError) -> Self {
+        Self {
+            reason: value.to_string(),
+            kind: value.kind(),
+        }
+    }
+}
+
+/// Reasons for a game server login to fail, as seen by the game server
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum LoginRejected {
+    #[error("central server rejected the login ({kind:?}): {reason}")]
+    Failed {
+        kind: LoginFailedReason,
+        reason: String,
+    },
+    #[error("unexpected message during handshake: {0}")]
+    UnexpectedMessage(&'static str),
+}
+
+/// A nonce issued by the central server to a single connection, which the game server must sign
+/// with the shared password. It is consumed by `verify_login`, so a response can never be replayed,
+/// neither on the same connection nor on another one, which gets its own nonce.
+#[derive(Debug)]
+pub struct LoginChallenge {
+    nonce: [u8; 32],
+    issued_at: Instant,
+}
+
+impl LoginChallenge {
+    pub fn new() -> Self {
+        Self {
+            nonce: random_nonce(),
+            issued_at: Instant::now(),
+        }
+    }
+
+    pub fn nonce(&self) -> &[u8; 32] {
+        &self.nonce
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(Instant::now())
+    }
+
+    /// Whether the challenge is expired at the given point in time
+    pub fn is_expired_at(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.issued_at) > CHALLENGE_TIMEOUT
+    }
+
+    /// The message to send to the game server right after it connects
+    pub fn message(&self) -> LoginChallengeMessage {
+        LoginChallengeMessage {
+            nonce: self.nonce.to_vec(),
+        }
+    }
+}
+
+impl Default for LoginChallenge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Unpredictable nonce from the OS CSPRNG
+fn random_nonce() -> [u8; 32] {
+    let mut nonce = [0u8; 32];
+    getrandom::fill(&mut nonce).expect("failed to get random bytes from the OS");
+    nonce
+}
+
+/// Keyed hash proving knowledge of the password, bound to the nonce and all of the server data,
+/// so that none of it can be altered by someone relaying the login
+pub fn challenge_response(signer: &HmacSigner, nonce: &[u8], data: &GameServerData) -> [u8; 32] {
+    signer.sign(&response_content(nonce, data))
+}
+
+/// Every variable-length part is prefixed with its length, so that no two different inputs encode the same way
+fn response_content(nonce: &[u8], data: &GameServerData) -> Vec<u8> {
+    fn push_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+        out.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+        out.extend_from_slice(bytes);
+    }
+
+    let mut content = Vec::with_capacity(
+        22 + nonce.len()
+            + data.address.len()
+            + data.string_id.len()
+            + data.name.len()
+            + data.region.len(),
+    );
+
+    push_bytes(&mut content, nonce);
+    content.push(data.id);
+    push_bytes(&mut content, data.address.as_bytes());
+    push_bytes(&mut content, data.string_id.as_bytes());
+    push_bytes(&mut content, data.name.as_bytes());
+    push_bytes(&mut content, data.region.as_bytes());
+    content.push(data.draining as u8);
+    content
+}
+
+/// Handles the first message sent by the central server on the game server
+pub fn handle_login_challenge(msg: SrvcMessage) -> Result<LoginChallengeMessage, LoginRejected> {
+    match msg {
+        SrvcMessage::LoginChallenge(msg) => Ok(msg),
+        SrvcMessage::LoginFailed(msg) => Err(LoginRejected::Failed {
+            kind: msg.kind,
+            reason: msg.reason,
+        }),
+        other => Err(LoginRejected::UnexpectedMessage(other.name())),
+    }
+}
+
+/// Builds the login message a game server sends in response to the central server's challenge.
+/// `signer` should be created with `HmacSigner::from_password`, the password itself is never sent.
+pub fn login_request(
+    signer: &HmacSigner,
+    challenge: &LoginChallengeMessage,
+    data: GameServerData,
+) -> LoginSrvMessage {
+    let response = challenge_response(signer, &challenge.nonce, &data);
+
+    LoginSrvMessage {
+        data,
+        magic: SRVC_MAGIC,
+        protocol_version: SRVC_PROTOCOL_VERSION,
+        challenge_response: response.to_vec(),
+    }
+}
+
+/// Verifies a login message on the central server against the challenge issued to this connection.
+/// On failure, the error should be sent back as a `LoginFailedMessage` and the connection closed.
+pub fn verify_login(
+    msg: &LoginSrvMessage,
+    challenge: LoginChallenge,
+    signer: &HmacSigner,
+) -> Result<(), HandshakeError> {
+    if msg.magic != SRVC_MAGIC {
+        return Err(HandshakeError::InvalidMagic(msg.magic));
+    }
+
+    if msg.protocol_version < SRVC_PROTOCOL_VERSION {
+        return Err(HandshakeError::OutdatedServer {
+            game: msg.protocol_version,
+            central: SRVC_PROTOCOL_VERSION,
+        });
+    } else if msg.protocol_version > SRVC_PROTOCOL_VERSION {
+        return Err(HandshakeError::OutdatedCentral {
+            game: msg.protocol_version,
+            central: SRVC_PROTOCOL_VERSION,
+        });
+    }
+
+    if challenge.is_expired() {
+        return Err(HandshakeError::ChallengeExpired);
+    }
+
+    let Ok(response) = <[u8; 32]>::try_from(msg.challenge_response.as_slice()) else {
+        return Err(HandshakeError::InvalidPassword);
+    };
+
+    // comparison of blake3 hashes is constant time
+    let content = response_content(&challenge.nonce, &msg.data);
+    if !signer.validate(&content, response) {
+        return Err(HandshakeError::InvalidPassword);
+    }
+
+    verify_server_data(&msg.data)
+}
+
+fn verify_server_data(data: &GameServerData) -> Result<(), HandshakeError> {
+    if data.string_id.is_empty() {
+        return Err(HandshakeError::InvalidData("empty string ID"));
+    }
+
+    if data.name.is_empty() {
+        return Err(HandshakeError::InvalidData("empty name"));
+    }
+
+    if data.address.is_empty() {
+        return Err(HandshakeError::InvalidData("empty address"));
+    }
+
+    Ok(())
+}
+
+/// Handles the response of the central server to `login_request` on the game server
+pub fn handle_login_response(msg: SrvcMessage) -> Result<SrvLoginOk, LoginRejected> {
+    match msg {
+        SrvcMessage::LoginOk(msg) => Ok(msg),
+        SrvcMessage::LoginFailed(msg) => Err(LoginRejected::Failed {
+            kind: msg.kind,
+            reason: msg.reason,
+        }),
+        other => Err(LoginRejected::UnexpectedMessage(other.name())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server_data() -> GameServerData {
+        GameServerData {
+            id: 3,
+            address: "127.0.0.1:4202".try_into().unwrap(),
+            string_id: "eu-1".try_into().unwrap(),
+            name: "Europe".try_into().unwrap(),
+            region: "eu".try_into().unwrap(),
+            draining: false,
+        }
+    }
+
+    fn login(data: GameServerData) -> (LoginChallenge, LoginSrvMessage) {
+        let signer = HmacSigner::from_password("password");
+        let challenge = LoginChallenge::new();
+        let msg = login_request(&signer, &challenge.message(), data);
+        (challenge, msg)
+    }
+
+    #[test]
+    fn valid_login() {
+        let signer = HmacSigner::from_password("password");
+        let (challenge, msg) = login(server_data());
+
+        assert_eq!(verify_login(&msg, challenge, &signer), Ok(()));
+    }
+
+    #[test]
+    fn wrong_password() {
+        let signer = HmacSigner::from_password("not the password");
+        let (challenge, msg) = login(server_data());
+
+        assert_eq!(
+            verify_login(&msg, challenge, &signer),
+            Err(HandshakeError::InvalidPassword)
+        );
+    }
+
+    #[test]
+    fn nonces_differ() {
+        assert_ne!(LoginChallenge::new().nonce(), LoginChallenge::new().nonce());
+    }
+
+    #[test]
+    fn challenge_expiry() {
+        let challenge = LoginChallenge::new();
+        let issued = challenge.issued_at;
+
+        assert!(!challenge.is_expired_at(issued));
+        assert!(!challenge.is_expired_at(issued + CHALLENGE_TIMEOUT));
+        assert!(challenge.is_expired_at(issued + CHALLENGE_TIMEOUT + Duration::from_millis(1)));
+    }
+
+    #[test]
+    fn expired_challenge_is_rejected() {
+        let signer = HmacSigner::from_password("password");
+        let (mut challenge, msg) = login(server_data());
+        challenge.issued_at = Instant::now()
+            .checked_sub(CHALLENGE_TIMEOUT + Duration::from_secs(1))
+            .unwrap();
+
+        assert_eq!(
+            verify_login(&msg, challenge, &signer),
+            Err(HandshakeError::ChallengeExpired)
+        );
+    }
+
+    #[test]
+    fn response_covers_server_data() {
+        let signer = HmacSigner::from_password("password");
+
+        let tampered: [fn(&mut GameServerData); 6] = [
+            |d| d.id += 1,
+            |d| d.address = "10.0.0.1:4202".try_into().unwrap(),
+            |d| d.string_id = "eu-2".try_into().unwrap(),
+            |d| d.name = "Elsewhere".try_into().unwrap(),
+            |d| d.region = "na".try_into().unwrap(),
+            |d| d.draining = true,
+        ];
+
+        for tamper in tampered {
+            let (challenge, mut msg) = login(server_data());
+            tamper(&mut msg.data);
+
+            assert_eq!(
+                verify_login(&msg, challenge, &signer),
+                Err(HandshakeError::InvalidPassword)
+            );
+        }
+    }
+
+    #[test]
+    fn content_is_unambiguous() {
+        let mut a = server_data();
+        a.name = "ab".try_into().unwrap();
+        a.region = "c".try_into().unwrap();
+
+        let mut b = server_data();
+        b.name = "a".try_into().unwrap();
+        b.region = "bc".try_into().unwrap();
+
+        let nonce = [7u8; 32];
+        assert_ne!(response_content(&nonce, &a), response_content(&nonce, &b));
+    }
+}
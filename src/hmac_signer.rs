@@ -13,6 +13,13 @@ impl HmacSigner {
         })
     }
 
+    /// Creates a signer keyed by a shared password instead of a hex encoded key
+    pub fn from_password(password: &str) -> Self {
+        Self {
+            secret_key: blake3::derive_key("globed srvc login password", password.as_bytes()),
+        }
+    }
+
     #[inline]
     pub fn validate(&self, content: &[u8], signature: [u8; 32]) -> bool {
         blake3::keyed_hash(&self.secret_key, content) == blake3::Hash::from_bytes(signature)
@@ -19,19 +19,52 @@ pub(crate) mod srvc_capnp;
 #[path = "../schema/generated/shared_capnp.rs"]
 pub(crate) mod shared_capnp;
 
+#[allow(warnings)]
+#[path = "../schema/generated/shared_models.rs"]
+mod shared_models;
+
+#[cfg(feature = "main")]
+#[allow(warnings)]
+#[path = "../schema/generated/main_models.rs"]
+mod main_models;
+
+#[cfg(feature = "game")]
+#[allow(warnings)]
+#[path = "../schema/generated/game_models.rs"]
+mod game_models;
+
+#[cfg(feature = "srvc")]
+#[allow(warnings)]
+#[path = "../schema/generated/srvc_models.rs"]
+mod srvc_models;
+
+// the schema compatibility checks of build.rs, included here so that their tests are run
+#[cfg(test)]
+#[path = "../build/schema_compat.rs"]
+mod schema_compat;
+
 pub mod config;
 mod constants;
 pub mod data;
+#[cfg(feature = "srvc")]
+pub mod drain;
 pub mod encoding;
 pub mod events;
+#[cfg(feature = "srvc")]
+pub mod handshake;
 pub mod hmac_signer;
+#[cfg(feature = "srvc")]
+pub mod load_balancer;
 pub mod logging;
+pub mod messages;
 mod multi_color;
+pub mod protocol;
 pub mod schema;
 mod session_id;
 pub mod token_issuer;
 mod typemap;
-mod user_settings;
+#[cfg(feature = "srvc")]
+pub mod user_data_cache;
 
 pub use capnp;
 pub use qunet;
@@ -39,5 +72,5 @@ pub use qunet;
 pub use constants::*;
 pub use multi_color::*;
 pub use session_id::SessionId;
+pub use shared_models::UserSettings;
 pub use typemap::TypeMap;
-pub use user_settings::UserSettings;
@@ -0,0 +1,399 @@
+use std::{
+    cmp::Ordering,
+    hash::{BuildHasher, RandomState},
+    sync::atomic::{self, AtomicU64},
+};
+
+use crate::data::{GameServerData, SrvStatusData};
+
+/// Servers at or above this load are not chosen unless the request says otherwise
+pub const DEFAULT_MAX_LOAD: f32 = 0.9;
+
+/// A connected game server, along with its latest status
+#[derive(Clone, Copy, Debug)]
+pub struct ServerCandidate<'a> {
+    pub server: &'a GameServerData,
+    pub status: &'a SrvStatusData,
+}
+
+impl ServerCandidate<'_> {
+    pub fn load(&self) -> f32 {
+        self.status.server_load
+    }
+
+    pub fn in_region(&self, region: &str) -> bool {
+        self.server.region.eq_ignore_ascii_case(region)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlacementRequest<'a> {
+    /// Preferred region, matched against `GameServerData::region`
+    pub region: Option<&'a str>,
+    /// Servers with a higher `server_load` are never chosen
+    pub max_load: f32,
+}
+
+impl Default for PlacementRequest<'_> {
+    fn default() -> Self {
+        Self {
+            region: None,
+            max_load: DEFAULT_MAX_LOAD,
+        }
+    }
+}
+
+impl<'a> PlacementRequest<'a> {
+    pub fn in_region(region: &'a str) -> Self {
+        Self {
+            region: Some(region),
+            ..Default::default()
+        }
+    }
+
+    /// Whether the server can take the new room at all
+    pub fn accepts(&self, candidate: &ServerCandidate<'_>) -> bool {
+        !candidate.server.draining && candidate.load() < self.max_load
+    }
+}
+
+/// Orders candidates that passed the capacity constraints, best first
+pub trait SelectionStrategy {
+    fn rank(&self, candidates: &mut [ServerCandidate<'_>], request: &PlacementRequest<'_>);
+}
+
+/// Prefers the server with the lowest load, then the one with the fewest clients
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LeastLoad;
+
+impl SelectionStrategy for LeastLoad {
+    fn rank(&self, candidates: &mut [ServerCandidate<'_>], _request: &PlacementRequest<'_>) {
+        candidates.sort_by(compare_load);
+    }
+}
+
+/// Prefers servers in the requested region, ranking each group with the inner strategy
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RegionAffinity<S = LeastLoad>(pub S);
+
+impl<S: SelectionStrategy> SelectionStrategy for RegionAffinity<S> {
+    fn rank(&self, candidates: &mut [ServerCandidate<'_>], request: &PlacementRequest<'_>) {
+        let Some(region) = request.region else {
+            self.0.rank(candidates, request);
+            return;
+        };
+
+        // stable, so the relative order within each group is preserved
+        candidates.sort_by_key(|c| !c.in_region(region));
+        let local = candidates
+            .iter()
+            .take_while(|c| c.in_region(region))
+            .count();
+
+        let (local, remote) = candidates.split_at_mut(local);
+        self.0.rank(local, request);
+        self.0.rank(remote, request);
+    }
+}
+
+/// Picks servers at random, with less loaded servers being proportionally more likely to come first.
+/// Spreads rooms more evenly than `LeastLoad` when many are created between two status updates.
+#[derive(Debug)]
+pub struct WeightedRandom {
+    state: AtomicU64,
+}
+
+impl WeightedRandom {
+    pub fn new() -> Self {
+        Self::with_seed(RandomState::new().hash_one(0u64))
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            state: AtomicU64::new(seed),
+        }
+    }
+
+    /// Uniform value in `(0, 1]`
+    fn next_unit(&self) -> f64 {
+        // splitmix64
+        let mut z = self
+            .state
+            .fetch_add(0x9e3779b97f4a7c15, atomic::Ordering::Relaxed)
+            .wrapping_add(0x9e3779b97f4a7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^= z >> 31;
+
+        ((z >> 11) + 1) as f64 / (1u64 << 53) as f64
+    }
+}
+
+impl Default for WeightedRandom {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SelectionStrategy for WeightedRandom {
+    fn rank(&self, candidates: &mut [ServerCandidate<'_>], _request: &PlacementRequest<'_>) {
+        // weighted sampling without replacement (Efraimidis-Spirakis): sort by u^(1/w) descending.
+        // fully loaded servers have no weight and always come last
+        let mut keyed: Vec<_> = candidates
+            .iter()
+            .map(|c| {
+                let weight = 1.0 - c.load() as f64;
+                let key = if weight > 0.0 {
+                    self.next_unit().powf(1.0 / weight)
+                } else {
+                    -1.0
+                };
+                (key, *c)
+            })
+            .collect();
+
+        keyed.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+        for (dst, (_, c)) in candidates.iter_mut().zip(keyed) {
+            *dst = c;
+        }
+    }
+}
+
+fn compare_load(a: &ServerCandidate<'_>, b: &ServerCandidate<'_>) -> Ordering {
+    a.load()
+        .total_cmp(&b.load())
+        .then(a.status.auth_clients.cmp(&b.status.auth_clients))
+        .then(a.server.id.cmp(&b.server.id))
+}
+
+/// Filters out servers that can't take the room and ranks the rest, best first
+pub fn rank_servers<'a>(
+    candidates: impl IntoIterator<Item = ServerCandidate<'a>>,
+    request: &PlacementRequest<'_>,
+    strategy: &dyn SelectionStrategy,
+) -> Vec<ServerCandidate<'a>> {
+    let mut candidates: Vec<_> = candidates
+        .into_iter()
+        .filter(|c| request.accepts(c))
+        .collect();
+
+    strategy.rank(&mut candidates, request);
+    candidates
+}
+
+/// Picks the server for a new room, or `None` if every server is at capacity.
+/// A non-zero `RoomSettings::server_id` should be honored instead of calling this.
+pub fn choose_server<'a>(
+    candidates: impl IntoIterator<Item = ServerCandidate<'a>>,
+    request: &PlacementRequest<'_>,
+    strategy: &dyn SelectionStrategy,
+) -> Option<&'a GameServerData> {
+    rank_servers(candidates, request, strategy)
+        .first()
+        .map(|c| c.server)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server(id: u8, region: &str, load: f32) -> (GameServerData, SrvStatusData) {
+        let server = GameServerData {
+            id,
+            address: "127.0.0.1:4202".try_into().unwrap(),
+            string_id: format!("server-{id}").as_str().try_into().unwrap(),
+            name: "Server".try_into().unwrap(),
+            region: region.try_into().unwrap(),
+            draining: false,
+        };
+
+        let status = SrvStatusData {
+            server_load: load,
+            ..Default::default()
+        };
+
+        (server, status)
+    }
+
+    fn candidates(servers: &[(GameServerData, SrvStatusData)]) -> Vec<ServerCandidate<'_>> {
+        servers
+            .iter()
+            .map(|(server, status)| ServerCandidate { server, status })
+            .collect()
+    }
+
+    fn ranked_ids(
+        servers: &[(GameServerData, SrvStatusData)],
+        request: &PlacementRequest<'_>,
+        strategy: &dyn SelectionStrategy,
+    ) -> Vec<u8> {
+        rank_servers(candidates(servers), request, strategy)
+            .iter()
+            .map(|c| c.server.id)
+            .collect()
+    }
+
+    #[test]
+    fn least_load_order() {
+        let mut servers = vec![
+            server(1, "eu", 0.5),
+            server(2, "eu", 0.1),
+            server(3, "na", 0.3),
+            server(4, "na", 0.3),
+            server(5, "na", 0.3),
+        ];
+
+        // equal load is broken by client count, then by ID
+        servers[2].1.auth_clients = 20;
+        servers[3].1.auth_clients = 10;
+        servers[4].1.auth_clients = 20;
+
+        let ids = ranked_ids(&servers, &PlacementRequest::default(), &LeastLoad);
+        assert_eq!(ids, [2, 4, 3, 5, 1]);
+    }
+
+    #[test]
+    fn excludes_draining_and_full() {
+        let mut servers = vec![
+            server(1, "eu", 0.1),
+            server(2, "eu", DEFAULT_MAX_LOAD),
+            server(3, "eu", 1.0),
+            server(4, "eu", 0.5),
+        ];
+        servers[0].0.draining = true;
+
+        for strategy in [
+            &LeastLoad as &dyn SelectionStrategy,
+            &RegionAffinity(LeastLoad),
+            &WeightedRandom::with_seed(1),
+        ] {
+            let ids = ranked_ids(&servers, &PlacementRequest::in_region("eu"), strategy);
+            assert_eq!(ids, [4]);
+        }
+    }
+
+    #[test]
+    fn region_preferred() {
+        let servers = vec![
+            server(1, "eu", 0.6),
+            server(2, "na", 0.1),
+            server(3, "eu", 0.4),
+            server(4, "na", 0.2),
+        ];
+
+        let strategy = RegionAffinity(LeastLoad);
+
+        let ids = ranked_ids(&servers, &PlacementRequest::in_region("EU"), &strategy);
+        assert_eq!(ids, [3, 1, 2, 4]);
+
+        // without a region, only the load matters
+        let ids = ranked_ids(&servers, &PlacementRequest::default(), &strategy);
+        assert_eq!(ids, [2, 4, 3, 1]);
+    }
+
+    #[test]
+    fn region_fallback() {
+        let mut servers = vec![
+            server(1, "eu", 0.1),
+            server(2, "eu", 0.95),
+            server(3, "na", 0.4),
+            server(4, "na", 0.2),
+        ];
+        servers[0].0.draining = true;
+
+        let strategy = RegionAffinity(LeastLoad);
+
+        let ids = ranked_ids(&servers, &PlacementRequest::in_region("eu"), &strategy);
+        assert_eq!(ids, [4, 3]);
+
+        let ids = ranked_ids(&servers, &PlacementRequest::in_region("as"), &strategy);
+        assert_eq!(ids, [4, 3]);
+
+        let chosen = choose_server(
+            candidates(&servers),
+            &PlacementRequest::in_region("eu"),
+            &strategy,
+        );
+        assert_eq!(chosen.map(|s| s.id), Some(4));
+    }
+
+    #[test]
+    fn weighted_random_deterministic() {
+        let servers: Vec<_> = (1..=8)
+            .map(|id| server(id, "eu", id as f32 / 10.0))
+            .collect();
+
+        let request = PlacementRequest {
+            max_load: 1.0,
+            ..Default::default()
+        };
+
+        let a = WeightedRandom::with_seed(42);
+        let b = WeightedRandom::with_seed(42);
+
+        for _ in 0..10 {
+            let ids = ranked_ids(&servers, &request, &a);
+            assert_eq!(ids, ranked_ids(&servers, &request, &b));
+
+            let mut sorted = ids.clone();
+            sorted.sort();
+            assert_eq!(sorted, (1..=8).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn weighted_random_never_picks_zero_weight() {
+        let servers = vec![
+            server(1, "eu", 1.0),
+            server(2, "eu", 0.99),
+            server(3, "eu", 1.0),
+        ];
+
+        let request = PlacementRequest {
+            max_load: 2.0,
+            ..Default::default()
+        };
+
+        for seed in 0..1000 {
+            let ids = ranked_ids(&servers, &request, &WeightedRandom::with_seed(seed));
+            assert_eq!(ids[0], 2, "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn weighted_random_prefers_less_loaded() {
+        let servers = vec![server(1, "eu", 0.8), server(2, "eu", 0.1)];
+
+        let first_picks = (0..1000)
+            .filter(|&seed| {
+                let strategy = WeightedRandom::with_seed(seed);
+                ranked_ids(&servers, &PlacementRequest::default(), &strategy)[0] == 2
+            })
+            .count();
+
+        // the less loaded server has 0.9 / 1.1 of the total weight
+        assert!((700..950).contains(&first_picks), "{first_picks}");
+    }
+
+    #[test]
+    fn choose_none() {
+        let request = PlacementRequest::default();
+
+        assert_eq!(choose_server([], &request, &LeastLoad), None);
+
+        let mut servers = vec![server(1, "eu", 0.1), server(2, "eu", 0.9)];
+        servers[0].0.draining = true;
+
+        for strategy in [
+            &LeastLoad as &dyn SelectionStrategy,
+            &RegionAffinity(LeastLoad),
+            &WeightedRandom::with_seed(1),
+        ] {
+            assert_eq!(
+                choose_server(candidates(&servers), &request, strategy),
+                None
+            );
+        }
+    }
+}
@@ -0,0 +1,707 @@
+use crate::{
+    UserSettings,
+    data::PlayerIconData,
+    encoding::{
+        DataDecodeError, EncodeMessageError, PackStrategy, builder_borrow, encode_message_into,
+        size::*,
+    },
+    protocol::{Capabilities, ProtocolMismatch, check_game_version},
+    schema::game::{self as schema, message::Which},
+};
+
+pub use crate::game_models::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoginMessage {
+    pub account_id: i32,
+    pub token: String,
+    pub icons: PlayerIconData,
+    pub settings: UserSettings,
+    pub session_id: u64,
+    pub passcode: u32,
+    pub platformer: bool,
+    pub editor_collab: bool,
+    pub event_dictionary: Vec<u8>,
+    pub protocol_version: u16,
+    pub capabilities: Capabilities,
+}
+
+impl LoginMessage {
+    pub fn from_reader(reader: schema::login_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            token: reader.get_token()?.to_str()?.to_owned(),
+            icons: PlayerIconData::from_reader(reader.get_icons()?)?,
+            settings: UserSettings::from_reader(reader.get_settings()?)?,
+            session_id: reader.get_session_id(),
+            passcode: reader.get_passcode(),
+            platformer: reader.get_platformer(),
+            editor_collab: reader.get_editor_collab(),
+            event_dictionary: reader.get_event_dictionary()?.to_vec(),
+            protocol_version: reader.get_protocol_version(),
+            capabilities: Capabilities::from_bits(reader.get_capabilities()),
+        })
+    }
+
+    pub fn encode(&self, mut builder: schema::login_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        builder.set_token(self.token.as_str());
+        self.icons.encode(builder.reborrow().init_icons());
+        self.settings.encode(builder.reborrow().init_settings());
+        builder.set_session_id(self.session_id);
+        builder.set_passcode(self.passcode);
+        builder.set_platformer(self.platformer);
+        builder.set_editor_collab(self.editor_collab);
+        builder.set_event_dictionary(&self.event_dictionary);
+        builder.set_protocol_version(self.protocol_version);
+        builder.set_capabilities(self.capabilities.bits());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<schema::login_message::Builder<'static>>()
+            + text_size(self.token.len())
+            + self.icons.encoded_size()
+            + self.settings.encoded_size()
+            + data_size(self.event_dictionary.len())
+    }
+
+    /// Checks that the client speaks a supported protocol version,
+    /// returning the capabilities supported by both sides, to be sent back in `LoginOkMessage`
+    pub fn negotiate(&self) -> Result<Capabilities, ProtocolMismatch> {
+        check_game_version(self.protocol_version)?;
+        Ok(Capabilities::SUPPORTED.negotiate(self.capabilities))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoginOkMessage {
+    pub tickrate: u16,
+    pub capabilities: Capabilities,
+}
+
+impl LoginOkMessage {
+    pub fn from_reader(
+        reader: schema::login_ok_message::Reader<'_>,
+    ) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            tickrate: reader.get_tickrate(),
+            capabilities: Capabilities::from_bits(reader.get_capabilities()),
+        })
+    }
+
+    pub fn encode(&self, mut builder: schema::login_ok_message::Builder<'_>) {
+        builder.set_tickrate(self.tickrate);
+        builder.set_capabilities(self.capabilities.bits());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<schema::login_ok_message::Builder<'static>>()
+    }
+}
+
+impl From<ProtocolMismatch> for LoginFailedMessage {
+    fn from(value: ProtocolMismatch) -> Self {
+        Self {
+            reason: value.game_reason(),
+        }
+    }
+}
+
+/// Player objects sent in `PlayerData`, depending on whether the player is in dual mode or culled
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum PlayerObjects {
+    Dual(PlayerObjectData, PlayerObjectData),
+    Single(PlayerObjectData),
+    #[default]
+    Culled,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PlayerData {
+    pub account_id: i32,
+    pub timestamp: f32,
+    pub frame_number: u8,
+    pub death_count: u8,
+    pub percentage: u16,
+    pub is_dead: bool,
+    pub is_paused: bool,
+    pub is_practicing: bool,
+    pub is_in_editor: bool,
+    pub is_editor_building: bool,
+    pub is_last_death_real: bool,
+    pub objects: PlayerObjects,
+}
+
+impl PlayerData {
+    pub fn from_reader(reader: schema::player_data::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            timestamp: reader.get_timestamp(),
+            frame_number: reader.get_frame_number(),
+            death_count: reader.get_death_count(),
+            percentage: reader.get_percentage(),
+            is_dead: reader.get_is_dead(),
+            is_paused: reader.get_is_paused(),
+            is_practicing: reader.get_is_practicing(),
+            is_in_editor: reader.get_is_in_editor(),
+            is_editor_building: reader.get_is_editor_building(),
+            is_last_death_real: reader.get_is_last_death_real(),
+            objects: match reader.which()? {
+                schema::player_data::Dual(dual) => PlayerObjects::Dual(
+                    PlayerObjectData::from_reader(dual.get_player1()?)?,
+                    PlayerObjectData::from_reader(dual.get_player2()?)?,
+                ),
+                schema::player_data::Single(single) => {
+                    PlayerObjects::Single(PlayerObjectData::from_reader(single.get_player1()?)?)
+                }
+                schema::player_data::Culled(_) => PlayerObjects::Culled,
+            },
+        })
+    }
+
+    pub fn encode(&self, mut builder: schema::player_data::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        builder.set_timestamp(self.timestamp);
+        builder.set_frame_number(self.frame_number);
+        builder.set_death_count(self.death_count);
+        builder.set_percentage(self.percentage);
+        builder.set_is_dead(self.is_dead);
+        builder.set_is_paused(self.is_paused);
+        builder.set_is_practicing(self.is_practicing);
+        builder.set_is_in_editor(self.is_in_editor);
+        builder.set_is_editor_building(self.is_editor_building);
+        builder.set_is_last_death_real(self.is_last_death_real);
+
+        match &self.objects {
+            PlayerObjects::Dual(player1, player2) => {
+                let mut dual = builder.init_dual();
+                player1.encode(dual.reborrow().init_player1());
+                player2.encode(dual.init_player2());
+            }
+            PlayerObjects::Single(player1) => player1.encode(builder.init_single().init_player1()),
+            PlayerObjects::Culled => builder.init_culled().set_nothing(()),
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<schema::player_data::Builder<'static>>()
+            + match &self.objects {
+                PlayerObjects::Dual(player1, player2) => {
+                    player1.encoded_size() + player2.encoded_size()
+                }
+                PlayerObjects::Single(player1) => player1.encoded_size(),
+                PlayerObjects::Culled => 0,
+            }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Event {
+    pub event_type: u16,
+    pub data: Vec<u8>,
+}
+
+impl Event {
+    pub fn from_reader(reader: schema::event::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            event_type: reader.get_type(),
+            data: reader.get_data()?.to_vec(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: schema::event::Builder<'_>) {
+        builder.set_type(self.event_type);
+        builder.set_data(&self.data);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<schema::event::Builder<'static>>() + data_size(self.data.len())
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum GameMessage {
+    Login(LoginMessage),
+    JoinSession(JoinSessionMessage),
+    LeaveSession,
+    PlayerData(PlayerDataMessage),
+    PlayerUpdateMeta(PlayerUpdateMetaMessage),
+    UpdateIcons(UpdateIconsMessage),
+    UpdateUserSettings(UpdateUserSettingsMessage),
+    SendLevelScript(SendLevelScriptMessage),
+    VoiceData(VoiceDataMessage),
+    QuickChat(QuickChatMessage),
+    LoginOk(LoginOkMessage),
+    LoginFailed(LoginFailedMessage),
+    JoinSessionOk(JoinSessionOkMessage),
+    JoinSessionFailed(JoinSessionFailedMessage),
+    LevelData(LevelDataMessage),
+    LevelMeta(LevelMetaMessage),
+    Kicked(KickedMessage),
+    ScriptLogs(ScriptLogsMessage),
+    VoiceBroadcast(VoiceBroadcastMessage),
+    ChatNotPermitted(ChatNotPermittedMessage),
+    QuickChatBroadcast(QuickChatBroadcastMessage),
+    Events(Vec<u8>),
+}
+
+impl GameMessage {
+    pub fn from_reader(reader: schema::message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(match reader.which()? {
+            Which::Login(msg) => Self::Login(LoginMessage::from_reader(msg?)?),
+            Which::JoinSession(msg) => Self::JoinSession(JoinSessionMessage::from_reader(msg?)?),
+            Which::LeaveSession(_) => Self::LeaveSession,
+            Which::PlayerData(msg) => Self::PlayerData(PlayerDataMessage::from_reader(msg?)?),
+            Which::PlayerUpdateMeta(msg) => {
+                Self::PlayerUpdateMeta(PlayerUpdateMetaMessage::from_reader(msg?)?)
+            }
+            Which::UpdateIcons(msg) => Self::UpdateIcons(UpdateIconsMessage::from_reader(msg?)?),
+            Which::UpdateUserSettings(msg) => {
+                Self::UpdateUserSettings(UpdateUserSettingsMessage::from_reader(msg?)?)
+            }
+            Which::SendLevelScript(msg) => {
+                Self::SendLevelScript(SendLevelScriptMessage::from_reader(msg?)?)
+            }
+            Which::VoiceData(msg) => Self::VoiceData(VoiceDataMessage::from_reader(msg?)?),
+            Which::QuickChat(msg) => Self::QuickChat(QuickChatMessage::from_reader(msg?)?),
+            Which::LoginOk(msg) => Self::LoginOk(LoginOkMessage::from_reader(msg?)?),
+            Which::LoginFailed(msg) => Self::LoginFailed(LoginFailedMessage::from_reader(msg?)?),
+            Which::JoinSessionOk(msg) => {
+                Self::JoinSessionOk(JoinSessionOkMessage::from_reader(msg?)?)
+            }
+            Which::JoinSessionFailed(msg) => {
+                Self::JoinSessionFailed(JoinSessionFailedMessage::from_reader(msg?)?)
+            }
+            Which::LevelData(msg) => Self::LevelData(LevelDataMessage::from_reader(msg?)?),
+            Which::LevelMeta(msg) => Self::LevelMeta(LevelMetaMessage::from_reader(msg?)?),
+            Which::Kicked(msg) => Self::Kicked(KickedMessage::from_reader(msg?)?),
+            Which::ScriptLogs(msg) => Self::ScriptLogs(ScriptLogsMessage::from_reader(msg?)?),
+            Which::VoiceBroadcast(msg) => {
+                Self::VoiceBroadcast(VoiceBroadcastMessage::from_reader(msg?)?)
+            }
+            Which::ChatNotPermitted(msg) => {
+                Self::ChatNotPermitted(ChatNotPermittedMessage::from_reader(msg?)?)
+            }
+            Which::QuickChatBroadcast(msg) => {
+                Self::QuickChatBroadcast(QuickChatBroadcastMessage::from_reader(msg?)?)
+            }
+            Which::Events(data) => Self::Events(data?.to_vec()),
+        })
+    }
+
+    pub fn encode(&self, mut builder: schema::message::Builder<'_>) {
+        match self {
+            Self::Login(msg) => msg.encode(builder.init_login()),
+            Self::JoinSession(msg) => msg.encode(builder.init_join_session()),
+            Self::LeaveSession => {
+                builder.init_leave_session();
+            }
+            Self::PlayerData(msg) => msg.encode(builder.init_player_data()),
+            Self::PlayerUpdateMeta(msg) => msg.encode(builder.init_player_update_meta()),
+            Self::UpdateIcons(msg) => msg.encode(builder.init_update_icons()),
+            Self::UpdateUserSettings(msg) => msg.encode(builder.init_update_user_settings()),
+            Self::SendLevelScript(msg) => msg.encode(builder.init_send_level_script()),
+            Self::VoiceData(msg) => msg.encode(builder.init_voice_data()),
+            Self::QuickChat(msg) => msg.encode(builder.init_quick_chat()),
+            Self::LoginOk(msg) => msg.encode(builder.init_login_ok()),
+            Self::LoginFailed(msg) => msg.encode(builder.init_login_failed()),
+            Self::JoinSessionOk(msg) => msg.encode(builder.init_join_session_ok()),
+            Self::JoinSessionFailed(msg) => msg.encode(builder.init_join_session_failed()),
+            Self::LevelData(msg) => msg.encode(builder.init_level_data()),
+            Self::LevelMeta(msg) => msg.encode(builder.init_level_meta()),
+            Self::Kicked(msg) => msg.encode(builder.init_kicked()),
+            Self::ScriptLogs(msg) => msg.encode(builder.init_script_logs()),
+            Self::VoiceBroadcast(msg) => msg.encode(builder.init_voice_broadcast()),
+            Self::ChatNotPermitted(msg) => msg.encode(builder.init_chat_not_permitted()),
+            Self::QuickChatBroadcast(msg) => msg.encode(builder.init_quick_chat_broadcast()),
+            Self::Events(data) => builder.set_events(data),
+        }
+    }
+
+    /// Upper bound for the size of this message in a capnp arena, in bytes, not including the root pointer
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<schema::message::Builder<'static>>()
+            + match self {
+                Self::Login(msg) => msg.encoded_size(),
+                Self::JoinSession(msg) => msg.encoded_size(),
+                Self::PlayerData(msg) => msg.encoded_size(),
+                Self::PlayerUpdateMeta(msg) => msg.encoded_size(),
+                Self::UpdateIcons(msg) => msg.encoded_size(),
+                Self::UpdateUserSettings(msg) => msg.encoded_size(),
+                Self::SendLevelScript(msg) => msg.encoded_size(),
+                Self::VoiceData(msg) => msg.encoded_size(),
+                Self::QuickChat(msg) => msg.encoded_size(),
+                Self::LoginOk(msg) => msg.encoded_size(),
+                Self::LoginFailed(msg) => msg.encoded_size(),
+                Self::JoinSessionOk(msg) => msg.encoded_size(),
+                Self::JoinSessionFailed(msg) => msg.encoded_size(),
+                Self::LevelData(msg) => msg.encoded_size(),
+                Self::LevelMeta(msg) => msg.encoded_size(),
+                Self::Kicked(msg) => msg.encoded_size(),
+                Self::ScriptLogs(msg) => msg.encoded_size(),
+                Self::VoiceBroadcast(msg) => msg.encoded_size(),
+                Self::ChatNotPermitted(msg) => msg.encoded_size(),
+                Self::QuickChatBroadcast(msg) => msg.encoded_size(),
+                Self::Events(data) => data_size(data.len()),
+                Self::LeaveSession => 0,
+            }
+    }
+
+    /// Arena capacity needed to encode this message, used by `encode_message!` when given an owned message
+    pub fn capacity(&self) -> usize {
+        message_capacity(self.encoded_size())
+    }
+
+    /// Name of the message variant, as in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Login(_) => "Login",
+            Self::JoinSession(_) => "JoinSession",
+            Self::LeaveSession => "LeaveSession",
+            Self::PlayerData(_) => "PlayerData",
+            Self::PlayerUpdateMeta(_) => "PlayerUpdateMeta",
+            Self::UpdateIcons(_) => "UpdateIcons",
+            Self::UpdateUserSettings(_) => "UpdateUserSettings",
+            Self::SendLevelScript(_) => "SendLevelScript",
+            Self::VoiceData(_) => "VoiceData",
+            Self::QuickChat(_) => "QuickChat",
+            Self::LoginOk(_) => "LoginOk",
+            Self::LoginFailed(_) => "LoginFailed",
+            Self::JoinSessionOk(_) => "JoinSessionOk",
+            Self::JoinSessionFailed(_) => "JoinSessionFailed",
+            Self::LevelData(_) => "LevelData",
+            Self::LevelMeta(_) => "LevelMeta",
+            Self::Kicked(_) => "Kicked",
+            Self::ScriptLogs(_) => "ScriptLogs",
+            Self::VoiceBroadcast(_) => "VoiceBroadcast",
+            Self::ChatNotPermitted(_) => "ChatNotPermitted",
+            Self::QuickChatBroadcast(_) => "QuickChatBroadcast",
+            Self::Events(_) => "Events",
+        }
+    }
+
+    /// Encodes the message into `out`, prefixed with its unpacked length, as expected by the receiving end.
+    /// The message is packed, unless that would make it larger.
+    #[track_caller]
+    pub fn encode_to_vec(&self, out: &mut Vec<u8>) -> Result<(), EncodeMessageError> {
+        let capacity = self.capacity();
+        let mut arena = vec![0u8; capacity];
+        let mut builder = builder_borrow(&mut arena);
+
+        encode_message_into::<schema::message::Owned, _, _>(
+            &mut builder,
+            self.name(),
+            capacity,
+            PackStrategy::Auto,
+            out,
+            |b| {
+                self.encode(b);
+                Ok(())
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use qunet::buffers::ByteReader;
+
+    use super::*;
+    use crate::{
+        MultiColor, RGBColor,
+        data::{PlayerDisplayData, SpecialUserData},
+        encoding::MessageDecoder,
+        messages::MAX_DATA_REQUESTS,
+        schema::shared,
+    };
+
+    fn roundtrip(msg: GameMessage) {
+        let mut buf = Vec::new();
+        msg.encode_to_vec(&mut buf).unwrap();
+
+        // the unpacked length includes the segment table, which is a single word for a single segment
+        let unpacked_len = ByteReader::new(&buf).read_varuint().unwrap() as usize;
+        assert!(unpacked_len <= msg.capacity() + WORD_SIZE, "{}", msg.name());
+
+        let mut scratch = Vec::new();
+        let reader = MessageDecoder::new()
+            .decode_game(&buf, &mut scratch)
+            .unwrap();
+        let decoded = GameMessage::from_reader(reader.get().unwrap()).unwrap();
+
+        assert_eq!(decoded, msg);
+    }
+
+    fn icons() -> PlayerIconData {
+        PlayerIconData {
+            cube: 42,
+            ship: -1,
+            color1: 12,
+            color2: 300,
+            death_effect: 255,
+            default_mini: true,
+            ..Default::default()
+        }
+    }
+
+    fn object(x: f32) -> PlayerObjectData {
+        PlayerObjectData {
+            position_x: x,
+            position_y: -x,
+            rotation: 90.0,
+            icon_type: shared::IconType::Ship,
+            is_visible: true,
+            is_looking_left: false,
+            is_upside_down: true,
+            is_dashing: false,
+            is_mini: true,
+            is_grounded: false,
+            is_stationary: false,
+            is_falling: true,
+            is_rotating: false,
+            is_sideways: false,
+            did_just_jump: true,
+            is_flipped: false,
+            is_holding: true,
+            ext_data: ExtendedPlayerData {
+                velocity_x: 1.5,
+                velocity_y: -2.5,
+                accelerating: true,
+                acceleration: 0.75,
+                fall_start_y: 300.0,
+                is_on_ground2: false,
+                gravity_mod: 1.0,
+                gravity: 0.958,
+                touched_pad: true,
+                maybe_falling: false,
+                fall_speed: 12.0,
+                is_on_ground4: true,
+            },
+        }
+    }
+
+    fn player(account_id: i32, objects: PlayerObjects) -> PlayerData {
+        PlayerData {
+            account_id,
+            timestamp: 12.5,
+            frame_number: 7,
+            death_count: 3,
+            percentage: 4521,
+            is_dead: false,
+            is_paused: true,
+            is_practicing: false,
+            is_in_editor: false,
+            is_editor_building: false,
+            is_last_death_real: true,
+            objects,
+        }
+    }
+
+    #[test]
+    fn login_roundtrip() {
+        roundtrip(GameMessage::Login(LoginMessage {
+            account_id: 7,
+            token: "token".into(),
+            icons: icons(),
+            settings: UserSettings {
+                hide_roles: true,
+                ..Default::default()
+            },
+            session_id: u64::MAX,
+            passcode: 1234,
+            platformer: true,
+            editor_collab: false,
+            event_dictionary: vec![1, 0, 0, 0, 14, 0, 0, 0],
+            protocol_version: 3,
+            capabilities: Capabilities::SUPPORTED,
+        }));
+
+        roundtrip(GameMessage::LoginOk(LoginOkMessage {
+            tickrate: 30,
+            capabilities: Capabilities::COMPRESSED_EVENTS,
+        }));
+        roundtrip(GameMessage::LoginFailed(LoginFailedMessage {
+            reason: schema::LoginFailedReason::OutdatedClient,
+        }));
+    }
+
+    #[test]
+    fn session_roundtrip() {
+        roundtrip(GameMessage::JoinSession(JoinSessionMessage {
+            session_id: 0x1234_5678_9abc,
+            passcode: 0,
+            platformer: false,
+            editor_collab: true,
+        }));
+        roundtrip(GameMessage::JoinSessionOk(JoinSessionOkMessage {
+            session_id: 0x1234_5678_9abc,
+        }));
+        roundtrip(GameMessage::JoinSessionFailed(JoinSessionFailedMessage {
+            reason: schema::JoinSessionFailedReason::InvalidPasscode,
+        }));
+        roundtrip(GameMessage::LeaveSession);
+    }
+
+    #[test]
+    fn player_data_roundtrip() {
+        for objects in [
+            PlayerObjects::Dual(object(10.0), object(20.0)),
+            PlayerObjects::Single(object(30.0)),
+            PlayerObjects::Culled,
+        ] {
+            roundtrip(GameMessage::PlayerData(PlayerDataMessage {
+                data: player(5, objects),
+                data_requests: vec![1, 2, -3],
+                event_data: vec![0xff; 10],
+                camera_x: 100.0,
+                camera_y: 200.0,
+                camera_radius: 50.0,
+                message_id: 9,
+            }));
+        }
+
+        roundtrip(GameMessage::PlayerUpdateMeta(PlayerUpdateMetaMessage {
+            meta: PlayerLevelMeta { progress: 8000 },
+            requests: vec![4, 5],
+        }));
+    }
+
+    #[test]
+    fn level_data_roundtrip() {
+        roundtrip(GameMessage::LevelData(LevelDataMessage {
+            players: vec![
+                player(1, PlayerObjects::Single(object(1.0))),
+                player(2, PlayerObjects::Culled),
+            ],
+            display_datas: vec![
+                PlayerDisplayData {
+                    account_id: 1,
+                    user_id: 11,
+                    username: "player1".try_into().unwrap(),
+                    icons: icons(),
+                    special_data: None,
+                },
+                PlayerDisplayData {
+                    account_id: 2,
+                    user_id: 22,
+                    username: "player2".try_into().unwrap(),
+                    icons: icons(),
+                    special_data: Some(SpecialUserData {
+                        roles: vec![1, 4],
+                        name_color: Some(MultiColor::Static(RGBColor::new(255, 0, 128))),
+                    }),
+                },
+            ],
+            event_data: Vec::new(),
+            message_id: 65535,
+        }));
+
+        roundtrip(GameMessage::LevelMeta(LevelMetaMessage {
+            ids: vec![1, 2],
+            metas: vec![
+                PlayerLevelMeta { progress: 0 },
+                PlayerLevelMeta { progress: 10000 },
+            ],
+        }));
+    }
+
+    #[test]
+    fn settings_roundtrip() {
+        roundtrip(GameMessage::UpdateIcons(UpdateIconsMessage {
+            icons: icons(),
+        }));
+        roundtrip(GameMessage::UpdateUserSettings(UpdateUserSettingsMessage {
+            settings: UserSettings {
+                hide_in_level: true,
+                friends_only_vc: true,
+                ..Default::default()
+            },
+        }));
+    }
+
+    #[test]
+    fn script_roundtrip() {
+        roundtrip(GameMessage::SendLevelScript(SendLevelScriptMessage {
+            scripts: vec![
+                LevelScript {
+                    content: "print('hi')".into(),
+                    filename: "main.lua".into(),
+                    main: true,
+                    signature: vec![7; 64],
+                },
+                LevelScript {
+                    content: String::new(),
+                    filename: "empty.lua".into(),
+                    main: false,
+                    signature: Vec::new(),
+                },
+            ],
+        }));
+
+        roundtrip(GameMessage::ScriptLogs(ScriptLogsMessage {
+            logs: vec!["first".into(), String::new(), "third".into()],
+            ram_usage: 1.25,
+        }));
+    }
+
+    #[test]
+    fn chat_roundtrip() {
+        let frames = vec![vec![0x55; 160], Vec::new(), (0..=255).collect()];
+
+        roundtrip(GameMessage::VoiceData(VoiceDataMessage {
+            frames: frames.clone(),
+        }));
+        roundtrip(GameMessage::VoiceBroadcast(VoiceBroadcastMessage {
+            account_id: 3,
+            frames,
+        }));
+        roundtrip(GameMessage::QuickChat(QuickChatMessage { id: 17 }));
+        roundtrip(GameMessage::QuickChatBroadcast(QuickChatBroadcastMessage {
+            account_id: 3,
+            id: 17,
+        }));
+        roundtrip(GameMessage::ChatNotPermitted(ChatNotPermittedMessage {
+            is_voice: true,
+            reason: schema::ChatNotPermittedReason::Muted,
+        }));
+    }
+
+    #[test]
+    fn misc_roundtrip() {
+        roundtrip(GameMessage::Kicked(KickedMessage {
+            reason: schema::KickReason::Custom,
+            message: "bye".into(),
+        }));
+        roundtrip(GameMessage::Events(vec![1, 2, 3, 4, 5]));
+        roundtrip(GameMessage::Events(Vec::new()));
+    }
+
+    /// Encodes the message into an arena of `arena` bytes, returning the amount of segments used
+    /// and the unpacked size of the message
+    fn encode_in_arena(msg: &GameMessage, arena: usize) -> (usize, usize) {
+        let mut arena = vec![0u8; arena];
+        let mut builder = builder_borrow(&mut arena);
+        msg.encode(builder.init_root());
+
+        let segments = builder.get_segments_for_output();
+        (segments.len(), segments.iter().map(|s| s.len()).sum())
+    }
+
+    #[test]
+    fn capacity_is_exact_at_limit() {
+        let msg = GameMessage::PlayerData(PlayerDataMessage {
+            data: player(1, PlayerObjects::Culled),
+            data_requests: (0..MAX_DATA_REQUESTS as i32).collect(),
+            ..Default::default()
+        });
+        let capacity = msg.capacity();
+
+        // the whole message fits in the computed capacity, without spilling into a fallback segment
+        assert_eq!(encode_in_arena(&msg, capacity), (1, capacity));
+
+        // a single word less is not enough
+        let (segments, _) = encode_in_arena(&msg, capacity - WORD_SIZE);
+        assert!(segments > 1);
+    }
+}
@@ -0,0 +1,1705 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    UserSettings,
+    data::{GameServerData, PlayerIconData, Punishment, UserRole, punishment_expired},
+    encoding::{
+        DataDecodeError, EncodeMessageError, PackStrategy, builder_borrow, encode_message_into,
+        size::*,
+    },
+    messages::RoomSettingsError,
+    protocol::{Capabilities, ProtocolMismatch, check_main_version},
+    schema::main::{self as schema, message::Which},
+};
+
+pub use crate::main_models::*;
+
+/// Conversions between `Punishment` and the messages notifying a user of one. The issuer is not
+/// sent to the punished user, so converting such a message into a `Punishment` leaves `issued_by`
+/// and `issued_at` at 0.
+macro_rules! impl_punishment_message {
+    ($name:ident) => {
+        impl $name {
+            pub fn is_expired_at(&self, now: i64) -> bool {
+                punishment_expired(self.expires_at, now)
+            }
+        }
+
+        impl From<&Punishment> for $name {
+            fn from(value: &Punishment) -> Self {
+                Self {
+                    reason: value.reason.clone(),
+                    expires_at: value.expires_at,
+                }
+            }
+        }
+
+        impl From<$name> for Punishment {
+            fn from(value: $name) -> Self {
+                Self {
+                    reason: value.reason,
+                    expires_at: value.expires_at,
+                    ..Default::default()
+                }
+            }
+        }
+    };
+}
+
+/// Authentication method used in `LoginMessage`
+#[derive(Clone, Debug, PartialEq)]
+pub enum LoginAuth {
+    Utoken(String),
+    Argon(String),
+    Plain(PlayerAccountData),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoginMessage {
+    pub account_id: i32,
+    pub icons: PlayerIconData,
+    pub uident: Vec<u8>,
+    pub settings: UserSettings,
+    pub auth: LoginAuth,
+    pub platform: schema::Platform,
+    pub geode_version: String,
+    pub globed_version: String,
+    pub platform_desc: String,
+    pub event_dictionary: Vec<u8>,
+    pub protocol_version: u16,
+    pub capabilities: Capabilities,
+}
+
+impl LoginMessage {
+    pub fn from_reader(reader: schema::login_message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            icons: PlayerIconData::from_reader(reader.get_icons()?)?,
+            uident: reader.get_uident()?.to_vec(),
+            settings: UserSettings::from_reader(reader.get_settings()?)?,
+            auth: match reader.which()? {
+                schema::login_message::Utoken(token) => {
+                    LoginAuth::Utoken(token?.to_str()?.to_owned())
+                }
+                schema::login_message::Argon(token) => {
+                    LoginAuth::Argon(token?.to_str()?.to_owned())
+                }
+                schema::login_message::Plain(data) => {
+                    LoginAuth::Plain(PlayerAccountData::from_reader(data?)?)
+                }
+            },
+            platform: reader.get_platform()?,
+            geode_version: reader.get_geode_version()?.to_str()?.to_owned(),
+            globed_version: reader.get_globed_version()?.to_str()?.to_owned(),
+            platform_desc: reader.get_platform_desc()?.to_str()?.to_owned(),
+            event_dictionary: reader.get_event_dictionary()?.to_vec(),
+            protocol_version: reader.get_protocol_version(),
+            capabilities: Capabilities::from_bits(reader.get_capabilities()),
+        })
+    }
+
+    pub fn encode(&self, mut builder: schema::login_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        self.icons.encode(builder.reborrow().init_icons());
+        builder.set_uident(&self.uident);
+        self.settings.encode(builder.reborrow().init_settings());
+
+        match &self.auth {
+            LoginAuth::Utoken(token) => builder.set_utoken(token.as_str()),
+            LoginAuth::Argon(token) => builder.set_argon(token.as_str()),
+            LoginAuth::Plain(data) => data.encode(builder.reborrow().init_plain()),
+        }
+
+        builder.set_platform(self.platform);
+        builder.set_geode_version(self.geode_version.as_str());
+        builder.set_globed_version(self.globed_version.as_str());
+        builder.set_platform_desc(self.platform_desc.as_str());
+        builder.set_event_dictionary(&self.event_dictionary);
+        builder.set_protocol_version(self.protocol_version);
+        builder.set_capabilities(self.capabilities.bits());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<schema::login_message::Builder<'static>>()
+            + self.icons.encoded_size()
+            + data_size(self.uident.len())
+            + self.settings.encoded_size()
+            + match &self.auth {
+                LoginAuth::Utoken(token) | LoginAuth::Argon(token) => text_size(token.len()),
+                LoginAuth::Plain(data) => data.encoded_size(),
+            }
+            + text_size(self.geode_version.len())
+            + text_size(self.globed_version.len())
+            + text_size(self.platform_desc.len())
+            + data_size(self.event_dictionary.len())
+    }
+
+    /// Checks that the client speaks a supported protocol version,
+    /// returning the capabilities supported by both sides, to be sent back in `LoginOkMessage`
+    pub fn negotiate(&self) -> Result<Capabilities, ProtocolMismatch> {
+        check_main_version(self.protocol_version)?;
+        Ok(Capabilities::SUPPORTED.negotiate(self.capabilities))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoginOkMessage {
+    pub servers: Vec<GameServerData>,
+    pub all_roles: Vec<UserRole>,
+    pub featured_level: i32,
+    pub featured_level_tier: u8,
+    pub featured_level_edition: u32,
+    pub user_data: ExtendedUserData,
+    pub capabilities: Capabilities,
+}
+
+impl LoginOkMessage {
+    pub fn from_reader(
+        reader: schema::login_ok_message::Reader<'_>,
+    ) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            servers: reader
+                .get_servers()?
+                .iter()
+                .map(GameServerData::from_reader)
+                .collect::<Result<_, _>>()?,
+            all_roles: reader
+                .get_all_roles()?
+                .iter()
+                .map(UserRole::from_reader)
+                .collect::<Result<_, _>>()?,
+            featured_level: reader.get_featured_level(),
+            featured_level_tier: reader.get_featured_level_tier(),
+            featured_level_edition: reader.get_featured_level_edition(),
+            user_data: ExtendedUserData::from_reader(reader.get_user_data()?)?,
+            capabilities: Capabilities::from_bits(reader.get_capabilities()),
+        })
+    }
+
+    pub fn encode(&self, mut builder: schema::login_ok_message::Builder<'_>) {
+        let mut servers = builder.reborrow().init_servers(self.servers.len() as u32);
+        for (i, v) in self.servers.iter().enumerate() {
+            v.encode(servers.reborrow().get(i as u32));
+        }
+
+        let mut all_roles = builder
+            .reborrow()
+            .init_all_roles(self.all_roles.len() as u32);
+        for (i, v) in self.all_roles.iter().enumerate() {
+            v.encode(all_roles.reborrow().get(i as u32));
+        }
+
+        builder.set_featured_level(self.featured_level);
+        builder.set_featured_level_tier(self.featured_level_tier);
+        builder.set_featured_level_edition(self.featured_level_edition);
+        self.user_data.encode(builder.reborrow().init_user_data());
+        builder.set_capabilities(self.capabilities.bits());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<schema::login_ok_message::Builder<'static>>()
+            + composite_list_size(self.servers.iter().map(GameServerData::encoded_size).sum())
+            + composite_list_size(self.all_roles.iter().map(UserRole::encoded_size).sum())
+            + self.user_data.encoded_size()
+    }
+}
+
+impl From<ProtocolMismatch> for LoginFailedMessage {
+    fn from(value: ProtocolMismatch) -> Self {
+        Self {
+            reason: value.main_reason(),
+        }
+    }
+}
+
+impl_punishment_message!(BannedMessage);
+
+impl_punishment_message!(MutedMessage);
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServersChangedMessage {
+    pub servers: Vec<GameServerData>,
+}
+
+impl ServersChangedMessage {
+    pub fn from_reader(
+        reader: schema::servers_changed_message::Reader<'_>,
+    ) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            servers: reader
+                .get_servers()?
+                .iter()
+                .map(GameServerData::from_reader)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn encode(&self, mut builder: schema::servers_changed_message::Builder<'_>) {
+        let mut servers = builder.reborrow().init_servers(self.servers.len() as u32);
+        for (i, v) in self.servers.iter().enumerate() {
+            v.encode(servers.reborrow().get(i as u32));
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<schema::servers_changed_message::Builder<'static>>()
+            + composite_list_size(self.servers.iter().map(GameServerData::encoded_size).sum())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdateOwnDataMessage {
+    pub icons: Option<PlayerIconData>,
+    pub friend_list: Option<Vec<i32>>,
+}
+
+impl UpdateOwnDataMessage {
+    pub fn from_reader(
+        reader: schema::update_own_data_message::Reader<'_>,
+    ) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            icons: if reader.has_icons() {
+                Some(PlayerIconData::from_reader(reader.get_icons()?)?)
+            } else {
+                None
+            },
+            friend_list: if reader.has_friend_list() {
+                Some(reader.get_friend_list()?.iter().collect())
+            } else {
+                None
+            },
+        })
+    }
+
+    pub fn encode(&self, mut builder: schema::update_own_data_message::Builder<'_>) {
+        if let Some(value) = &self.icons {
+            value.encode(builder.reborrow().init_icons());
+        }
+
+        if let Some(value) = &self.friend_list {
+            let mut friend_list = builder.reborrow().init_friend_list(value.len() as u32);
+            for (i, v) in value.iter().enumerate() {
+                friend_list.set(i as u32, *v);
+            }
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<schema::update_own_data_message::Builder<'static>>()
+            + self.icons.as_ref().map_or(0, |v| v.encoded_size())
+            + self
+                .friend_list
+                .as_ref()
+                .map_or(0, |v| list_size::<i32>(v.len()))
+    }
+}
+
+impl RoomSettingsError {
+    pub fn kind(&self) -> schema::RoomCreateFailedReason {
+        schema::RoomCreateFailedReason::InvalidSettings
+    }
+}
+
+impl From<&RoomSettingsError> for RoomCreateFailedMessage {
+    fn from(value: &RoomSettingsError) -> Self {
+        Self {
+            reason: value.kind(),
+        }
+    }
+}
+
+/// Settings of a room. Can also be deserialized from a config, in which case missing fields are
+/// left at their defaults, and should be validated before use just like ones sent by a client.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RoomSettings {
+    pub server_id: u8,
+    pub player_limit: u16,
+    pub faster_reset: bool,
+    pub hidden: bool,
+    pub private_invites: bool,
+    pub is_follower: bool,
+    pub level_integrity: bool,
+    pub teams: bool,
+    pub locked_teams: bool,
+    pub manual_pinning: bool,
+    pub collision: bool,
+    pub two_player_mode: bool,
+    pub deathlink: bool,
+    pub switcheroo: bool,
+}
+
+impl RoomSettings {
+    /// Checks that the flags are consistent with each other, should be done before creating a room or
+    /// applying new settings to an existing one. Which combinations of modes are allowed is up to the server.
+    pub fn validate(&self) -> Result<(), RoomSettingsError> {
+        if self.locked_teams && !self.teams {
+            return Err(RoomSettingsError::LockedTeamsWithoutTeams);
+        }
+
+        Ok(())
+    }
+
+    pub fn from_reader(reader: schema::room_settings::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            server_id: reader.get_server_id(),
+            player_limit: reader.get_player_limit(),
+            faster_reset: reader.get_faster_reset(),
+            hidden: reader.get_hidden(),
+            private_invites: reader.get_private_invites(),
+            is_follower: reader.get_is_follower(),
+            level_integrity: reader.get_level_integrity(),
+            teams: reader.get_teams(),
+            locked_teams: reader.get_locked_teams(),
+            manual_pinning: reader.get_manual_pinning(),
+            collision: reader.get_collision(),
+            two_player_mode: reader.get_two_player_mode(),
+            deathlink: reader.get_deathlink(),
+            switcheroo: reader.get_switcheroo(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: schema::room_settings::Builder<'_>) {
+        builder.set_server_id(self.server_id);
+        builder.set_player_limit(self.player_limit);
+        builder.set_faster_reset(self.faster_reset);
+        builder.set_hidden(self.hidden);
+        builder.set_private_invites(self.private_invites);
+        builder.set_is_follower(self.is_follower);
+        builder.set_level_integrity(self.level_integrity);
+        builder.set_teams(self.teams);
+        builder.set_locked_teams(self.locked_teams);
+        builder.set_manual_pinning(self.manual_pinning);
+        builder.set_collision(self.collision);
+        builder.set_two_player_mode(self.two_player_mode);
+        builder.set_deathlink(self.deathlink);
+        builder.set_switcheroo(self.switcheroo);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<schema::room_settings::Builder<'static>>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoomOwnerActionMessage {
+    pub action: schema::RoomOwnerActionType,
+    pub target: i32,
+}
+
+impl RoomOwnerActionMessage {
+    pub fn from_reader(
+        reader: schema::room_owner_action_message::Reader<'_>,
+    ) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            action: reader.get_type()?,
+            target: reader.get_target(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: schema::room_owner_action_message::Builder<'_>) {
+        builder.set_type(self.action);
+        builder.set_target(self.target);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<schema::room_owner_action_message::Builder<'static>>()
+    }
+}
+
+impl_punishment_message!(RoomBannedMessage);
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserStateMessage {
+    pub active_mute: Option<Punishment>,
+    pub active_room_ban: Option<Punishment>,
+}
+
+impl UserStateMessage {
+    pub fn from_reader(
+        reader: schema::user_state_message::Reader<'_>,
+    ) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            active_mute: if reader.has_active_mute() {
+                Some(Punishment::from_reader(reader.get_active_mute()?)?)
+            } else {
+                None
+            },
+            active_room_ban: if reader.has_active_room_ban() {
+                Some(Punishment::from_reader(reader.get_active_room_ban()?)?)
+            } else {
+                None
+            },
+        })
+    }
+
+    pub fn encode(&self, mut builder: schema::user_state_message::Builder<'_>) {
+        if let Some(value) = &self.active_mute {
+            value.encode(builder.reborrow().init_active_mute());
+        }
+
+        if let Some(value) = &self.active_room_ban {
+            value.encode(builder.reborrow().init_active_room_ban());
+        }
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<schema::user_state_message::Builder<'static>>()
+            + self.active_mute.as_ref().map_or(0, |v| v.encoded_size())
+            + self
+                .active_room_ban
+                .as_ref()
+                .map_or(0, |v| v.encoded_size())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminFetchResponseMessage {
+    pub account_id: i32,
+    pub found: bool,
+    pub whitelisted: bool,
+    pub roles: Vec<u8>,
+    pub active_ban: Option<Punishment>,
+    pub active_room_ban: Option<Punishment>,
+    pub active_mute: Option<Punishment>,
+    pub punishment_count: u32,
+}
+
+impl AdminFetchResponseMessage {
+    pub fn from_reader(
+        reader: schema::admin_fetch_response_message::Reader<'_>,
+    ) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            account_id: reader.get_account_id(),
+            found: reader.get_found(),
+            whitelisted: reader.get_whitelisted(),
+            roles: reader.get_roles()?.iter().collect(),
+            active_ban: if reader.has_active_ban() {
+                Some(Punishment::from_reader(reader.get_active_ban()?)?)
+            } else {
+                None
+            },
+            active_room_ban: if reader.has_active_room_ban() {
+                Some(Punishment::from_reader(reader.get_active_room_ban()?)?)
+            } else {
+                None
+            },
+            active_mute: if reader.has_active_mute() {
+                Some(Punishment::from_reader(reader.get_active_mute()?)?)
+            } else {
+                None
+            },
+            punishment_count: reader.get_punishment_count(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: schema::admin_fetch_response_message::Builder<'_>) {
+        builder.set_account_id(self.account_id);
+        builder.set_found(self.found);
+        builder.set_whitelisted(self.whitelisted);
+
+        let mut roles = builder.reborrow().init_roles(self.roles.len() as u32);
+        for (i, v) in self.roles.iter().enumerate() {
+            roles.set(i as u32, *v);
+        }
+
+        if let Some(value) = &self.active_ban {
+            value.encode(builder.reborrow().init_active_ban());
+        }
+
+        if let Some(value) = &self.active_room_ban {
+            value.encode(builder.reborrow().init_active_room_ban());
+        }
+
+        if let Some(value) = &self.active_mute {
+            value.encode(builder.reborrow().init_active_mute());
+        }
+
+        builder.set_punishment_count(self.punishment_count);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<schema::admin_fetch_response_message::Builder<'static>>()
+            + data_size(self.roles.len())
+            + self.active_ban.as_ref().map_or(0, |v| v.encoded_size())
+            + self
+                .active_room_ban
+                .as_ref()
+                .map_or(0, |v| v.encoded_size())
+            + self.active_mute.as_ref().map_or(0, |v| v.encoded_size())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminFetchLogsMessage {
+    pub issuer: i32,
+    pub target: i32,
+    pub log_type: String,
+    pub before: i64,
+    pub after: i64,
+    pub page: u32,
+}
+
+impl AdminFetchLogsMessage {
+    pub fn from_reader(
+        reader: schema::admin_fetch_logs_message::Reader<'_>,
+    ) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            issuer: reader.get_issuer(),
+            target: reader.get_target(),
+            log_type: reader.get_type()?.to_str()?.to_owned(),
+            before: reader.get_before(),
+            after: reader.get_after(),
+            page: reader.get_page(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: schema::admin_fetch_logs_message::Builder<'_>) {
+        builder.set_issuer(self.issuer);
+        builder.set_target(self.target);
+        builder.set_type(self.log_type.as_str());
+        builder.set_before(self.before);
+        builder.set_after(self.after);
+        builder.set_page(self.page);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<schema::admin_fetch_logs_message::Builder<'static>>()
+            + text_size(self.log_type.len())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditLog {
+    pub id: i32,
+    pub account_id: i32,
+    pub target_account_id: i32,
+    pub log_type: String,
+    pub timestamp: i64,
+    pub expires_at: i64,
+    pub message: String,
+}
+
+impl AuditLog {
+    pub fn from_reader(reader: schema::audit_log::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            id: reader.get_id(),
+            account_id: reader.get_account_id(),
+            target_account_id: reader.get_target_account_id(),
+            log_type: reader.get_type()?.to_str()?.to_owned(),
+            timestamp: reader.get_timestamp(),
+            expires_at: reader.get_expires_at(),
+            message: reader.get_message()?.to_str()?.to_owned(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: schema::audit_log::Builder<'_>) {
+        builder.set_id(self.id);
+        builder.set_account_id(self.account_id);
+        builder.set_target_account_id(self.target_account_id);
+        builder.set_type(self.log_type.as_str());
+        builder.set_timestamp(self.timestamp);
+        builder.set_expires_at(self.expires_at);
+        builder.set_message(self.message.as_str());
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<schema::audit_log::Builder<'static>>()
+            + text_size(self.log_type.len())
+            + text_size(self.message.len())
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum MainMessage {
+    Login(LoginMessage),
+    UpdateOwnData(UpdateOwnDataMessage),
+    RequestPlayerCounts(RequestPlayerCountsMessage),
+    RequestGlobalPlayerList(RequestGlobalPlayerListMessage),
+    UpdateUserSettings(UpdateUserSettingsMessage),
+    CreateRoom(CreateRoomMessage),
+    JoinRoom(JoinRoomMessage),
+    JoinRoomByToken(JoinRoomByTokenMessage),
+    LeaveRoom,
+    CheckRoomState,
+    RequestRoomPlayers(RequestRoomPlayersMessage),
+    RequestRoomList(RequestRoomListMessage),
+    AssignTeam(AssignTeamMessage),
+    CreateTeam(CreateTeamMessage),
+    DeleteTeam(DeleteTeamMessage),
+    UpdateTeam(UpdateTeamMessage),
+    GetTeamMembers,
+    RoomOwnerAction(RoomOwnerActionMessage),
+    UpdateRoomSettings(UpdateRoomSettingsMessage),
+    InvitePlayer(InvitePlayerMessage),
+    UpdatePinnedLevel(UpdatePinnedLevelMessage),
+    JoinSession(JoinSessionMessage),
+    LeaveSession,
+    RequestLevelList,
+    AdminLogin(AdminLoginMessage),
+    AdminKick(AdminKickMessage),
+    AdminNotice(AdminNoticeMessage),
+    AdminNoticeEveryone(AdminNoticeEveryoneMessage),
+    AdminFetchUser(AdminFetchUserMessage),
+    AdminFetchLogs(AdminFetchLogsMessage),
+    AdminBan(AdminBanMessage),
+    AdminUnban(AdminUnbanMessage),
+    AdminRoomBan(AdminRoomBanMessage),
+    AdminRoomUnban(AdminRoomUnbanMessage),
+    AdminMute(AdminMuteMessage),
+    AdminUnmute(AdminUnmuteMessage),
+    AdminEditRoles(AdminEditRolesMessage),
+    AdminSetPassword(AdminSetPasswordMessage),
+    AdminUpdateUser(AdminUpdateUserMessage),
+    AdminFetchMods,
+    AdminSetWhitelisted(AdminSetWhitelistedMessage),
+    AdminCloseRoom(AdminCloseRoomMessage),
+    FetchCredits,
+    GetUserState,
+    GetDiscordLinkState,
+    SetDiscordPairingState(SetDiscordPairingStateMessage),
+    DiscordLinkConfirm(DiscordLinkConfirmMessage),
+    RequestDiscordOauth,
+    RequestDiscordUnlink,
+    GetFeaturedLevel,
+    GetFeaturedList(GetFeaturedListMessage),
+    SendFeaturedLevel(SendFeaturedLevelMessage),
+    NoticeReply(NoticeReplyMessage),
+    FetchUser(FetchUserMessage),
+    LoginOk(LoginOkMessage),
+    LoginFailed(LoginFailedMessage),
+    LoginRequired(LoginRequiredMessage),
+    Banned(BannedMessage),
+    Muted(MutedMessage),
+    ServersChanged(ServersChangedMessage),
+    UserDataChanged(UserDataChangedMessage),
+    RoomState(RoomStateMessage),
+    RoomPlayers(RoomPlayersMessage),
+    RoomJoinFailed(RoomJoinFailedMessage),
+    RoomCreateFailed(RoomCreateFailedMessage),
+    RoomBanned(RoomBannedMessage),
+    RoomList(RoomListMessage),
+    TeamCreationResult(TeamCreationResultMessage),
+    TeamChanged(TeamChangedMessage),
+    TeamMembers(TeamMembersMessage),
+    TeamsUpdated(TeamsUpdatedMessage),
+    RoomSettingsUpdated(RoomSettingsUpdatedMessage),
+    PinnedLevelUpdated(PinnedLevelUpdatedMessage),
+    Invited(InvitedMessage),
+    InviteTokenCreated(InviteTokenCreatedMessage),
+    JoinFailed(JoinFailedMessage),
+    WarpPlayer(WarpPlayerMessage),
+    RoomWarp(RoomWarpMessage),
+    PlayerCounts(PlayerCountsMessage),
+    GlobalPlayers(GlobalPlayersMessage),
+    LevelList(LevelListMessage),
+    Kicked(KickedMessage),
+    Notice(NoticeMessage),
+    Warn(WarnMessage),
+    NoticeReplyResult(NoticeReplyResultMessage),
+    AdminResult(AdminResultMessage),
+    AdminFetchResponse(AdminFetchResponseMessage),
+    AdminFetchModsResponse(AdminFetchModsResponseMessage),
+    AdminLogsResponse(AdminLogsResponseMessage),
+    AdminPunishmentReasons(AdminPunishmentReasonsMessage),
+    Credits(CreditsMessage),
+    UserState(UserStateMessage),
+    DiscordLinkState(DiscordLinkStateMessage),
+    DiscordLinkAttempt(DiscordLinkAttemptMessage),
+    DiscordOauthUrl(DiscordOauthUrlMessage),
+    DiscordUnlinkResult(DiscordUnlinkResultMessage),
+    FeaturedLevel(FeaturedLevelMessage),
+    FeaturedList(FeaturedListMessage),
+    FetchUserResponse(FetchUserResponseMessage),
+    Events(Vec<u8>),
+}
+
+impl MainMessage {
+    pub fn from_reader(reader: schema::message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(match reader.which()? {
+            Which::Login(msg) => Self::Login(LoginMessage::from_reader(msg?)?),
+            Which::UpdateOwnData(msg) => {
+                Self::UpdateOwnData(UpdateOwnDataMessage::from_reader(msg?)?)
+            }
+            Which::RequestPlayerCounts(msg) => {
+                Self::RequestPlayerCounts(RequestPlayerCountsMessage::from_reader(msg?)?)
+            }
+            Which::RequestGlobalPlayerList(msg) => {
+                Self::RequestGlobalPlayerList(RequestGlobalPlayerListMessage::from_reader(msg?)?)
+            }
+            Which::UpdateUserSettings(msg) => {
+                Self::UpdateUserSettings(UpdateUserSettingsMessage::from_reader(msg?)?)
+            }
+            Which::CreateRoom(msg) => Self::CreateRoom(CreateRoomMessage::from_reader(msg?)?),
+            Which::JoinRoom(msg) => Self::JoinRoom(JoinRoomMessage::from_reader(msg?)?),
+            Which::JoinRoomByToken(msg) => {
+                Self::JoinRoomByToken(JoinRoomByTokenMessage::from_reader(msg?)?)
+            }
+            Which::LeaveRoom(()) => Self::LeaveRoom,
+            Which::CheckRoomState(()) => Self::CheckRoomState,
+            Which::RequestRoomPlayers(msg) => {
+                Self::RequestRoomPlayers(RequestRoomPlayersMessage::from_reader(msg?)?)
+            }
+            Which::RequestRoomList(msg) => {
+                Self::RequestRoomList(RequestRoomListMessage::from_reader(msg?)?)
+            }
+            Which::AssignTeam(msg) => Self::AssignTeam(AssignTeamMessage::from_reader(msg?)?),
+            Which::CreateTeam(msg) => Self::CreateTeam(CreateTeamMessage::from_reader(msg?)?),
+            Which::DeleteTeam(msg) => Self::DeleteTeam(DeleteTeamMessage::from_reader(msg?)?),
+            Which::UpdateTeam(msg) => Self::UpdateTeam(UpdateTeamMessage::from_reader(msg?)?),
+            Which::GetTeamMembers(_) => Self::GetTeamMembers,
+            Which::RoomOwnerAction(msg) => {
+                Self::RoomOwnerAction(RoomOwnerActionMessage::from_reader(msg?)?)
+            }
+            Which::UpdateRoomSettings(msg) => {
+                Self::UpdateRoomSettings(UpdateRoomSettingsMessage::from_reader(msg?)?)
+            }
+            Which::InvitePlayer(msg) => Self::InvitePlayer(InvitePlayerMessage::from_reader(msg?)?),
+            Which::UpdatePinnedLevel(msg) => {
+                Self::UpdatePinnedLevel(UpdatePinnedLevelMessage::from_reader(msg?)?)
+            }
+            Which::JoinSession(msg) => Self::JoinSession(JoinSessionMessage::from_reader(msg?)?),
+            Which::LeaveSession(_) => Self::LeaveSession,
+            Which::RequestLevelList(_) => Self::RequestLevelList,
+            Which::AdminLogin(msg) => Self::AdminLogin(AdminLoginMessage::from_reader(msg?)?),
+            Which::AdminKick(msg) => Self::AdminKick(AdminKickMessage::from_reader(msg?)?),
+            Which::AdminNotice(msg) => Self::AdminNotice(AdminNoticeMessage::from_reader(msg?)?),
+            Which::AdminNoticeEveryone(msg) => {
+                Self::AdminNoticeEveryone(AdminNoticeEveryoneMessage::from_reader(msg?)?)
+            }
+            Which::AdminFetchUser(msg) => {
+                Self::AdminFetchUser(AdminFetchUserMessage::from_reader(msg?)?)
+            }
+            Which::AdminFetchLogs(msg) => {
+                Self::AdminFetchLogs(AdminFetchLogsMessage::from_reader(msg?)?)
+            }
+            Which::AdminBan(msg) => Self::AdminBan(AdminBanMessage::from_reader(msg?)?),
+            Which::AdminUnban(msg) => Self::AdminUnban(AdminUnbanMessage::from_reader(msg?)?),
+            Which::AdminRoomBan(msg) => Self::AdminRoomBan(AdminRoomBanMessage::from_reader(msg?)?),
+            Which::AdminRoomUnban(msg) => {
+                Self::AdminRoomUnban(AdminRoomUnbanMessage::from_reader(msg?)?)
+            }
+            Which::AdminMute(msg) => Self::AdminMute(AdminMuteMessage::from_reader(msg?)?),
+            Which::AdminUnmute(msg) => Self::AdminUnmute(AdminUnmuteMessage::from_reader(msg?)?),
+            Which::AdminEditRoles(msg) => {
+                Self::AdminEditRoles(AdminEditRolesMessage::from_reader(msg?)?)
+            }
+            Which::AdminSetPassword(msg) => {
+                Self::AdminSetPassword(AdminSetPasswordMessage::from_reader(msg?)?)
+            }
+            Which::AdminUpdateUser(msg) => {
+                Self::AdminUpdateUser(AdminUpdateUserMessage::from_reader(msg?)?)
+            }
+            Which::AdminFetchMods(_) => Self::AdminFetchMods,
+            Which::AdminSetWhitelisted(msg) => {
+                Self::AdminSetWhitelisted(AdminSetWhitelistedMessage::from_reader(msg?)?)
+            }
+            Which::AdminCloseRoom(msg) => {
+                Self::AdminCloseRoom(AdminCloseRoomMessage::from_reader(msg?)?)
+            }
+            Which::FetchCredits(_) => Self::FetchCredits,
+            Which::GetUserState(()) => Self::GetUserState,
+            Which::GetDiscordLinkState(_) => Self::GetDiscordLinkState,
+            Which::SetDiscordPairingState(msg) => {
+                Self::SetDiscordPairingState(SetDiscordPairingStateMessage::from_reader(msg?)?)
+            }
+            Which::DiscordLinkConfirm(msg) => {
+                Self::DiscordLinkConfirm(DiscordLinkConfirmMessage::from_reader(msg?)?)
+            }
+            Which::RequestDiscordOauth(()) => Self::RequestDiscordOauth,
+            Which::RequestDiscordUnlink(()) => Self::RequestDiscordUnlink,
+            Which::GetFeaturedLevel(()) => Self::GetFeaturedLevel,
+            Which::GetFeaturedList(msg) => {
+                Self::GetFeaturedList(GetFeaturedListMessage::from_reader(msg?)?)
+            }
+            Which::SendFeaturedLevel(msg) => {
+                Self::SendFeaturedLevel(SendFeaturedLevelMessage::from_reader(msg?)?)
+            }
+            Which::NoticeReply(msg) => Self::NoticeReply(NoticeReplyMessage::from_reader(msg?)?),
+            Which::FetchUser(msg) => Self::FetchUser(FetchUserMessage::from_reader(msg?)?),
+            Which::LoginOk(msg) => Self::LoginOk(LoginOkMessage::from_reader(msg?)?),
+            Which::LoginFailed(msg) => Self::LoginFailed(LoginFailedMessage::from_reader(msg?)?),
+            Which::LoginRequired(msg) => {
+                Self::LoginRequired(LoginRequiredMessage::from_reader(msg?)?)
+            }
+            Which::Banned(msg) => Self::Banned(BannedMessage::from_reader(msg?)?),
+            Which::Muted(msg) => Self::Muted(MutedMessage::from_reader(msg?)?),
+            Which::ServersChanged(msg) => {
+                Self::ServersChanged(ServersChangedMessage::from_reader(msg?)?)
+            }
+            Which::UserDataChanged(msg) => {
+                Self::UserDataChanged(UserDataChangedMessage::from_reader(msg?)?)
+            }
+            Which::RoomState(msg) => Self::RoomState(RoomStateMessage::from_reader(msg?)?),
+            Which::RoomPlayers(msg) => Self::RoomPlayers(RoomPlayersMessage::from_reader(msg?)?),
+            Which::RoomJoinFailed(msg) => {
+                Self::RoomJoinFailed(RoomJoinFailedMessage::from_reader(msg?)?)
+            }
+            Which::RoomCreateFailed(msg) => {
+                Self::RoomCreateFailed(RoomCreateFailedMessage::from_reader(msg?)?)
+            }
+            Which::RoomBanned(msg) => Self::RoomBanned(RoomBannedMessage::from_reader(msg?)?),
+            Which::RoomList(msg) => Self::RoomList(RoomListMessage::from_reader(msg?)?),
+            Which::TeamCreationResult(msg) => {
+                Self::TeamCreationResult(TeamCreationResultMessage::from_reader(msg?)?)
+            }
+            Which::TeamChanged(msg) => Self::TeamChanged(TeamChangedMessage::from_reader(msg?)?),
+            Which::TeamMembers(msg) => Self::TeamMembers(TeamMembersMessage::from_reader(msg?)?),
+            Which::TeamsUpdated(msg) => Self::TeamsUpdated(TeamsUpdatedMessage::from_reader(msg?)?),
+            Which::RoomSettingsUpdated(msg) => {
+                Self::RoomSettingsUpdated(RoomSettingsUpdatedMessage::from_reader(msg?)?)
+            }
+            Which::PinnedLevelUpdated(msg) => {
+                Self::PinnedLevelUpdated(PinnedLevelUpdatedMessage::from_reader(msg?)?)
+            }
+            Which::Invited(msg) => Self::Invited(InvitedMessage::from_reader(msg?)?),
+            Which::InviteTokenCreated(msg) => {
+                Self::InviteTokenCreated(InviteTokenCreatedMessage::from_reader(msg?)?)
+            }
+            Which::JoinFailed(msg) => Self::JoinFailed(JoinFailedMessage::from_reader(msg?)?),
+            Which::WarpPlayer(msg) => Self::WarpPlayer(WarpPlayerMessage::from_reader(msg?)?),
+            Which::RoomWarp(msg) => Self::RoomWarp(RoomWarpMessage::from_reader(msg?)?),
+            Which::PlayerCounts(msg) => Self::PlayerCounts(PlayerCountsMessage::from_reader(msg?)?),
+            Which::GlobalPlayers(msg) => {
+                Self::GlobalPlayers(GlobalPlayersMessage::from_reader(msg?)?)
+            }
+            Which::LevelList(msg) => Self::LevelList(LevelListMessage::from_reader(msg?)?),
+            Which::Kicked(msg) => Self::Kicked(KickedMessage::from_reader(msg?)?),
+            Which::Notice(msg) => Self::Notice(NoticeMessage::from_reader(msg?)?),
+            Which::Warn(msg) => Self::Warn(WarnMessage::from_reader(msg?)?),
+            Which::NoticeReplyResult(msg) => {
+                Self::NoticeReplyResult(NoticeReplyResultMessage::from_reader(msg?)?)
+            }
+            Which::AdminResult(msg) => Self::AdminResult(AdminResultMessage::from_reader(msg?)?),
+            Which::AdminFetchResponse(msg) => {
+                Self::AdminFetchResponse(AdminFetchResponseMessage::from_reader(msg?)?)
+            }
+            Which::AdminFetchModsResponse(msg) => {
+                Self::AdminFetchModsResponse(AdminFetchModsResponseMessage::from_reader(msg?)?)
+            }
+            Which::AdminLogsResponse(msg) => {
+                Self::AdminLogsResponse(AdminLogsResponseMessage::from_reader(msg?)?)
+            }
+            Which::AdminPunishmentReasons(msg) => {
+                Self::AdminPunishmentReasons(AdminPunishmentReasonsMessage::from_reader(msg?)?)
+            }
+            Which::Credits(msg) => Self::Credits(CreditsMessage::from_reader(msg?)?),
+            Which::UserState(msg) => Self::UserState(UserStateMessage::from_reader(msg?)?),
+            Which::DiscordLinkState(msg) => {
+                Self::DiscordLinkState(DiscordLinkStateMessage::from_reader(msg?)?)
+            }
+            Which::DiscordLinkAttempt(msg) => {
+                Self::DiscordLinkAttempt(DiscordLinkAttemptMessage::from_reader(msg?)?)
+            }
+            Which::DiscordOauthUrl(msg) => {
+                Self::DiscordOauthUrl(DiscordOauthUrlMessage::from_reader(msg?)?)
+            }
+            Which::DiscordUnlinkResult(msg) => {
+                Self::DiscordUnlinkResult(DiscordUnlinkResultMessage::from_reader(msg?)?)
+            }
+            Which::FeaturedLevel(msg) => {
+                Self::FeaturedLevel(FeaturedLevelMessage::from_reader(msg?)?)
+            }
+            Which::FeaturedList(msg) => Self::FeaturedList(FeaturedListMessage::from_reader(msg?)?),
+            Which::FetchUserResponse(msg) => {
+                Self::FetchUserResponse(FetchUserResponseMessage::from_reader(msg?)?)
+            }
+            Which::Events(data) => Self::Events(data?.to_vec()),
+        })
+    }
+
+    pub fn encode(&self, mut builder: schema::message::Builder<'_>) {
+        match self {
+            Self::Login(msg) => msg.encode(builder.init_login()),
+            Self::UpdateOwnData(msg) => msg.encode(builder.init_update_own_data()),
+            Self::RequestPlayerCounts(msg) => msg.encode(builder.init_request_player_counts()),
+            Self::RequestGlobalPlayerList(msg) => {
+                msg.encode(builder.init_request_global_player_list())
+            }
+            Self::UpdateUserSettings(msg) => msg.encode(builder.init_update_user_settings()),
+            Self::CreateRoom(msg) => msg.encode(builder.init_create_room()),
+            Self::JoinRoom(msg) => msg.encode(builder.init_join_room()),
+            Self::JoinRoomByToken(msg) => msg.encode(builder.init_join_room_by_token()),
+            Self::LeaveRoom => builder.set_leave_room(()),
+            Self::CheckRoomState => builder.set_check_room_state(()),
+            Self::RequestRoomPlayers(msg) => msg.encode(builder.init_request_room_players()),
+            Self::RequestRoomList(msg) => msg.encode(builder.init_request_room_list()),
+            Self::AssignTeam(msg) => msg.encode(builder.init_assign_team()),
+            Self::CreateTeam(msg) => msg.encode(builder.init_create_team()),
+            Self::DeleteTeam(msg) => msg.encode(builder.init_delete_team()),
+            Self::UpdateTeam(msg) => msg.encode(builder.init_update_team()),
+            Self::GetTeamMembers => {
+                builder.init_get_team_members();
+            }
+            Self::RoomOwnerAction(msg) => msg.encode(builder.init_room_owner_action()),
+            Self::UpdateRoomSettings(msg) => msg.encode(builder.init_update_room_settings()),
+            Self::InvitePlayer(msg) => msg.encode(builder.init_invite_player()),
+            Self::UpdatePinnedLevel(msg) => msg.encode(builder.init_update_pinned_level()),
+            Self::JoinSession(msg) => msg.encode(builder.init_join_session()),
+            Self::LeaveSession => {
+                builder.init_leave_session();
+            }
+            Self::RequestLevelList => {
+                builder.init_request_level_list();
+            }
+            Self::AdminLogin(msg) => msg.encode(builder.init_admin_login()),
+            Self::AdminKick(msg) => msg.encode(builder.init_admin_kick()),
+            Self::AdminNotice(msg) => msg.encode(builder.init_admin_notice()),
+            Self::AdminNoticeEveryone(msg) => msg.encode(builder.init_admin_notice_everyone()),
+            Self::AdminFetchUser(msg) => msg.encode(builder.init_admin_fetch_user()),
+            Self::AdminFetchLogs(msg) => msg.encode(builder.init_admin_fetch_logs()),
+            Self::AdminBan(msg) => msg.encode(builder.init_admin_ban()),
+            Self::AdminUnban(msg) => msg.encode(builder.init_admin_unban()),
+            Self::AdminRoomBan(msg) => msg.encode(builder.init_admin_room_ban()),
+            Self::AdminRoomUnban(msg) => msg.encode(builder.init_admin_room_unban()),
+            Self::AdminMute(msg) => msg.encode(builder.init_admin_mute()),
+            Self::AdminUnmute(msg) => msg.encode(builder.init_admin_unmute()),
+            Self::AdminEditRoles(msg) => msg.encode(builder.init_admin_edit_roles()),
+            Self::AdminSetPassword(msg) => msg.encode(builder.init_admin_set_password()),
+            Self::AdminUpdateUser(msg) => msg.encode(builder.init_admin_update_user()),
+            Self::AdminFetchMods => {
+                builder.init_admin_fetch_mods();
+            }
+            Self::AdminSetWhitelisted(msg) => msg.encode(builder.init_admin_set_whitelisted()),
+            Self::AdminCloseRoom(msg) => msg.encode(builder.init_admin_close_room()),
+            Self::FetchCredits => {
+                builder.init_fetch_credits();
+            }
+            Self::GetUserState => builder.set_get_user_state(()),
+            Self::GetDiscordLinkState => {
+                builder.init_get_discord_link_state();
+            }
+            Self::SetDiscordPairingState(msg) => {
+                msg.encode(builder.init_set_discord_pairing_state())
+            }
+            Self::DiscordLinkConfirm(msg) => msg.encode(builder.init_discord_link_confirm()),
+            Self::RequestDiscordOauth => builder.set_request_discord_oauth(()),
+            Self::RequestDiscordUnlink => builder.set_request_discord_unlink(()),
+            Self::GetFeaturedLevel => builder.set_get_featured_level(()),
+            Self::GetFeaturedList(msg) => msg.encode(builder.init_get_featured_list()),
+            Self::SendFeaturedLevel(msg) => msg.encode(builder.init_send_featured_level()),
+            Self::NoticeReply(msg) => msg.encode(builder.init_notice_reply()),
+            Self::FetchUser(msg) => msg.encode(builder.init_fetch_user()),
+            Self::LoginOk(msg) => msg.encode(builder.init_login_ok()),
+            Self::LoginFailed(msg) => msg.encode(builder.init_login_failed()),
+            Self::LoginRequired(msg) => msg.encode(builder.init_login_required()),
+            Self::Banned(msg) => msg.encode(builder.init_banned()),
+            Self::Muted(msg) => msg.encode(builder.init_muted()),
+            Self::ServersChanged(msg) => msg.encode(builder.init_servers_changed()),
+            Self::UserDataChanged(msg) => msg.encode(builder.init_user_data_changed()),
+            Self::RoomState(msg) => msg.encode(builder.init_room_state()),
+            Self::RoomPlayers(msg) => msg.encode(builder.init_room_players()),
+            Self::RoomJoinFailed(msg) => msg.encode(builder.init_room_join_failed()),
+            Self::RoomCreateFailed(msg) => msg.encode(builder.init_room_create_failed()),
+            Self::RoomBanned(msg) => msg.encode(builder.init_room_banned()),
+            Self::RoomList(msg) => msg.encode(builder.init_room_list()),
+            Self::TeamCreationResult(msg) => msg.encode(builder.init_team_creation_result()),
+            Self::TeamChanged(msg) => msg.encode(builder.init_team_changed()),
+            Self::TeamMembers(msg) => msg.encode(builder.init_team_members()),
+            Self::TeamsUpdated(msg) => msg.encode(builder.init_teams_updated()),
+            Self::RoomSettingsUpdated(msg) => msg.encode(builder.init_room_settings_updated()),
+            Self::PinnedLevelUpdated(msg) => msg.encode(builder.init_pinned_level_updated()),
+            Self::Invited(msg) => msg.encode(builder.init_invited()),
+            Self::InviteTokenCreated(msg) => msg.encode(builder.init_invite_token_created()),
+            Self::JoinFailed(msg) => msg.encode(builder.init_join_failed()),
+            Self::WarpPlayer(msg) => msg.encode(builder.init_warp_player()),
+            Self::RoomWarp(msg) => msg.encode(builder.init_room_warp()),
+            Self::PlayerCounts(msg) => msg.encode(builder.init_player_counts()),
+            Self::GlobalPlayers(msg) => msg.encode(builder.init_global_players()),
+            Self::LevelList(msg) => msg.encode(builder.init_level_list()),
+            Self::Kicked(msg) => msg.encode(builder.init_kicked()),
+            Self::Notice(msg) => msg.encode(builder.init_notice()),
+            Self::Warn(msg) => msg.encode(builder.init_warn()),
+            Self::NoticeReplyResult(msg) => msg.encode(builder.init_notice_reply_result()),
+            Self::AdminResult(msg) => msg.encode(builder.init_admin_result()),
+            Self::AdminFetchResponse(msg) => msg.encode(builder.init_admin_fetch_response()),
+            Self::AdminFetchModsResponse(msg) => {
+                msg.encode(builder.init_admin_fetch_mods_response())
+            }
+            Self::AdminLogsResponse(msg) => msg.encode(builder.init_admin_logs_response()),
+            Self::AdminPunishmentReasons(msg) => {
+                msg.encode(builder.init_admin_punishment_reasons())
+            }
+            Self::Credits(msg) => msg.encode(builder.init_credits()),
+            Self::UserState(msg) => msg.encode(builder.init_user_state()),
+            Self::DiscordLinkState(msg) => msg.encode(builder.init_discord_link_state()),
+            Self::DiscordLinkAttempt(msg) => msg.encode(builder.init_discord_link_attempt()),
+            Self::DiscordOauthUrl(msg) => msg.encode(builder.init_discord_oauth_url()),
+            Self::DiscordUnlinkResult(msg) => msg.encode(builder.init_discord_unlink_result()),
+            Self::FeaturedLevel(msg) => msg.encode(builder.init_featured_level()),
+            Self::FeaturedList(msg) => msg.encode(builder.init_featured_list()),
+            Self::FetchUserResponse(msg) => msg.encode(builder.init_fetch_user_response()),
+            Self::Events(data) => builder.set_events(data),
+        }
+    }
+
+    /// Upper bound for the size of this message in a capnp arena, in bytes, not including the root pointer
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<schema::message::Builder<'static>>()
+            + match self {
+                Self::Login(msg) => msg.encoded_size(),
+                Self::UpdateOwnData(msg) => msg.encoded_size(),
+                Self::RequestPlayerCounts(msg) => msg.encoded_size(),
+                Self::RequestGlobalPlayerList(msg) => msg.encoded_size(),
+                Self::UpdateUserSettings(msg) => msg.encoded_size(),
+                Self::CreateRoom(msg) => msg.encoded_size(),
+                Self::JoinRoom(msg) => msg.encoded_size(),
+                Self::JoinRoomByToken(msg) => msg.encoded_size(),
+                Self::RequestRoomPlayers(msg) => msg.encoded_size(),
+                Self::RequestRoomList(msg) => msg.encoded_size(),
+                Self::AssignTeam(msg) => msg.encoded_size(),
+                Self::CreateTeam(msg) => msg.encoded_size(),
+                Self::DeleteTeam(msg) => msg.encoded_size(),
+                Self::UpdateTeam(msg) => msg.encoded_size(),
+                Self::RoomOwnerAction(msg) => msg.encoded_size(),
+                Self::UpdateRoomSettings(msg) => msg.encoded_size(),
+                Self::InvitePlayer(msg) => msg.encoded_size(),
+                Self::UpdatePinnedLevel(msg) => msg.encoded_size(),
+                Self::JoinSession(msg) => msg.encoded_size(),
+                Self::AdminLogin(msg) => msg.encoded_size(),
+                Self::AdminKick(msg) => msg.encoded_size(),
+                Self::AdminNotice(msg) => msg.encoded_size(),
+                Self::AdminNoticeEveryone(msg) => msg.encoded_size(),
+                Self::AdminFetchUser(msg) => msg.encoded_size(),
+                Self::AdminFetchLogs(msg) => msg.encoded_size(),
+                Self::AdminBan(msg) => msg.encoded_size(),
+                Self::AdminUnban(msg) => msg.encoded_size(),
+                Self::AdminRoomBan(msg) => msg.encoded_size(),
+                Self::AdminRoomUnban(msg) => msg.encoded_size(),
+                Self::AdminMute(msg) => msg.encoded_size(),
+                Self::AdminUnmute(msg) => msg.encoded_size(),
+                Self::AdminEditRoles(msg) => msg.encoded_size(),
+                Self::AdminSetPassword(msg) => msg.encoded_size(),
+                Self::AdminUpdateUser(msg) => msg.encoded_size(),
+                Self::AdminSetWhitelisted(msg) => msg.encoded_size(),
+                Self::AdminCloseRoom(msg) => msg.encoded_size(),
+                Self::SetDiscordPairingState(msg) => msg.encoded_size(),
+                Self::DiscordLinkConfirm(msg) => msg.encoded_size(),
+                Self::GetFeaturedList(msg) => msg.encoded_size(),
+                Self::SendFeaturedLevel(msg) => msg.encoded_size(),
+                Self::NoticeReply(msg) => msg.encoded_size(),
+                Self::FetchUser(msg) => msg.encoded_size(),
+                Self::LoginOk(msg) => msg.encoded_size(),
+                Self::LoginFailed(msg) => msg.encoded_size(),
+                Self::LoginRequired(msg) => msg.encoded_size(),
+                Self::Banned(msg) => msg.encoded_size(),
+                Self::Muted(msg) => msg.encoded_size(),
+                Self::ServersChanged(msg) => msg.encoded_size(),
+                Self::UserDataChanged(msg) => msg.encoded_size(),
+                Self::RoomState(msg) => msg.encoded_size(),
+                Self::RoomPlayers(msg) => msg.encoded_size(),
+                Self::RoomJoinFailed(msg) => msg.encoded_size(),
+                Self::RoomCreateFailed(msg) => msg.encoded_size(),
+                Self::RoomBanned(msg) => msg.encoded_size(),
+                Self::RoomList(msg) => msg.encoded_size(),
+                Self::TeamCreationResult(msg) => msg.encoded_size(),
+                Self::TeamChanged(msg) => msg.encoded_size(),
+                Self::TeamMembers(msg) => msg.encoded_size(),
+                Self::TeamsUpdated(msg) => msg.encoded_size(),
+                Self::RoomSettingsUpdated(msg) => msg.encoded_size(),
+                Self::PinnedLevelUpdated(msg) => msg.encoded_size(),
+                Self::Invited(msg) => msg.encoded_size(),
+                Self::InviteTokenCreated(msg) => msg.encoded_size(),
+                Self::JoinFailed(msg) => msg.encoded_size(),
+                Self::WarpPlayer(msg) => msg.encoded_size(),
+                Self::RoomWarp(msg) => msg.encoded_size(),
+                Self::PlayerCounts(msg) => msg.encoded_size(),
+                Self::GlobalPlayers(msg) => msg.encoded_size(),
+                Self::LevelList(msg) => msg.encoded_size(),
+                Self::Kicked(msg) => msg.encoded_size(),
+                Self::Notice(msg) => msg.encoded_size(),
+                Self::Warn(msg) => msg.encoded_size(),
+                Self::NoticeReplyResult(msg) => msg.encoded_size(),
+                Self::AdminResult(msg) => msg.encoded_size(),
+                Self::AdminFetchResponse(msg) => msg.encoded_size(),
+                Self::AdminFetchModsResponse(msg) => msg.encoded_size(),
+                Self::AdminLogsResponse(msg) => msg.encoded_size(),
+                Self::AdminPunishmentReasons(msg) => msg.encoded_size(),
+                Self::Credits(msg) => msg.encoded_size(),
+                Self::UserState(msg) => msg.encoded_size(),
+                Self::DiscordLinkState(msg) => msg.encoded_size(),
+                Self::DiscordLinkAttempt(msg) => msg.encoded_size(),
+                Self::DiscordOauthUrl(msg) => msg.encoded_size(),
+                Self::DiscordUnlinkResult(msg) => msg.encoded_size(),
+                Self::FeaturedLevel(msg) => msg.encoded_size(),
+                Self::FeaturedList(msg) => msg.encoded_size(),
+                Self::FetchUserResponse(msg) => msg.encoded_size(),
+                Self::Events(data) => data_size(data.len()),
+                Self::LeaveRoom
+                | Self::CheckRoomState
+                | Self::GetTeamMembers
+                | Self::LeaveSession
+                | Self::RequestLevelList
+                | Self::AdminFetchMods
+                | Self::FetchCredits
+                | Self::GetUserState
+                | Self::GetDiscordLinkState
+                | Self::RequestDiscordOauth
+                | Self::RequestDiscordUnlink
+                | Self::GetFeaturedLevel => 0,
+            }
+    }
+
+    /// Arena capacity needed to encode this message, used by `encode_message!` when given an owned message
+    pub fn capacity(&self) -> usize {
+        message_capacity(self.encoded_size())
+    }
+
+    /// Name of the message variant, as in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Login(_) => "Login",
+            Self::UpdateOwnData(_) => "UpdateOwnData",
+            Self::RequestPlayerCounts(_) => "RequestPlayerCounts",
+            Self::RequestGlobalPlayerList(_) => "RequestGlobalPlayerList",
+            Self::UpdateUserSettings(_) => "UpdateUserSettings",
+            Self::CreateRoom(_) => "CreateRoom",
+            Self::JoinRoom(_) => "JoinRoom",
+            Self::JoinRoomByToken(_) => "JoinRoomByToken",
+            Self::LeaveRoom => "LeaveRoom",
+            Self::CheckRoomState => "CheckRoomState",
+            Self::RequestRoomPlayers(_) => "RequestRoomPlayers",
+            Self::RequestRoomList(_) => "RequestRoomList",
+            Self::AssignTeam(_) => "AssignTeam",
+            Self::CreateTeam(_) => "CreateTeam",
+            Self::DeleteTeam(_) => "DeleteTeam",
+            Self::UpdateTeam(_) => "UpdateTeam",
+            Self::GetTeamMembers => "GetTeamMembers",
+            Self::RoomOwnerAction(_) => "RoomOwnerAction",
+            Self::UpdateRoomSettings(_) => "UpdateRoomSettings",
+            Self::InvitePlayer(_) => "InvitePlayer",
+            Self::UpdatePinnedLevel(_) => "UpdatePinnedLevel",
+            Self::JoinSession(_) => "JoinSession",
+            Self::LeaveSession => "LeaveSession",
+            Self::RequestLevelList => "RequestLevelList",
+            Self::AdminLogin(_) => "AdminLogin",
+            Self::AdminKick(_) => "AdminKick",
+            Self::AdminNotice(_) => "AdminNotice",
+            Self::AdminNoticeEveryone(_) => "AdminNoticeEveryone",
+            Self::AdminFetchUser(_) => "AdminFetchUser",
+            Self::AdminFetchLogs(_) => "AdminFetchLogs",
+            Self::AdminBan(_) => "AdminBan",
+            Self::AdminUnban(_) => "AdminUnban",
+            Self::AdminRoomBan(_) => "AdminRoomBan",
+            Self::AdminRoomUnban(_) => "AdminRoomUnban",
+            Self::AdminMute(_) => "AdminMute",
+            Self::AdminUnmute(_) => "AdminUnmute",
+            Self::AdminEditRoles(_) => "AdminEditRoles",
+            Self::AdminSetPassword(_) => "AdminSetPassword",
+            Self::AdminUpdateUser(_) => "AdminUpdateUser",
+            Self::AdminFetchMods => "AdminFetchMods",
+            Self::AdminSetWhitelisted(_) => "AdminSetWhitelisted",
+            Self::AdminCloseRoom(_) => "AdminCloseRoom",
+            Self::FetchCredits => "FetchCredits",
+            Self::GetUserState => "GetUserState",
+            Self::GetDiscordLinkState => "GetDiscordLinkState",
+            Self::SetDiscordPairingState(_) => "SetDiscordPairingState",
+            Self::DiscordLinkConfirm(_) => "DiscordLinkConfirm",
+            Self::RequestDiscordOauth => "RequestDiscordOauth",
+            Self::RequestDiscordUnlink => "RequestDiscordUnlink",
+            Self::GetFeaturedLevel => "GetFeaturedLevel",
+            Self::GetFeaturedList(_) => "GetFeaturedList",
+            Self::SendFeaturedLevel(_) => "SendFeaturedLevel",
+            Self::NoticeReply(_) => "NoticeReply",
+            Self::FetchUser(_) => "FetchUser",
+            Self::LoginOk(_) => "LoginOk",
+            Self::LoginFailed(_) => "LoginFailed",
+            Self::LoginRequired(_) => "LoginRequired",
+            Self::Banned(_) => "Banned",
+            Self::Muted(_) => "Muted",
+            Self::ServersChanged(_) => "ServersChanged",
+            Self::UserDataChanged(_) => "UserDataChanged",
+            Self::RoomState(_) => "RoomState",
+            Self::RoomPlayers(_) => "RoomPlayers",
+            Self::RoomJoinFailed(_) => "RoomJoinFailed",
+            Self::RoomCreateFailed(_) => "RoomCreateFailed",
+            Self::RoomBanned(_) => "RoomBanned",
+            Self::RoomList(_) => "RoomList",
+            Self::TeamCreationResult(_) => "TeamCreationResult",
+            Self::TeamChanged(_) => "TeamChanged",
+            Self::TeamMembers(_) => "TeamMembers",
+            Self::TeamsUpdated(_) => "TeamsUpdated",
+            Self::RoomSettingsUpdated(_) => "RoomSettingsUpdated",
+            Self::PinnedLevelUpdated(_) => "PinnedLevelUpdated",
+            Self::Invited(_) => "Invited",
+            Self::InviteTokenCreated(_) => "InviteTokenCreated",
+            Self::JoinFailed(_) => "JoinFailed",
+            Self::WarpPlayer(_) => "WarpPlayer",
+            Self::RoomWarp(_) => "RoomWarp",
+            Self::PlayerCounts(_) => "PlayerCounts",
+            Self::GlobalPlayers(_) => "GlobalPlayers",
+            Self::LevelList(_) => "LevelList",
+            Self::Kicked(_) => "Kicked",
+            Self::Notice(_) => "Notice",
+            Self::Warn(_) => "Warn",
+            Self::NoticeReplyResult(_) => "NoticeReplyResult",
+            Self::AdminResult(_) => "AdminResult",
+            Self::AdminFetchResponse(_) => "AdminFetchResponse",
+            Self::AdminFetchModsResponse(_) => "AdminFetchModsResponse",
+            Self::AdminLogsResponse(_) => "AdminLogsResponse",
+            Self::AdminPunishmentReasons(_) => "AdminPunishmentReasons",
+            Self::Credits(_) => "Credits",
+            Self::UserState(_) => "UserState",
+            Self::DiscordLinkState(_) => "DiscordLinkState",
+            Self::DiscordLinkAttempt(_) => "DiscordLinkAttempt",
+            Self::DiscordOauthUrl(_) => "DiscordOauthUrl",
+            Self::DiscordUnlinkResult(_) => "DiscordUnlinkResult",
+            Self::FeaturedLevel(_) => "FeaturedLevel",
+            Self::FeaturedList(_) => "FeaturedList",
+            Self::FetchUserResponse(_) => "FetchUserResponse",
+            Self::Events(_) => "Events",
+        }
+    }
+
+    /// Encodes the message into `out`, prefixed with its unpacked length, as expected by the receiving end.
+    /// The message is packed, unless that would make it larger.
+    #[track_caller]
+    pub fn encode_to_vec(&self, out: &mut Vec<u8>) -> Result<(), EncodeMessageError> {
+        let capacity = self.capacity();
+        let mut arena = vec![0u8; capacity];
+        let mut builder = builder_borrow(&mut arena);
+
+        encode_message_into::<schema::message::Owned, _, _>(
+            &mut builder,
+            self.name(),
+            capacity,
+            PackStrategy::Auto,
+            out,
+            |b| {
+                self.encode(b);
+                Ok(())
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use qunet::buffers::ByteReader;
+
+    use super::*;
+    use crate::{
+        MAX_ROOM_NAME_LENGTH, MultiColor, RGBColor, data::SpecialUserData, encoding::MessageDecoder,
+    };
+
+    fn roundtrip(msg: MainMessage) {
+        let mut buf = Vec::new();
+        msg.encode_to_vec(&mut buf).unwrap();
+
+        // the unpacked length includes the segment table, which is a single word for a single segment
+        let unpacked_len = ByteReader::new(&buf).read_varuint().unwrap() as usize;
+        assert!(unpacked_len <= msg.capacity() + WORD_SIZE, "{}", msg.name());
+
+        let mut scratch = Vec::new();
+        let reader = MessageDecoder::new()
+            .decode_main(&buf, &mut scratch)
+            .unwrap();
+        let decoded = MainMessage::from_reader(reader.get().unwrap()).unwrap();
+
+        assert_eq!(decoded, msg);
+    }
+
+    fn account(account_id: i32) -> PlayerAccountData {
+        PlayerAccountData {
+            account_id,
+            user_id: account_id * 10,
+            username: format!("player{account_id}"),
+        }
+    }
+
+    fn room_player(account_id: i32) -> RoomPlayer {
+        RoomPlayer {
+            account_data: account(account_id),
+            cube: 12,
+            color1: 3,
+            color2: 4,
+            glow_color: 5,
+            session: 0xdead_beef,
+            team_id: 1,
+            special_data: SpecialUserData {
+                roles: vec![2],
+                name_color: Some(MultiColor::Static(RGBColor::new(0, 255, 0))),
+            },
+        }
+    }
+
+    fn settings() -> RoomSettings {
+        RoomSettings {
+            server_id: 2,
+            player_limit: 10,
+            hidden: true,
+            teams: true,
+            locked_teams: true,
+            collision: true,
+            ..Default::default()
+        }
+    }
+
+    fn punishment(expires_at: i64) -> Punishment {
+        Punishment {
+            issued_by: 71,
+            issued_at: 1_700_000_000,
+            reason: "spam".into(),
+            expires_at,
+        }
+    }
+
+    #[test]
+    fn login_roundtrip() {
+        for auth in [
+            LoginAuth::Utoken("utoken".into()),
+            LoginAuth::Argon("argon".into()),
+            LoginAuth::Plain(account(5)),
+        ] {
+            roundtrip(MainMessage::Login(LoginMessage {
+                account_id: 5,
+                icons: PlayerIconData {
+                    cube: 100,
+                    glow_color: 7,
+                    ..Default::default()
+                },
+                uident: vec![9; 32],
+                settings: UserSettings::default(),
+                auth,
+                platform: schema::Platform::Android64,
+                geode_version: "v4.0.0".into(),
+                globed_version: "v2.0.0".into(),
+                platform_desc: "test".into(),
+                event_dictionary: vec![1, 0, 0, 0, 1, 0, 0, 0],
+                protocol_version: 3,
+                capabilities: Capabilities::SUPPORTED,
+            }));
+        }
+    }
+
+    #[test]
+    fn login_response_roundtrip() {
+        roundtrip(MainMessage::LoginOk(LoginOkMessage {
+            servers: vec![GameServerData {
+                id: 1,
+                address: "127.0.0.1:4202".try_into().unwrap(),
+                string_id: "eu-1".try_into().unwrap(),
+                name: "Europe".try_into().unwrap(),
+                region: "eu".try_into().unwrap(),
+                draining: false,
+            }],
+            all_roles: vec![UserRole {
+                string_id: "mod".into(),
+                icon: "mod.png".into(),
+                name_color: vec![0x41, 0xff, 0, 0],
+                hide: false,
+            }],
+            featured_level: 128,
+            featured_level_tier: 2,
+            featured_level_edition: 5,
+            user_data: ExtendedUserData {
+                new_token: "new token".into(),
+                roles: vec![1, 2],
+                name_color: Vec::new(),
+                is_moderator: true,
+                can_mute: true,
+                can_ban: false,
+                can_set_password: false,
+                can_edit_roles: false,
+                can_send_features: true,
+                can_rate_features: false,
+                can_name_rooms: true,
+            },
+            capabilities: Capabilities::COMPRESSED_EVENTS,
+        }));
+
+        roundtrip(MainMessage::LoginFailed(LoginFailedMessage {
+            reason: schema::LoginFailedReason::NotWhitelisted,
+        }));
+        roundtrip(MainMessage::LoginRequired(LoginRequiredMessage {
+            argon_url: "https://argon.example".into(),
+        }));
+    }
+
+    #[test]
+    fn punishment_roundtrip() {
+        roundtrip(MainMessage::Banned(BannedMessage::from(&punishment(0))));
+        roundtrip(MainMessage::Muted(MutedMessage::from(&punishment(
+            1_800_000_000,
+        ))));
+        roundtrip(MainMessage::RoomBanned(RoomBannedMessage {
+            reason: String::new(),
+            expires_at: 1,
+        }));
+
+        roundtrip(MainMessage::UserState(UserStateMessage {
+            active_mute: Some(punishment(1_800_000_000)),
+            active_room_ban: None,
+        }));
+        roundtrip(MainMessage::UserState(UserStateMessage {
+            active_mute: None,
+            active_room_ban: None,
+        }));
+    }
+
+    #[test]
+    fn own_data_roundtrip() {
+        roundtrip(MainMessage::UpdateOwnData(UpdateOwnDataMessage {
+            icons: None,
+            friend_list: None,
+        }));
+        roundtrip(MainMessage::UpdateOwnData(UpdateOwnDataMessage {
+            icons: Some(PlayerIconData::default()),
+            friend_list: Some(vec![1, 2, 3]),
+        }));
+        roundtrip(MainMessage::UpdateOwnData(UpdateOwnDataMessage {
+            icons: None,
+            friend_list: Some(Vec::new()),
+        }));
+    }
+
+    #[test]
+    fn room_roundtrip() {
+        roundtrip(MainMessage::CreateRoom(CreateRoomMessage {
+            name: "my room".into(),
+            passcode: 4321,
+            settings: settings(),
+        }));
+        roundtrip(MainMessage::UpdateRoomSettings(UpdateRoomSettingsMessage {
+            settings: RoomSettings::default(),
+        }));
+
+        roundtrip(MainMessage::RoomState(RoomStateMessage {
+            room_id: 99,
+            room_owner: 1,
+            room_name: "my room".into(),
+            players: vec![room_player(1), room_player(2)],
+            settings: settings(),
+            teams: vec![0xff0000, 0x00ff00],
+            passcode: 4321,
+            player_count: 2,
+            pinned_level: 1234,
+        }));
+
+        roundtrip(MainMessage::RoomList(RoomListMessage {
+            rooms: vec![RoomListingInfo {
+                room_id: 99,
+                room_name: "my room".into(),
+                room_owner: room_player(1),
+                original_owner_id: 1,
+                player_count: 2,
+                has_password: true,
+                settings: settings(),
+            }],
+            total: 1,
+            page: 0,
+        }));
+
+        roundtrip(MainMessage::TeamMembers(TeamMembersMessage {
+            members: vec![1, 2],
+            team_ids: vec![0, 1],
+        }));
+        roundtrip(MainMessage::RoomJoinFailed(RoomJoinFailedMessage {
+            reason: schema::RoomJoinFailedReason::Full,
+        }));
+        roundtrip(MainMessage::RoomOwnerAction(RoomOwnerActionMessage {
+            action: schema::RoomOwnerActionType::KickUser,
+            target: 2,
+        }));
+        roundtrip(MainMessage::Invited(InvitedMessage {
+            invited_by: account(3),
+            token: u64::MAX,
+        }));
+    }
+
+    #[test]
+    fn listing_roundtrip() {
+        roundtrip(MainMessage::PlayerCounts(PlayerCountsMessage {
+            level_ids: vec![1, u64::MAX],
+            counts: vec![5, 0],
+        }));
+        roundtrip(MainMessage::GlobalPlayers(GlobalPlayersMessage {
+            players: vec![MinimalRoomPlayer {
+                account_data: account(4),
+                cube: 1,
+                color1: 2,
+                color2: 3,
+                glow_color: 4,
+            }],
+        }));
+        roundtrip(MainMessage::Credits(CreditsMessage {
+            categories: vec![CreditsCategory {
+                name: "Developers".into(),
+                users: vec![CreditsUser {
+                    account_id: 1,
+                    user_id: 2,
+                    username: "dev".into(),
+                    display_name: "Dev".into(),
+                    cube: 3,
+                    color1: 4,
+                    color2: 5,
+                    glow_color: 6,
+                }],
+            }],
+            unavailable: false,
+        }));
+        roundtrip(MainMessage::FeaturedList(FeaturedListMessage {
+            level_ids: vec![10, 20],
+            rate_tiers: vec![1, 2],
+            feature_times: vec![100, 200],
+            page: 1,
+            total_pages: 3,
+        }));
+    }
+
+    #[test]
+    fn admin_roundtrip() {
+        roundtrip(MainMessage::AdminNotice(AdminNoticeMessage {
+            target_user: "player1".into(),
+            message: "hello".into(),
+            room_id: 0,
+            level_id: -1,
+            can_reply: true,
+            show_sender: false,
+        }));
+        roundtrip(MainMessage::AdminMute(AdminMuteMessage {
+            account_id: 1,
+            reason: "spam".into(),
+            expires_at: 1_800_000_000,
+        }));
+        roundtrip(MainMessage::AdminFetchResponse(AdminFetchResponseMessage {
+            account_id: 1,
+            found: true,
+            whitelisted: false,
+            roles: vec![3],
+            active_ban: None,
+            active_room_ban: Some(punishment(0)),
+            active_mute: Some(punishment(1_800_000_000)),
+            punishment_count: 2,
+        }));
+        roundtrip(MainMessage::AdminLogsResponse(AdminLogsResponseMessage {
+            logs: vec![AuditLog {
+                id: 1,
+                account_id: 71,
+                target_account_id: 1,
+                log_type: "mute".into(),
+                timestamp: 1_700_000_000,
+                expires_at: 1_800_000_000,
+                message: "spam".into(),
+            }],
+            accounts: vec![account(71), account(1)],
+        }));
+        roundtrip(MainMessage::AdminPunishmentReasons(
+            AdminPunishmentReasonsMessage {
+                ban: vec!["cheating".into()],
+                mute: vec!["spam".into(), "slurs".into()],
+                room_ban: Vec::new(),
+            },
+        ));
+    }
+
+    #[test]
+    fn empty_roundtrip() {
+        for msg in [
+            MainMessage::LeaveRoom,
+            MainMessage::CheckRoomState,
+            MainMessage::GetTeamMembers,
+            MainMessage::LeaveSession,
+            MainMessage::RequestLevelList,
+            MainMessage::AdminFetchMods,
+            MainMessage::FetchCredits,
+            MainMessage::GetUserState,
+            MainMessage::GetDiscordLinkState,
+            MainMessage::RequestDiscordOauth,
+            MainMessage::RequestDiscordUnlink,
+            MainMessage::GetFeaturedLevel,
+            MainMessage::Events(Vec::new()),
+            MainMessage::Events(vec![0, 1, 2]),
+        ] {
+            roundtrip(msg);
+        }
+    }
+
+    /// Encodes the message into an arena of `arena` bytes, returning the amount of segments used
+    /// and the unpacked size of the message
+    fn encode_in_arena(msg: &MainMessage, arena: usize) -> (usize, usize) {
+        let mut arena = vec![0u8; arena];
+        let mut builder = builder_borrow(&mut arena);
+        msg.encode(builder.init_root());
+
+        let segments = builder.get_segments_for_output();
+        (segments.len(), segments.iter().map(|s| s.len()).sum())
+    }
+
+    #[test]
+    fn capacity_fits_at_limit() {
+        let msg = MainMessage::CreateRoom(CreateRoomMessage {
+            name: "a".repeat(MAX_ROOM_NAME_LENGTH),
+            ..Default::default()
+        });
+        let capacity = msg.capacity();
+
+        assert_eq!(encode_in_arena(&msg, capacity), (1, capacity));
+    }
+
+    #[test]
+    fn room_settings_valid() {
+        assert_eq!(RoomSettings::default().validate(), Ok(()));
+        assert_eq!(settings().validate(), Ok(()));
+
+        let locked_teams = RoomSettings {
+            teams: true,
+            locked_teams: true,
+            ..Default::default()
+        };
+        assert_eq!(locked_teams.validate(), Ok(()));
+
+        // combining modes and the player limit are left to the server
+        let modes = RoomSettings {
+            player_limit: 0,
+            two_player_mode: true,
+            deathlink: true,
+            switcheroo: true,
+            ..Default::default()
+        };
+        assert_eq!(modes.validate(), Ok(()));
+    }
+
+    #[test]
+    fn room_settings_rejected() {
+        let settings = RoomSettings {
+            locked_teams: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            settings.validate(),
+            Err(RoomSettingsError::LockedTeamsWithoutTeams)
+        );
+    }
+
+    #[test]
+    fn room_settings_from_config() {
+        let settings: RoomSettings = toml::from_str(
+            r#"
+            player_limit = 2
+            is_follower = true
+            collision = true
+            two_player_mode = true
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            settings,
+            RoomSettings {
+                player_limit: 2,
+                is_follower: true,
+                collision: true,
+                two_player_mode: true,
+                ..Default::default()
+            }
+        );
+        assert_eq!(settings.validate(), Ok(()));
+
+        let empty: RoomSettings = toml::from_str("").unwrap();
+        assert_eq!(empty, RoomSettings::default());
+
+        assert!(toml::from_str::<RoomSettings>("max_players = 2").is_err());
+    }
+}
@@ -0,0 +1,17 @@
+#[cfg(feature = "game")]
+pub mod game;
+#[cfg(feature = "main")]
+pub mod main;
+#[cfg(feature = "srvc")]
+pub mod srvc;
+mod validate;
+
+#[cfg(feature = "game")]
+pub use game::GameMessage;
+#[cfg(feature = "main")]
+pub use main::MainMessage;
+#[cfg(feature = "srvc")]
+pub use srvc::SrvcMessage;
+pub use validate::{
+    MAX_DATA_REQUESTS, MAX_META_REQUESTS, RoomSettingsError, Validate, ValidationError,
+};
@@ -0,0 +1,362 @@
+use crate::{
+    data::{GameServerData, SrvLoginOk, SrvStatusData, SrvUserData, SrvUserDataDiff},
+    encoding::{
+        DataDecodeError, EncodeMessageError, PackStrategy, builder_borrow, encode_message_into,
+        size::*,
+    },
+    schema::srvc::{self as schema, message::Which},
+};
+
+pub use crate::srvc_models::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoginSrvMessage {
+    pub data: GameServerData,
+    pub magic: u64,
+    pub protocol_version: u32,
+    pub challenge_response: Vec<u8>,
+}
+
+impl LoginSrvMessage {
+    pub fn from_reader(
+        reader: schema::login_srv_message::Reader<'_>,
+    ) -> Result<Self, DataDecodeError> {
+        Ok(Self {
+            data: GameServerData::from_reader(reader.get_data()?)?,
+            magic: reader.get_magic(),
+            protocol_version: reader.get_protocol_version(),
+            challenge_response: reader.get_challenge_response()?.to_vec(),
+        })
+    }
+
+    pub fn encode(&self, mut builder: schema::login_srv_message::Builder<'_>) {
+        self.data.encode(builder.reborrow().init_data());
+        builder.set_magic(self.magic);
+        builder.set_protocol_version(self.protocol_version);
+        builder.set_challenge_response(&self.challenge_response);
+    }
+
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<schema::login_srv_message::Builder<'static>>()
+            + self.data.encoded_size()
+            + data_size(self.challenge_response.len())
+    }
+}
+
+impl NotifyUserDataBatchMessage {
+    /// Adds `new` to the batch, as a diff against `old` if the game server has already been sent `old`
+    pub fn push(&mut self, old: Option<&SrvUserData>, new: &SrvUserData) {
+        match old {
+            Some(old) if old.account_id == new.account_id => {
+                self.diffs.extend(SrvUserDataDiff::between(old, new));
+            }
+            _ => self.data.push(new.clone()),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty() && self.diffs.is_empty()
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum SrvcMessage {
+    LoginSrv(LoginSrvMessage),
+    RoomCreatedAck(RoomCreatedAckMessage),
+    Status(SrvStatusData),
+    LoginChallenge(LoginChallengeMessage),
+    LoginOk(SrvLoginOk),
+    LoginFailed(LoginFailedMessage),
+    NotifyRoomCreated(NotifyRoomCreatedMessage),
+    NotifyRoomDeleted(NotifyRoomDeletedMessage),
+    NotifyUserData(NotifyUserDataMessage),
+    NotifyKickUser(NotifyKickUserMessage),
+    ReloadConfig,
+    Resume,
+    Drain(DrainMessage),
+    NotifyUserDataBatch(NotifyUserDataBatchMessage),
+}
+
+impl SrvcMessage {
+    pub fn from_reader(reader: schema::message::Reader<'_>) -> Result<Self, DataDecodeError> {
+        Ok(match reader.which()? {
+            Which::LoginSrv(msg) => Self::LoginSrv(LoginSrvMessage::from_reader(msg?)?),
+            Which::RoomCreatedAck(msg) => {
+                Self::RoomCreatedAck(RoomCreatedAckMessage::from_reader(msg?)?)
+            }
+            Which::Status(msg) => Self::Status(SrvStatusData::from_reader(msg?)?),
+            Which::LoginChallenge(msg) => {
+                Self::LoginChallenge(LoginChallengeMessage::from_reader(msg?)?)
+            }
+            Which::LoginOk(msg) => Self::LoginOk(SrvLoginOk::from_reader(msg?)?),
+            Which::LoginFailed(msg) => Self::LoginFailed(LoginFailedMessage::from_reader(msg?)?),
+            Which::NotifyRoomCreated(msg) => {
+                Self::NotifyRoomCreated(NotifyRoomCreatedMessage::from_reader(msg?)?)
+            }
+            Which::NotifyRoomDeleted(msg) => {
+                Self::NotifyRoomDeleted(NotifyRoomDeletedMessage::from_reader(msg?)?)
+            }
+            Which::NotifyUserData(msg) => {
+                Self::NotifyUserData(NotifyUserDataMessage::from_reader(msg?)?)
+            }
+            Which::NotifyKickUser(msg) => {
+                Self::NotifyKickUser(NotifyKickUserMessage::from_reader(msg?)?)
+            }
+            Which::ReloadConfig(_) => Self::ReloadConfig,
+            Which::Resume(_) => Self::Resume,
+            Which::Drain(msg) => Self::Drain(DrainMessage::from_reader(msg?)?),
+            Which::NotifyUserDataBatch(msg) => {
+                Self::NotifyUserDataBatch(NotifyUserDataBatchMessage::from_reader(msg?)?)
+            }
+        })
+    }
+
+    pub fn encode(&self, builder: schema::message::Builder<'_>) {
+        match self {
+            Self::LoginSrv(msg) => msg.encode(builder.init_login_srv()),
+            Self::RoomCreatedAck(msg) => msg.encode(builder.init_room_created_ack()),
+            Self::Status(msg) => msg.encode(builder.init_status()),
+            Self::LoginChallenge(msg) => msg.encode(builder.init_login_challenge()),
+            Self::LoginOk(msg) => msg.encode(builder.init_login_ok()),
+            Self::LoginFailed(msg) => msg.encode(builder.init_login_failed()),
+            Self::NotifyRoomCreated(msg) => msg.encode(builder.init_notify_room_created()),
+            Self::NotifyRoomDeleted(msg) => msg.encode(builder.init_notify_room_deleted()),
+            Self::NotifyUserData(msg) => msg.encode(builder.init_notify_user_data()),
+            Self::NotifyKickUser(msg) => msg.encode(builder.init_notify_kick_user()),
+            Self::ReloadConfig => {
+                builder.init_reload_config();
+            }
+            Self::Resume => {
+                builder.init_resume();
+            }
+            Self::Drain(msg) => msg.encode(builder.init_drain()),
+            Self::NotifyUserDataBatch(msg) => msg.encode(builder.init_notify_user_data_batch()),
+        }
+    }
+
+    /// Upper bound for the size of this message in a capnp arena, in bytes, not including the root pointer
+    pub fn encoded_size(&self) -> usize {
+        struct_size::<schema::message::Builder<'static>>()
+            + match self {
+                Self::LoginSrv(msg) => msg.encoded_size(),
+                Self::RoomCreatedAck(msg) => msg.encoded_size(),
+                Self::Status(msg) => msg.encoded_size(),
+                Self::LoginChallenge(msg) => msg.encoded_size(),
+                Self::LoginOk(msg) => msg.encoded_size(),
+                Self::LoginFailed(msg) => msg.encoded_size(),
+                Self::NotifyRoomCreated(msg) => msg.encoded_size(),
+                Self::NotifyRoomDeleted(msg) => msg.encoded_size(),
+                Self::NotifyUserData(msg) => msg.encoded_size(),
+                Self::NotifyKickUser(msg) => msg.encoded_size(),
+                Self::ReloadConfig => 0,
+                Self::Resume => 0,
+                Self::Drain(msg) => msg.encoded_size(),
+                Self::NotifyUserDataBatch(msg) => msg.encoded_size(),
+            }
+    }
+
+    /// Arena capacity needed to encode this message, used by `encode_message!` when given an owned message
+    pub fn capacity(&self) -> usize {
+        message_capacity(self.encoded_size())
+    }
+
+    /// Name of the message variant, as in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::LoginSrv(_) => "LoginSrv",
+            Self::RoomCreatedAck(_) => "RoomCreatedAck",
+            Self::Status(_) => "Status",
+            Self::LoginChallenge(_) => "LoginChallenge",
+            Self::LoginOk(_) => "LoginOk",
+            Self::LoginFailed(_) => "LoginFailed",
+            Self::NotifyRoomCreated(_) => "NotifyRoomCreated",
+            Self::NotifyRoomDeleted(_) => "NotifyRoomDeleted",
+            Self::NotifyUserData(_) => "NotifyUserData",
+            Self::NotifyKickUser(_) => "NotifyKickUser",
+            Self::ReloadConfig => "ReloadConfig",
+            Self::Resume => "Resume",
+            Self::Drain(_) => "Drain",
+            Self::NotifyUserDataBatch(_) => "NotifyUserDataBatch",
+        }
+    }
+
+    /// Encodes the message into `out`, prefixed with its unpacked length, as expected by the receiving end.
+    /// The message is packed, unless that would make it larger.
+    #[track_caller]
+    pub fn encode_to_vec(&self, out: &mut Vec<u8>) -> Result<(), EncodeMessageError> {
+        let capacity = self.capacity();
+        let mut arena = vec![0u8; capacity];
+        let mut builder = builder_borrow(&mut arena);
+
+        encode_message_into::<schema::message::Owned, _, _>(
+            &mut builder,
+            self.name(),
+            capacity,
+            PackStrategy::Auto,
+            out,
+            |b| {
+                self.encode(b);
+                Ok(())
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use qunet::buffers::ByteReader;
+
+    use super::*;
+    use crate::{
+        data::{ServerRole, SessionPlayerCount},
+        encoding::MessageDecoder,
+    };
+
+    fn roundtrip(msg: SrvcMessage) {
+        let mut buf = Vec::new();
+        msg.encode_to_vec(&mut buf).unwrap();
+
+        // the unpacked length includes the segment table, which is a single word for a single segment
+        let unpacked_len = ByteReader::new(&buf).read_varuint().unwrap() as usize;
+        assert!(unpacked_len <= msg.capacity() + WORD_SIZE, "{}", msg.name());
+
+        let mut scratch = Vec::new();
+        let reader = MessageDecoder::new()
+            .decode_srvc(&buf, &mut scratch)
+            .unwrap();
+        let decoded = SrvcMessage::from_reader(reader.get().unwrap()).unwrap();
+
+        assert_eq!(decoded, msg);
+    }
+
+    fn user_data(account_id: i32) -> SrvUserData {
+        SrvUserData {
+            account_id,
+            can_use_voice: true,
+            is_muted: true,
+            mute_expires_at: 1_700_000_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn login_roundtrip() {
+        roundtrip(SrvcMessage::LoginChallenge(LoginChallengeMessage {
+            nonce: (0..32).collect(),
+        }));
+
+        roundtrip(SrvcMessage::LoginSrv(LoginSrvMessage {
+            data: GameServerData {
+                id: 3,
+                address: "127.0.0.1:4202".try_into().unwrap(),
+                string_id: "eu-1".try_into().unwrap(),
+                name: "Europe".try_into().unwrap(),
+                region: "eu".try_into().unwrap(),
+                draining: true,
+            },
+            magic: crate::data::SRVC_MAGIC,
+            protocol_version: crate::data::SRVC_PROTOCOL_VERSION,
+            challenge_response: vec![0xab; 32],
+        }));
+
+        roundtrip(SrvcMessage::LoginOk(SrvLoginOk {
+            token_key: "token key".into(),
+            token_expiry: 86400,
+            roles: vec![
+                ServerRole {
+                    id: 1,
+                    string_id: "mod".into(),
+                    can_moderate: true,
+                },
+                ServerRole {
+                    id: 2,
+                    string_id: "vip".into(),
+                    can_moderate: false,
+                },
+            ],
+            script_key: "script key".into(),
+        }));
+
+        roundtrip(SrvcMessage::LoginFailed(LoginFailedMessage {
+            reason: "challenge expired".into(),
+            kind: schema::LoginFailedReason::ChallengeExpired,
+        }));
+    }
+
+    #[test]
+    fn status_roundtrip() {
+        roundtrip(SrvcMessage::Status(SrvStatusData {
+            clients: 120,
+            auth_clients: 100,
+            rooms: 4,
+            sessions: 2,
+            total_connections: 5000,
+            total_data_messages: 1 << 40,
+            server_load: 0.25,
+            session_players: vec![
+                SessionPlayerCount {
+                    session_id: 1,
+                    players: 60,
+                },
+                SessionPlayerCount {
+                    session_id: u64::MAX,
+                    players: 40,
+                },
+            ],
+            tick_p50: 500,
+            tick_p95: 900,
+            tick_p99: 1200,
+            uptime: 3600,
+            ..Default::default()
+        }));
+    }
+
+    #[test]
+    fn room_roundtrip() {
+        roundtrip(SrvcMessage::NotifyRoomCreated(NotifyRoomCreatedMessage {
+            room_id: 123456,
+            passcode: 1111,
+            owner: -5,
+        }));
+        roundtrip(SrvcMessage::NotifyRoomDeleted(NotifyRoomDeletedMessage {
+            room_id: 123456,
+        }));
+        roundtrip(SrvcMessage::RoomCreatedAck(RoomCreatedAckMessage {
+            room_id: 123456,
+        }));
+    }
+
+    #[test]
+    fn user_data_roundtrip() {
+        roundtrip(SrvcMessage::NotifyUserData(NotifyUserDataMessage {
+            data: user_data(1),
+        }));
+        roundtrip(SrvcMessage::NotifyKickUser(NotifyKickUserMessage {
+            account_id: 1,
+        }));
+
+        let old = user_data(2);
+        let mut new = old.clone();
+        new.is_banned = true;
+
+        let mut batch = NotifyUserDataBatchMessage::default();
+        batch.push(None, &user_data(1));
+        batch.push(Some(&old), &new);
+        assert_eq!(batch.data.len(), 1);
+        assert_eq!(batch.diffs.len(), 1);
+
+        roundtrip(SrvcMessage::NotifyUserDataBatch(batch));
+        roundtrip(SrvcMessage::NotifyUserDataBatch(Default::default()));
+    }
+
+    #[test]
+    fn control_roundtrip() {
+        roundtrip(SrvcMessage::ReloadConfig);
+        roundtrip(SrvcMessage::Resume);
+        roundtrip(SrvcMessage::Drain(DrainMessage {
+            deadline: 1_700_000_000,
+            reason: "maintenance".into(),
+        }));
+    }
+}
@@ -0,0 +1,422 @@
+use thiserror::Error;
+
+#[cfg(feature = "game")]
+use super::game::{
+    ExtendedPlayerData, GameMessage, PlayerData, PlayerDataMessage, PlayerObjectData,
+    PlayerObjects, PlayerUpdateMetaMessage,
+};
+#[cfg(feature = "main")]
+use super::main::{
+    CreateRoomMessage, LoginAuth, LoginMessage, MainMessage, PlayerAccountData,
+    UpdateRoomSettingsMessage,
+};
+#[cfg(feature = "main")]
+use crate::{MAX_ROOM_NAME_LENGTH, MAX_USERNAME_LENGTH};
+
+/// Maximum amount of players a client can request data of in a single `PlayerDataMessage`
+pub const MAX_DATA_REQUESTS: usize = 64;
+/// Maximum amount of players a client can request level meta of in a single `PlayerUpdateMetaMessage`
+pub const MAX_META_REQUESTS: usize = 256;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    #[error("nan/inf value in {0}")]
+    NonFinite(&'static str),
+    #[error("too many elements in {field}: {len} (limit: {max})")]
+    TooManyElements {
+        field: &'static str,
+        len: usize,
+        max: usize,
+    },
+    #[error("{field} is too long: {len} bytes (limit: {max})")]
+    TooLong {
+        field: &'static str,
+        len: usize,
+        max: usize,
+    },
+    #[error("invalid room settings: {0}")]
+    InvalidSettings(#[from] RoomSettingsError),
+}
+
+/// Reasons for a combination of room settings to be rejected, see `RoomSettings::validate`
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum RoomSettingsError {
+    #[error("locked teams require teams to be enabled")]
+    LockedTeamsWithoutTeams,
+}
+
+/// Checks the limits documented in the schema that can't be expressed in capnp itself.
+/// Implemented for messages sent by clients, server messages always pass validation.
+pub trait Validate {
+    fn validate(&self) -> Result<(), ValidationError>;
+}
+
+#[cfg(feature = "game")]
+fn check_finite(field: &'static str, value: f32) -> Result<(), ValidationError> {
+    if value.is_finite() {
+        Ok(())
+    } else {
+        Err(ValidationError::NonFinite(field))
+    }
+}
+
+#[cfg(feature = "game")]
+fn check_count(field: &'static str, len: usize, max: usize) -> Result<(), ValidationError> {
+    if len <= max {
+        Ok(())
+    } else {
+        Err(ValidationError::TooManyElements { field, len, max })
+    }
+}
+
+#[cfg(feature = "main")]
+fn check_len(field: &'static str, value: &str, max: usize) -> Result<(), ValidationError> {
+    if value.len() <= max {
+        Ok(())
+    } else {
+        Err(ValidationError::TooLong {
+            field,
+            len: value.len(),
+            max,
+        })
+    }
+}
+
+// Game
+
+#[cfg(feature = "game")]
+impl Validate for ExtendedPlayerData {
+    fn validate(&self) -> Result<(), ValidationError> {
+        check_finite("velocityX", self.velocity_x)?;
+        check_finite("velocityY", self.velocity_y)?;
+        check_finite("acceleration", self.acceleration)?;
+        check_finite("fallStartY", self.fall_start_y)?;
+        check_finite("gravityMod", self.gravity_mod)?;
+        check_finite("gravity", self.gravity)?;
+        check_finite("fallSpeed", self.fall_speed)
+    }
+}
+
+#[cfg(feature = "game")]
+impl Validate for PlayerObjectData {
+    fn validate(&self) -> Result<(), ValidationError> {
+        check_finite("positionX", self.position_x)?;
+        check_finite("positionY", self.position_y)?;
+        check_finite("rotation", self.rotation)?;
+        self.ext_data.validate()
+    }
+}
+
+#[cfg(feature = "game")]
+impl Validate for PlayerData {
+    fn validate(&self) -> Result<(), ValidationError> {
+        check_finite("timestamp", self.timestamp)?;
+
+        match &self.objects {
+            PlayerObjects::Dual(player1, player2) => {
+                player1.validate()?;
+                player2.validate()
+            }
+            PlayerObjects::Single(player1) => player1.validate(),
+            PlayerObjects::Culled => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "game")]
+impl Validate for PlayerDataMessage {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.data.validate()?;
+        check_count("dataRequests", self.data_requests.len(), MAX_DATA_REQUESTS)?;
+        check_finite("cameraX", self.camera_x)?;
+        check_finite("cameraY", self.camera_y)?;
+        check_finite("cameraRadius", self.camera_radius)
+    }
+}
+
+#[cfg(feature = "game")]
+impl Validate for PlayerUpdateMetaMessage {
+    fn validate(&self) -> Result<(), ValidationError> {
+        check_count("requests", self.requests.len(), MAX_META_REQUESTS)
+    }
+}
+
+#[cfg(feature = "game")]
+impl Validate for GameMessage {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            Self::PlayerData(msg) => msg.validate(),
+            Self::PlayerUpdateMeta(msg) => msg.validate(),
+            _ => Ok(()),
+        }
+    }
+}
+
+// Main
+
+#[cfg(feature = "main")]
+impl Validate for PlayerAccountData {
+    fn validate(&self) -> Result<(), ValidationError> {
+        check_len("username", &self.username, MAX_USERNAME_LENGTH)
+    }
+}
+
+#[cfg(feature = "main")]
+impl Validate for LoginMessage {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match &self.auth {
+            LoginAuth::Plain(data) => data.validate(),
+            LoginAuth::Utoken(_) | LoginAuth::Argon(_) => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "main")]
+impl Validate for CreateRoomMessage {
+    fn validate(&self) -> Result<(), ValidationError> {
+        check_len("name", &self.name, MAX_ROOM_NAME_LENGTH)?;
+        Ok(self.settings.validate()?)
+    }
+}
+
+#[cfg(feature = "main")]
+impl Validate for UpdateRoomSettingsMessage {
+    fn validate(&self) -> Result<(), ValidationError> {
+        Ok(self.settings.validate()?)
+    }
+}
+
+#[cfg(feature = "main")]
+impl Validate for MainMessage {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            Self::Login(msg) => msg.validate(),
+            Self::CreateRoom(msg) => msg.validate(),
+            Self::UpdateRoomSettings(msg) => msg.validate(),
+            Self::RequestGlobalPlayerList(msg) => {
+                check_len("nameFilter", &msg.name_filter, MAX_USERNAME_LENGTH)
+            }
+            Self::RequestRoomPlayers(msg) => {
+                check_len("nameFilter", &msg.name_filter, MAX_USERNAME_LENGTH)
+            }
+            Self::RequestRoomList(msg) => {
+                check_len("nameFilter", &msg.name_filter, MAX_ROOM_NAME_LENGTH)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(all(test, any(feature = "game", feature = "main")))]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "game")]
+    mod game {
+        use super::*;
+        use crate::messages::game::{PlayerLevelMeta, PlayerObjectData};
+
+        fn player_data(requests: usize) -> PlayerDataMessage {
+            PlayerDataMessage {
+                data: PlayerData {
+                    account_id: 1,
+                    timestamp: 1.0,
+                    frame_number: 0,
+                    death_count: 0,
+                    percentage: 0,
+                    is_dead: false,
+                    is_paused: false,
+                    is_practicing: false,
+                    is_in_editor: false,
+                    is_editor_building: false,
+                    is_last_death_real: false,
+                    objects: PlayerObjects::Culled,
+                },
+                data_requests: (0..requests as i32).collect(),
+                event_data: Vec::new(),
+                camera_x: 0.0,
+                camera_y: 0.0,
+                camera_radius: 100.0,
+                message_id: 0,
+            }
+        }
+
+        fn update_meta(requests: usize) -> PlayerUpdateMetaMessage {
+            PlayerUpdateMetaMessage {
+                meta: PlayerLevelMeta { progress: 0 },
+                requests: (0..requests as i32).collect(),
+            }
+        }
+
+        #[test]
+        fn data_requests_at_limit() {
+            assert_eq!(player_data(MAX_DATA_REQUESTS).validate(), Ok(()));
+            assert_eq!(update_meta(MAX_META_REQUESTS).validate(), Ok(()));
+        }
+
+        #[test]
+        fn data_requests_over_limit() {
+            assert_eq!(
+                player_data(MAX_DATA_REQUESTS + 1).validate(),
+                Err(ValidationError::TooManyElements {
+                    field: "dataRequests",
+                    len: MAX_DATA_REQUESTS + 1,
+                    max: MAX_DATA_REQUESTS,
+                })
+            );
+            assert_eq!(
+                update_meta(MAX_META_REQUESTS + 1).validate(),
+                Err(ValidationError::TooManyElements {
+                    field: "requests",
+                    len: MAX_META_REQUESTS + 1,
+                    max: MAX_META_REQUESTS,
+                })
+            );
+        }
+
+        #[test]
+        fn non_finite_is_rejected() {
+            let mut msg = player_data(0);
+            msg.camera_radius = f32::NAN;
+            assert_eq!(
+                msg.validate(),
+                Err(ValidationError::NonFinite("cameraRadius"))
+            );
+
+            let mut msg = player_data(0);
+            let mut object = PlayerObjectData {
+                position_x: 0.0,
+                position_y: 0.0,
+                rotation: 0.0,
+                icon_type: crate::schema::shared::IconType::Cube,
+                is_visible: true,
+                is_looking_left: false,
+                is_upside_down: false,
+                is_dashing: false,
+                is_mini: false,
+                is_grounded: true,
+                is_stationary: false,
+                is_falling: false,
+                is_rotating: false,
+                is_sideways: false,
+                did_just_jump: false,
+                is_flipped: false,
+                is_holding: false,
+                ext_data: ExtendedPlayerData {
+                    velocity_x: 0.0,
+                    velocity_y: 0.0,
+                    accelerating: false,
+                    acceleration: 0.0,
+                    fall_start_y: 0.0,
+                    is_on_ground2: true,
+                    gravity_mod: 1.0,
+                    gravity: 1.0,
+                    touched_pad: false,
+                    maybe_falling: false,
+                    fall_speed: 0.0,
+                    is_on_ground4: true,
+                },
+            };
+            object.ext_data.gravity = f32::INFINITY;
+            msg.data.objects = PlayerObjects::Single(object);
+            assert_eq!(msg.validate(), Err(ValidationError::NonFinite("gravity")));
+        }
+    }
+
+    #[cfg(feature = "main")]
+    mod main {
+        use super::*;
+        use crate::messages::main::{RequestRoomListMessage, RoomSettings};
+
+        fn create_room(name_len: usize) -> CreateRoomMessage {
+            CreateRoomMessage {
+                name: "a".repeat(name_len),
+                passcode: 0,
+                settings: RoomSettings::default(),
+            }
+        }
+
+        fn plain_login(username_len: usize) -> LoginMessage {
+            LoginMessage {
+                account_id: 1,
+                icons: Default::default(),
+                uident: Vec::new(),
+                settings: Default::default(),
+                auth: LoginAuth::Plain(PlayerAccountData {
+                    account_id: 1,
+                    user_id: 1,
+                    username: "a".repeat(username_len),
+                }),
+                platform: crate::schema::main::Platform::Windows,
+                geode_version: String::new(),
+                globed_version: String::new(),
+                platform_desc: String::new(),
+                event_dictionary: Vec::new(),
+                protocol_version: 0,
+                capabilities: Default::default(),
+            }
+        }
+
+        #[test]
+        fn lengths_at_limit() {
+            assert_eq!(create_room(MAX_ROOM_NAME_LENGTH).validate(), Ok(()));
+            assert_eq!(plain_login(MAX_USERNAME_LENGTH).validate(), Ok(()));
+            assert_eq!(
+                MainMessage::RequestRoomList(RequestRoomListMessage {
+                    name_filter: "a".repeat(MAX_ROOM_NAME_LENGTH),
+                    page: 0,
+                })
+                .validate(),
+                Ok(())
+            );
+        }
+
+        #[test]
+        fn lengths_over_limit() {
+            assert_eq!(
+                create_room(MAX_ROOM_NAME_LENGTH + 1).validate(),
+                Err(ValidationError::TooLong {
+                    field: "name",
+                    len: MAX_ROOM_NAME_LENGTH + 1,
+                    max: MAX_ROOM_NAME_LENGTH,
+                })
+            );
+            assert_eq!(
+                plain_login(MAX_USERNAME_LENGTH + 1).validate(),
+                Err(ValidationError::TooLong {
+                    field: "username",
+                    len: MAX_USERNAME_LENGTH + 1,
+                    max: MAX_USERNAME_LENGTH,
+                })
+            );
+            assert!(
+                MainMessage::RequestRoomList(RequestRoomListMessage {
+                    name_filter: "a".repeat(MAX_ROOM_NAME_LENGTH + 1),
+                    page: 0,
+                })
+                .validate()
+                .is_err()
+            );
+        }
+
+        #[test]
+        fn invalid_room_settings() {
+            let settings = RoomSettings {
+                locked_teams: true,
+                ..Default::default()
+            };
+
+            let mut create = create_room(MAX_ROOM_NAME_LENGTH);
+            create.settings = settings.clone();
+
+            let err = Err(ValidationError::InvalidSettings(
+                RoomSettingsError::LockedTeamsWithoutTeams,
+            ));
+            assert_eq!(MainMessage::CreateRoom(create).validate(), err);
+            assert_eq!(
+                MainMessage::UpdateRoomSettings(UpdateRoomSettingsMessage { settings }).validate(),
+                err
+            );
+        }
+    }
+}
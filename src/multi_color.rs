@@ -53,7 +53,7 @@ pub type ColorVec = SmallVec<[RGBColor; 8]>;
 
 /// Represents up to 63 colors, with 3 variations: static, tinting, gradient.
 /// Can be efficiently encoded to a byte writer and back.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum MultiColor {
     Static(RGBColor),
     Tinting(ColorVec),
@@ -0,0 +1,220 @@
+use std::ops::{BitAnd, BitOr};
+
+use thiserror::Error;
+
+/// Version of the main server protocol, bump whenever a change requires clients to update.
+/// Additions that old clients can safely ignore should be gated behind a capability instead.
+#[cfg(feature = "main")]
+pub const MAIN_PROTOCOL_VERSION: u16 = 1;
+/// Oldest main server protocol version that is still accepted.
+/// 0 is the legacy version of clients that predate versioning, which negotiate no capabilities.
+#[cfg(feature = "main")]
+pub const MIN_MAIN_PROTOCOL_VERSION: u16 = 0;
+
+/// Version of the game server protocol, see `MAIN_PROTOCOL_VERSION`
+#[cfg(feature = "game")]
+pub const GAME_PROTOCOL_VERSION: u16 = 1;
+/// Oldest game server protocol version that is still accepted, see `MIN_MAIN_PROTOCOL_VERSION`
+#[cfg(feature = "game")]
+pub const MIN_GAME_PROTOCOL_VERSION: u16 = 0;
+
+/// Set of optional features a peer supports, exchanged at login.
+/// Bits unknown to this build are preserved, so that they can be passed along, but never negotiated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Capabilities(u64);
+
+impl Capabilities {
+    pub const NONE: Self = Self(0);
+    /// Peer can decompress lz4 compressed event payloads
+    pub const COMPRESSED_EVENTS: Self = Self(1 << 0);
+
+    /// All capabilities implemented by this build
+    pub const SUPPORTED: Self = Self(Self::COMPRESSED_EVENTS.0);
+
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Capabilities that both this build and the peer support
+    pub const fn negotiate(self, peer: Self) -> Self {
+        Self(self.0 & peer.0 & Self::SUPPORTED.0)
+    }
+}
+
+impl BitOr for Capabilities {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitAnd for Capabilities {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ProtocolMismatch {
+    #[error("client protocol v{client} is outdated (minimum supported: v{min})")]
+    OutdatedClient { client: u16, min: u16 },
+    #[error("client protocol v{client} is newer than the server (v{server})")]
+    OutdatedServer { client: u16, server: u16 },
+}
+
+/// Checks that `version` is within `min..=current`.
+/// Clients that predate versioning send 0, they are only rejected once `min` is deliberately raised above it.
+pub const fn check_version(version: u16, min: u16, current: u16) -> Result<(), ProtocolMismatch> {
+    if version < min {
+        Err(ProtocolMismatch::OutdatedClient {
+            client: version,
+            min,
+        })
+    } else if version > current {
+        Err(ProtocolMismatch::OutdatedServer {
+            client: version,
+            server: current,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "main")]
+pub const fn check_main_version(version: u16) -> Result<(), ProtocolMismatch> {
+    check_version(version, MIN_MAIN_PROTOCOL_VERSION, MAIN_PROTOCOL_VERSION)
+}
+
+#[cfg(feature = "game")]
+pub const fn check_game_version(version: u16) -> Result<(), ProtocolMismatch> {
+    check_version(version, MIN_GAME_PROTOCOL_VERSION, GAME_PROTOCOL_VERSION)
+}
+
+impl ProtocolMismatch {
+    #[cfg(feature = "main")]
+    pub fn main_reason(self) -> crate::schema::main::LoginFailedReason {
+        use crate::schema::main::LoginFailedReason;
+
+        match self {
+            Self::OutdatedClient { .. } => LoginFailedReason::OutdatedClient,
+            Self::OutdatedServer { .. } => LoginFailedReason::OutdatedServer,
+        }
+    }
+
+    #[cfg(feature = "game")]
+    pub fn game_reason(self) -> crate::schema::game::LoginFailedReason {
+        use crate::schema::game::LoginFailedReason;
+
+        match self {
+            Self::OutdatedClient { .. } => LoginFailedReason::OutdatedClient,
+            Self::OutdatedServer { .. } => LoginFailedReason::OutdatedServer,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn version_in_range() {
+        assert_eq!(check_version(2, 2, 5), Ok(()));
+        assert_eq!(check_version(3, 2, 5), Ok(()));
+        assert_eq!(check_version(5, 2, 5), Ok(()));
+        assert_eq!(check_version(0, 0, 0), Ok(()));
+    }
+
+    #[test]
+    fn outdated_client() {
+        assert_eq!(
+            check_version(1, 2, 5),
+            Err(ProtocolMismatch::OutdatedClient { client: 1, min: 2 })
+        );
+        assert_eq!(
+            check_version(0, 1, 5),
+            Err(ProtocolMismatch::OutdatedClient { client: 0, min: 1 })
+        );
+    }
+
+    #[test]
+    fn outdated_server() {
+        assert_eq!(
+            check_version(6, 2, 5),
+            Err(ProtocolMismatch::OutdatedServer {
+                client: 6,
+                server: 5
+            })
+        );
+        assert_eq!(
+            check_version(u16::MAX, 0, 5),
+            Err(ProtocolMismatch::OutdatedServer {
+                client: u16::MAX,
+                server: 5
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "main")]
+    fn legacy_main_client() {
+        assert_eq!(check_main_version(0), Ok(()));
+        assert_eq!(check_main_version(MAIN_PROTOCOL_VERSION), Ok(()));
+        assert!(check_main_version(MAIN_PROTOCOL_VERSION + 1).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "game")]
+    fn legacy_game_client() {
+        assert_eq!(check_game_version(0), Ok(()));
+        assert_eq!(check_game_version(GAME_PROTOCOL_VERSION), Ok(()));
+        assert!(check_game_version(GAME_PROTOCOL_VERSION + 1).is_err());
+    }
+
+    #[test]
+    fn negotiate_intersects() {
+        let unknown = Capabilities::from_bits(1 << 63);
+
+        assert_eq!(
+            Capabilities::SUPPORTED.negotiate(Capabilities::COMPRESSED_EVENTS | unknown),
+            Capabilities::COMPRESSED_EVENTS
+        );
+        assert_eq!(
+            Capabilities::SUPPORTED.negotiate(Capabilities::NONE),
+            Capabilities::NONE
+        );
+        assert_eq!(
+            Capabilities::NONE.negotiate(Capabilities::SUPPORTED),
+            Capabilities::NONE
+        );
+
+        // bits unknown to this build are never negotiated, even if both sides claim them
+        assert_eq!(unknown.negotiate(unknown), Capabilities::NONE);
+    }
+
+    #[test]
+    fn bit_operations() {
+        let unknown = Capabilities::from_bits(1 << 40);
+        let both = Capabilities::COMPRESSED_EVENTS | unknown;
+
+        assert_eq!(
+            both.bits(),
+            (1 << 40) | Capabilities::COMPRESSED_EVENTS.bits()
+        );
+        assert!(both.contains(unknown));
+        assert!(both.contains(Capabilities::COMPRESSED_EVENTS));
+        assert!(both.contains(Capabilities::NONE));
+        assert!(!Capabilities::COMPRESSED_EVENTS.contains(both));
+        assert_eq!(both & unknown, unknown);
+    }
+}
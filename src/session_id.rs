@@ -26,6 +26,11 @@ impl SessionId {
         ((self.0 >> 32) & 0x00ffffff) as u32
     }
 
+    /// The same room and level, hosted on another server
+    pub fn with_server_id(&self, server_id: u8) -> SessionId {
+        SessionId((self.0 & 0x00ffffff_ffffffff) | (u64::from(server_id) << 56))
+    }
+
     pub fn as_u64(&self) -> u64 {
         self.0
     }
@@ -40,3 +45,35 @@ impl From<u64> for SessionId {
         SessionId(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parts() {
+        let id = SessionId::from_parts(0xfe, -2, 0x123456);
+
+        assert_eq!(id.server_id(), 0xfe);
+        assert_eq!(id.level_id(), -2);
+        assert_eq!(id.room_id(), 0x123456);
+    }
+
+    #[test]
+    fn with_server_id() {
+        for id in [
+            SessionId::from_parts(1, 100, 5),
+            SessionId::from_parts(0xff, -1, 0xffffff),
+            SessionId::from_parts(0, i32::MIN, 0),
+        ] {
+            for server_id in [0, 1, 0x80, 0xff] {
+                let moved = id.with_server_id(server_id);
+
+                assert_eq!(moved.server_id(), server_id);
+                assert_eq!(moved.room_id(), id.room_id());
+                assert_eq!(moved.level_id(), id.level_id());
+                assert_eq!(moved.0 & 0x00ffffff_ffffffff, id.0 & 0x00ffffff_ffffffff);
+            }
+        }
+    }
+}
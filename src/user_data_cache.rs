@@ -0,0 +1,120 @@
+use rustc_hash::FxHashMap;
+
+use crate::{
+    data::SrvUserData,
+    messages::srvc::{NotifyUserDataBatchMessage, NotifyUserDataMessage},
+};
+
+/// User data known to a game server, kept in sync by the central server's notifications
+#[derive(Debug, Default)]
+pub struct UserDataCache {
+    users: FxHashMap<i32, SrvUserData>,
+}
+
+impl UserDataCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, account_id: i32) -> Option<&SrvUserData> {
+        self.users.get(&account_id)
+    }
+
+    pub fn insert(&mut self, data: SrvUserData) {
+        self.users.insert(data.account_id, data);
+    }
+
+    /// Should be called when a user disconnects from the game server
+    pub fn remove(&mut self, account_id: i32) -> Option<SrvUserData> {
+        self.users.remove(&account_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.users.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.users.is_empty()
+    }
+
+    pub fn apply(&mut self, msg: NotifyUserDataMessage) {
+        self.insert(msg.data);
+    }
+
+    /// Applies a batch, full entries first. Returns the account IDs of diffs for users not in the cache,
+    /// those diffs are dropped and the game server should request their full data if needed.
+    pub fn apply_batch(&mut self, msg: NotifyUserDataBatchMessage) -> Vec<i32> {
+        for data in msg.data {
+            self.insert(data);
+        }
+
+        let mut unknown = Vec::new();
+
+        for diff in &msg.diffs {
+            match self.users.get_mut(&diff.account_id) {
+                Some(data) => diff.apply(data),
+                None => unknown.push(diff.account_id),
+            }
+        }
+
+        unknown
+    }
+
+    /// Lifts every mute that has expired at `now`, returning the account IDs of the unmuted users
+    pub fn expire_mutes(&mut self, now: i64) -> Vec<i32> {
+        self.users
+            .values_mut()
+            .filter_map(|data| data.expire_mute(now).then_some(data.account_id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::SrvUserDataDiff;
+
+    fn user(account_id: i32, is_muted: bool, mute_expires_at: i64) -> SrvUserData {
+        SrvUserData {
+            account_id,
+            is_muted,
+            mute_expires_at,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn expire_mutes() {
+        let mut cache = UserDataCache::new();
+        cache.insert(user(1, true, 1000));
+        cache.insert(user(2, true, 2000));
+        cache.insert(user(3, true, 0));
+        cache.insert(user(4, false, 0));
+
+        assert!(cache.expire_mutes(999).is_empty());
+
+        assert_eq!(cache.expire_mutes(1000), vec![1]);
+        assert!(!cache.get(1).unwrap().is_muted);
+        assert!(cache.get(2).unwrap().is_muted);
+        assert!(cache.get(3).unwrap().is_muted);
+
+        assert_eq!(cache.expire_mutes(i64::MAX), vec![2]);
+        assert!(cache.get(3).unwrap().is_muted);
+    }
+
+    #[test]
+    fn extended_mute_does_not_expire() {
+        let mut cache = UserDataCache::new();
+        cache.insert(user(1, true, 1000));
+
+        let diff = SrvUserDataDiff::between(&user(1, true, 1000), &user(1, true, 2000)).unwrap();
+
+        cache.apply_batch(NotifyUserDataBatchMessage {
+            data: Vec::new(),
+            diffs: vec![diff],
+        });
+
+        assert!(cache.expire_mutes(1500).is_empty());
+        assert_eq!(cache.expire_mutes(2000), vec![1]);
+    }
+}
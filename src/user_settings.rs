@@ -1,20 +0,0 @@
-#[derive(Clone, Copy, Debug, Default)]
-pub struct UserSettings {
-    pub hide_in_level: bool,
-    pub hide_in_menus: bool,
-    pub hide_roles: bool,
-    pub disable_notices: bool,
-    pub friends_only_vc: bool,
-}
-
-impl UserSettings {
-    pub fn from_reader(reader: crate::schema::shared::user_settings::Reader) -> Self {
-        Self {
-            hide_in_level: reader.get_hide_in_level(),
-            hide_in_menus: reader.get_hide_in_menus(),
-            hide_roles: reader.get_hide_roles(),
-            disable_notices: reader.get_disable_notices(),
-            friends_only_vc: reader.get_friends_only_vc(),
-        }
-    }
-}